@@ -138,7 +138,7 @@ async fn main() -> Result<()> {
 /// - Order aggregated results
 #[instrument(skip(service), fields(layer_id = %layer_id))]
 async fn demonstrate_statistical_aggregations(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> Result<()> {
     tracing::info!("=== Example 1: Statistical Aggregations ===");
@@ -220,10 +220,7 @@ async fn demonstrate_statistical_aggregations(
 /// function is kept for documentation but not called.
 #[allow(dead_code)]
 #[instrument(skip(service), fields(layer_id = %layer_id))]
-async fn demonstrate_top_features(
-    service: &FeatureServiceClient<'_>,
-    layer_id: LayerId,
-) -> Result<()> {
+async fn demonstrate_top_features(service: &FeatureServiceClient, layer_id: LayerId) -> Result<()> {
     tracing::info!("\n=== Example 2: Top N Features Query ===");
     tracing::info!("Find top 5 service requests by district");
 
@@ -346,7 +343,7 @@ async fn demonstrate_top_features(
 /// - Comparing manual vs automatic pagination
 #[instrument(skip(service), fields(layer_id = %layer_id))]
 async fn demonstrate_pagination_strategies(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> Result<()> {
     tracing::info!("\n=== Example 3: Pagination Strategies ===");
@@ -488,7 +485,7 @@ async fn demonstrate_pagination_strategies(
 /// and more bandwidth-efficient than querying features and counting client-side.
 #[instrument(skip(service), fields(layer_id = %layer_id))]
 async fn demonstrate_feature_count(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> Result<()> {
     tracing::info!("\n=== Example 4: Feature Count Queries ===");
@@ -570,7 +567,7 @@ async fn demonstrate_feature_count(
 /// useful when you need specific parameter combinations.
 #[instrument(skip(service), fields(layer_id = %layer_id))]
 async fn demonstrate_query_with_params(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> Result<()> {
     tracing::info!("\n=== Example 5: Advanced Query with Params ===");
@@ -698,7 +695,7 @@ async fn demonstrate_query_with_params(
 #[allow(dead_code)]
 #[instrument(skip(service), fields(layer_id = %layer_id))]
 async fn demonstrate_related_records(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> Result<()> {
     tracing::info!("\n=== Example 4: Related Records Query ===");
@@ -800,7 +797,7 @@ async fn demonstrate_related_records(
 /// - Get subtype information
 #[allow(dead_code)]
 #[instrument(skip(service))]
-async fn demonstrate_domain_lookups(service: &FeatureServiceClient<'_>) -> Result<()> {
+async fn demonstrate_domain_lookups(service: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Domain Lookups ===");
     tracing::info!("Query valid domain values for crime types");
     tracing::debug!("Note: Requires service with domain/subtype definitions");
@@ -112,7 +112,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates generating an elevation profile along a line.
-async fn demonstrate_elevation_profile(elevation: &ElevationClient<'_>) -> Result<()> {
+async fn demonstrate_elevation_profile(elevation: &ElevationClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Elevation Profile ===");
     tracing::info!("Extract elevation along a hiking trail transect");
     tracing::info!("");
@@ -123,7 +123,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates asynchronous terrain statistics extraction.
-async fn demonstrate_summarize_elevation(elevation: &ElevationClient<'_>) -> Result<()> {
+async fn demonstrate_summarize_elevation(elevation: &ElevationClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Summarize Elevation (Async) ===");
     tracing::info!("Extract terrain statistics for a polygon area");
     tracing::info!("");
@@ -292,7 +292,7 @@ async fn demonstrate_summarize_elevation(elevation: &ElevationClient<'_>) -> Res
 }
 
 /// Demonstrates asynchronous viewshed (visibility) analysis.
-async fn demonstrate_viewshed(elevation: &ElevationClient<'_>) -> Result<()> {
+async fn demonstrate_viewshed(elevation: &ElevationClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Viewshed Analysis (Async) ===");
     tracing::info!("Calculate visible areas from observation points");
     tracing::info!("");
@@ -209,7 +209,7 @@ async fn create_feature_service(content_key: &str) -> Result<ServiceInfo> {
         .text("addToDefinition", layer_definition.to_string());
 
     // Get token if required
-    if let Some(token) = content_client.get_token_if_required().await? {
+    if let Some(token) = content_client.get_token_for_url(&add_def_url).await? {
         form = form.text("token", token);
     }
 
@@ -239,7 +239,7 @@ async fn create_feature_service(content_key: &str) -> Result<ServiceInfo> {
 
 /// Creates a test feature to demonstrate attachment operations.
 async fn create_test_feature(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
 ) -> Result<ObjectId> {
     tracing::info!("\n=== Step 2: Creating Test Feature ===");
@@ -297,7 +297,7 @@ async fn create_test_feature(
 
 /// Demonstrates listing existing attachments on a feature.
 async fn demonstrate_list_attachments(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
 ) -> Result<()> {
@@ -330,7 +330,7 @@ async fn demonstrate_list_attachments(
 
 /// Demonstrates adding a photo attachment.
 async fn demonstrate_add_photo(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
 ) -> Result<()> {
@@ -369,7 +369,7 @@ async fn demonstrate_add_photo(
 
 /// Demonstrates adding a PDF document attachment.
 async fn demonstrate_add_pdf(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
 ) -> Result<()> {
@@ -405,7 +405,7 @@ async fn demonstrate_add_pdf(
 
 /// Demonstrates downloading attachments to file and memory.
 async fn demonstrate_download(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
 ) -> Result<()> {
@@ -480,7 +480,7 @@ async fn demonstrate_download(
 
 /// Demonstrates updating an existing attachment.
 async fn demonstrate_update(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
 ) -> Result<()> {
@@ -523,7 +523,7 @@ async fn demonstrate_update(
 
 /// Demonstrates deleting attachments.
 async fn demonstrate_delete(
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
 ) -> Result<()> {
@@ -583,7 +583,7 @@ async fn demonstrate_delete(
 /// Cleans up test data - deletes feature and service.
 async fn cleanup(
     content_key: &str,
-    feature_service: &FeatureServiceClient<'_>,
+    feature_service: &FeatureServiceClient,
     layer_id: arcgis::LayerId,
     object_id: ObjectId,
     service_item_id: &str,
@@ -131,7 +131,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates querying table definition metadata.
-async fn demonstrate_get_table_definition(fs_client: &FeatureServiceClient<'_>) -> Result<()> {
+async fn demonstrate_get_table_definition(fs_client: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Get Table Definition ===");
     tracing::info!("Query schema and metadata for a table");
     tracing::info!("");
@@ -198,7 +198,7 @@ async fn demonstrate_get_table_definition(fs_client: &FeatureServiceClient<'_>)
 }
 
 /// Demonstrates atomic batch editing with apply_edits.
-async fn demonstrate_apply_edits(fs_client: &FeatureServiceClient<'_>) -> Result<()> {
+async fn demonstrate_apply_edits(fs_client: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Atomic Batch Editing (apply_edits) ===");
     tracing::info!("Add, update, and delete features in single transaction");
     tracing::info!("");
@@ -299,7 +299,7 @@ async fn demonstrate_apply_edits(fs_client: &FeatureServiceClient<'_>) -> Result
 }
 
 /// Demonstrates bulk update operations.
-async fn demonstrate_update_features(fs_client: &FeatureServiceClient<'_>) -> Result<()> {
+async fn demonstrate_update_features(fs_client: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Bulk Feature Updates ===");
     tracing::info!("Update multiple features efficiently");
     tracing::info!("");
@@ -402,9 +402,7 @@ async fn demonstrate_update_features(fs_client: &FeatureServiceClient<'_>) -> Re
 }
 
 /// Demonstrates editing with global IDs.
-async fn demonstrate_apply_edits_with_global_ids(
-    fs_client: &FeatureServiceClient<'_>,
-) -> Result<()> {
+async fn demonstrate_apply_edits_with_global_ids(fs_client: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Global ID Editing ===");
     tracing::info!("Use global IDs for replicated/offline editing scenarios");
     tracing::info!("");
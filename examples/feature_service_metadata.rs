@@ -92,7 +92,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates retrieving and inspecting the service-level definition.
-async fn demonstrate_service_definition(service: &FeatureServiceClient<'_>) -> Result<()> {
+async fn demonstrate_service_definition(service: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Service Definition ===");
     tracing::info!("Retrieve service-level metadata to discover layers and capabilities");
     tracing::info!("");
@@ -163,7 +163,7 @@ async fn demonstrate_service_definition(service: &FeatureServiceClient<'_>) -> R
 }
 
 /// Demonstrates retrieving complete layer definition with full field schema.
-async fn demonstrate_layer_definition(service: &FeatureServiceClient<'_>) -> Result<()> {
+async fn demonstrate_layer_definition(service: &FeatureServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Layer Definition (Full Schema) ===");
     tracing::info!("Retrieve complete field definitions for a specific layer");
     tracing::info!("");
@@ -161,8 +161,8 @@ async fn main() -> Result<()> {
 
 /// Demonstrates the safe truncate pattern using version management.
 async fn demonstrate_safe_truncate(
-    fs_client: &FeatureServiceClient<'_>,
-    vm_client: &VersionManagementClient<'_>,
+    fs_client: &FeatureServiceClient,
+    vm_client: &VersionManagementClient,
 ) -> Result<()> {
     tracing::info!("\n=== Safe Truncate Pattern ===");
     tracing::info!("Using version management to safely test destructive operations");
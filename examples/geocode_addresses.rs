@@ -93,7 +93,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates forward geocoding (address → coordinates).
-async fn demonstrate_forward_geocoding(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_forward_geocoding(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Forward Geocoding ===");
     tracing::info!("Convert addresses to geographic coordinates");
 
@@ -146,7 +146,7 @@ async fn demonstrate_forward_geocoding(geocoder: &GeocodeServiceClient<'_>) -> R
 }
 
 /// Demonstrates reverse geocoding (coordinates → address).
-async fn demonstrate_reverse_geocoding(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_reverse_geocoding(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Reverse Geocoding ===");
     tracing::info!("Convert coordinates to addresses");
 
@@ -202,7 +202,7 @@ async fn demonstrate_reverse_geocoding(geocoder: &GeocodeServiceClient<'_>) -> R
 }
 
 /// Demonstrates autocomplete suggestions for user input.
-async fn demonstrate_autocomplete(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_autocomplete(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Autocomplete Suggestions ===");
     tracing::info!("Get address suggestions as user types");
 
@@ -241,7 +241,7 @@ async fn demonstrate_autocomplete(geocoder: &GeocodeServiceClient<'_>) -> Result
 }
 
 /// Demonstrates batch processing of multiple addresses with rate limiting.
-async fn demonstrate_batch_processing(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_batch_processing(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Batch Processing ===");
     tracing::info!("Geocode multiple addresses efficiently with rate limiting");
 
@@ -298,7 +298,7 @@ async fn demonstrate_batch_processing(geocoder: &GeocodeServiceClient<'_>) -> Re
 }
 
 /// Demonstrates filtering results by match score for high-precision geocoding.
-async fn demonstrate_high_precision(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_high_precision(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: High-Precision Filtering ===");
     tracing::info!("Only accept high-quality matches");
 
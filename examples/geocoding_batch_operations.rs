@@ -97,7 +97,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates batch geocoding with geocode_addresses().
-async fn demonstrate_batch_geocode(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_batch_geocode(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Batch Geocoding ===");
     tracing::info!("Process multiple addresses in a single API request");
     tracing::info!("");
@@ -185,7 +185,7 @@ async fn demonstrate_batch_geocode(geocoder: &GeocodeServiceClient<'_>) -> Resul
 }
 
 /// Demonstrates advanced options with find_address_candidates_with_options().
-async fn demonstrate_advanced_options(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_advanced_options(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Advanced Geocoding Options ===");
     tracing::info!("Use max_locations and location_type filters for precise control");
     tracing::info!("");
@@ -271,7 +271,7 @@ async fn demonstrate_advanced_options(geocoder: &GeocodeServiceClient<'_>) -> Re
 }
 
 /// Demonstrates geocoding with custom spatial reference.
-async fn demonstrate_custom_spatial_reference(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_custom_spatial_reference(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Custom Spatial Reference ===");
     tracing::info!("Geocode with Web Mercator projection (EPSG:3857)");
     tracing::info!("");
@@ -324,7 +324,7 @@ async fn demonstrate_custom_spatial_reference(geocoder: &GeocodeServiceClient<'_
 }
 
 /// Demonstrates type-safe reverse geocoding with spatial reference conversion.
-async fn demonstrate_reverse_geocode_custom_sr(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_reverse_geocode_custom_sr(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Type-Safe Reverse Geocoding ===");
     tracing::info!("Using ProjectedPoint types for compile-time spatial reference safety");
     tracing::info!("");
@@ -383,7 +383,7 @@ async fn demonstrate_reverse_geocode_custom_sr(geocoder: &GeocodeServiceClient<'
 }
 
 /// Demonstrates category-filtered autocomplete suggestions.
-async fn demonstrate_suggest_with_category(geocoder: &GeocodeServiceClient<'_>) -> Result<()> {
+async fn demonstrate_suggest_with_category(geocoder: &GeocodeServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Category-Filtered Suggestions ===");
     tracing::info!("Autocomplete with POI category filters");
     tracing::info!("");
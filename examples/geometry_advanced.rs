@@ -79,7 +79,7 @@ async fn main() -> Result<()> {
 /// - Self-intersections
 /// - Duplicate vertices
 /// - Invalid ring orientations
-async fn demonstrate_simplify(service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_simplify(service: &GeometryServiceClient) -> Result<()> {
     info!("\n=== Simplify Operation ===");
 
     // Create a complex polygon with potential issues
@@ -151,7 +151,7 @@ async fn demonstrate_simplify(service: &GeometryServiceClient<'_>) -> Result<()>
 ///
 /// Union combines multiple geometries into a single geometry,
 /// useful for merging adjacent parcels or combining zones.
-async fn demonstrate_union(service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_union(service: &GeometryServiceClient) -> Result<()> {
     info!("\n=== Union Operation ===");
 
     // Create two adjacent polygons
@@ -220,7 +220,7 @@ async fn demonstrate_union(service: &GeometryServiceClient<'_>) -> Result<()> {
 ///
 /// This operation is useful for accurate measurements, especially
 /// when working with data in different coordinate systems.
-async fn demonstrate_areas_and_lengths(service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_areas_and_lengths(service: &GeometryServiceClient) -> Result<()> {
     info!("\n=== Areas and Lengths Calculation ===");
 
     // Create a polygon representing approximately 1 degree by 1 degree square
@@ -308,7 +308,7 @@ async fn demonstrate_areas_and_lengths(service: &GeometryServiceClient<'_>) -> R
 ///
 /// This is crucial for accurate coordinate transformations between different
 /// spatial reference systems, especially across different datums (e.g., NAD83 to WGS84).
-async fn demonstrate_datum_transformations(service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_datum_transformations(service: &GeometryServiceClient) -> Result<()> {
     info!("\n=== Datum Transformations ===");
 
     // Find transformations from NAD83 (4269) to WGS84 (4326)
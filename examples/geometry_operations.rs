@@ -85,7 +85,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates coordinate projection between spatial reference systems.
-async fn demonstrate_coordinate_projection(geom_service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_coordinate_projection(geom_service: &GeometryServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Coordinate Projection ===");
     tracing::info!("Project coordinates from WGS84 (4326) to Web Mercator (3857)");
 
@@ -150,7 +150,7 @@ async fn demonstrate_coordinate_projection(geom_service: &GeometryServiceClient<
 }
 
 /// Demonstrates creating buffers around geometries.
-async fn demonstrate_buffer_creation(geom_service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_buffer_creation(geom_service: &GeometryServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Buffer Creation ===");
     tracing::info!("Create a 1000-meter buffer around a point");
 
@@ -189,7 +189,7 @@ async fn demonstrate_buffer_creation(geom_service: &GeometryServiceClient<'_>) -
 }
 
 /// Demonstrates calculating geodesic distance between points.
-async fn demonstrate_distance_calculation(geom_service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_distance_calculation(geom_service: &GeometryServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Distance Calculation ===");
     tracing::info!("Calculate geodesic distance between San Francisco and Los Angeles");
 
@@ -232,7 +232,7 @@ async fn demonstrate_distance_calculation(geom_service: &GeometryServiceClient<'
 }
 
 /// Demonstrates batch projection of multiple geometries.
-async fn demonstrate_batch_projection(geom_service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_batch_projection(geom_service: &GeometryServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Batch Projection ===");
     tracing::info!("Project multiple cities at once (more efficient than individual calls)");
 
@@ -283,7 +283,7 @@ async fn demonstrate_batch_projection(geom_service: &GeometryServiceClient<'_>)
 }
 
 /// Demonstrates creating and projecting a polyline.
-async fn demonstrate_line_length(geom_service: &GeometryServiceClient<'_>) -> Result<()> {
+async fn demonstrate_line_length(geom_service: &GeometryServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Polyline Creation ===");
     tracing::info!("Create and project a route line between cities");
 
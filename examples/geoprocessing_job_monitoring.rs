@@ -85,7 +85,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates get_job_status() for manual polling.
-async fn demonstrate_manual_status_polling(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_manual_status_polling(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Manual Status Polling (get_job_status) ===");
     tracing::info!("Testing get_job_status() method with comprehensive validation");
     tracing::info!("");
@@ -216,7 +216,7 @@ async fn demonstrate_manual_status_polling(service: &GeoprocessingServiceClient<
 }
 
 /// Demonstrates get_job_messages() for detailed diagnostics.
-async fn demonstrate_messages_retrieval(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_messages_retrieval(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Message Retrieval (get_job_messages) ===");
     tracing::info!("Testing get_job_messages() method with validation");
     tracing::info!("");
@@ -321,7 +321,7 @@ async fn demonstrate_messages_retrieval(service: &GeoprocessingServiceClient<'_>
 }
 
 /// Demonstrates get_job_result() and get_result_data().
-async fn demonstrate_result_data_access(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_result_data_access(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Result Data Access (get_job_result, get_result_data) ===");
     tracing::info!("Testing result retrieval methods");
     tracing::info!("");
@@ -408,7 +408,7 @@ async fn demonstrate_result_data_access(service: &GeoprocessingServiceClient<'_>
 }
 
 /// Demonstrates synchronous execution with execute().
-async fn demonstrate_synchronous_execution(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_synchronous_execution(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Synchronous Execution (execute) ===");
     tracing::info!("Testing execute() method for sync tasks");
     tracing::info!("");
@@ -110,7 +110,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates submitting and completing an asynchronous geoprocessing job.
-async fn demonstrate_async_job(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_async_job(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Asynchronous Job Execution ===");
     tracing::info!("Analyzing weekend 911 call patterns in January 1998");
     tracing::info!("");
@@ -278,7 +278,7 @@ async fn demonstrate_async_job(service: &GeoprocessingServiceClient<'_>) -> Resu
 }
 
 /// Demonstrates manual job monitoring and status checking.
-async fn demonstrate_job_monitoring(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_job_monitoring(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Manual Job Monitoring ===");
     tracing::info!("Monitoring job status transitions for UI progress displays");
     tracing::info!("");
@@ -457,7 +457,7 @@ async fn demonstrate_job_monitoring(service: &GeoprocessingServiceClient<'_>) ->
 }
 
 /// Demonstrates retrieving detailed job messages.
-async fn demonstrate_job_messages(service: &GeoprocessingServiceClient<'_>) -> Result<()> {
+async fn demonstrate_job_messages(service: &GeoprocessingServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Job Messages and Diagnostics ===");
     tracing::info!("Understanding server-side processing through message analysis");
     tracing::info!("");
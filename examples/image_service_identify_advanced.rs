@@ -90,7 +90,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates basic identify_with_params usage.
-async fn demonstrate_basic_params(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_basic_params(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("=== Example 1: Basic identify_with_params ===");
     tracing::info!("Identify with custom parameters for more control");
     tracing::info!("");
@@ -140,7 +140,7 @@ async fn demonstrate_basic_params(service: &ImageServiceClient<'_>) -> Result<()
 }
 
 /// Demonstrates return options (geometry, catalog items).
-async fn demonstrate_return_options(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_return_options(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Return Options ===");
     tracing::info!("Control what information is returned in identify results");
     tracing::info!("");
@@ -213,7 +213,7 @@ async fn demonstrate_return_options(service: &ImageServiceClient<'_>) -> Result<
 }
 
 /// Demonstrates spatial reference handling.
-async fn demonstrate_spatial_reference(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_spatial_reference(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Spatial Reference Handling ===");
     tracing::info!("Specify input geometry coordinate system explicitly");
     tracing::info!("");
@@ -100,7 +100,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates retrieving raster metadata.
-async fn demonstrate_raster_metadata(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_raster_metadata(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Raster Metadata ===");
     tracing::info!("Query raster information (bands, extent, pixel type)");
 
@@ -141,7 +141,7 @@ async fn demonstrate_raster_metadata(service: &ImageServiceClient<'_>) -> Result
 }
 
 /// Demonstrates exporting a raster image.
-async fn demonstrate_export_image(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_export_image(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Export Raster Image ===");
     tracing::info!("Export land cover data for San Francisco Bay Area");
 
@@ -190,7 +190,7 @@ async fn demonstrate_export_image(service: &ImageServiceClient<'_>) -> Result<()
 }
 
 /// Demonstrates identifying pixel values at a point.
-async fn demonstrate_identify_pixel(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_identify_pixel(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Identify Pixel Values ===");
     tracing::info!("Get land cover classification at specific location");
 
@@ -238,7 +238,7 @@ async fn demonstrate_identify_pixel(service: &ImageServiceClient<'_>) -> Result<
 }
 
 /// Demonstrates sampling pixel values along a transect.
-async fn demonstrate_sample_transect(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_sample_transect(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Sample Along Transect ===");
     tracing::info!("Extract pixel values along a line (e.g., hiking trail)");
 
@@ -297,7 +297,7 @@ async fn demonstrate_sample_transect(service: &ImageServiceClient<'_>) -> Result
 }
 
 /// Demonstrates computing pixel value histograms.
-async fn demonstrate_compute_histograms(service: &ImageServiceClient<'_>) -> Result<()> {
+async fn demonstrate_compute_histograms(service: &ImageServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Compute Histograms ===");
     tracing::info!("Analyze pixel value distribution in study area");
 
@@ -141,7 +141,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates basic map export with custom extent.
-async fn demonstrate_basic_map_export(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_basic_map_export(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Basic Map Export ===");
     tracing::info!("Export map of San Francisco Bay Area");
 
@@ -189,7 +189,7 @@ async fn demonstrate_basic_map_export(service: &MapServiceClient<'_>) -> Result<
 }
 
 /// Demonstrates transparent background for overlay maps.
-async fn demonstrate_transparent_export(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_transparent_export(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Transparent Background Export ===");
     tracing::info!("Export map with transparency for overlay use");
 
@@ -231,7 +231,7 @@ async fn demonstrate_transparent_export(service: &MapServiceClient<'_>) -> Resul
 }
 
 /// Demonstrates high DPI export for print quality.
-async fn demonstrate_high_dpi_export(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_high_dpi_export(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: High DPI Export ===");
     tracing::info!("Export high-resolution map for print");
 
@@ -280,7 +280,7 @@ async fn demonstrate_high_dpi_export(service: &MapServiceClient<'_>) -> Result<(
 }
 
 /// Demonstrates identifying features at a clicked point.
-async fn demonstrate_identify_features(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_identify_features(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Identify Features at Point ===");
     tracing::info!("Identify features at clicked location (Los Angeles area)");
 
@@ -359,7 +359,7 @@ async fn demonstrate_identify_features(service: &MapServiceClient<'_>) -> Result
 }
 
 /// Demonstrates finding features by text search.
-async fn demonstrate_find_by_text(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_find_by_text(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Find Features by Text ===");
     tracing::info!("Search for cities containing 'Los' in their name");
 
@@ -436,7 +436,7 @@ async fn demonstrate_find_by_text(service: &MapServiceClient<'_>) -> Result<()>
 }
 
 /// Demonstrates retrieving map legend information.
-async fn demonstrate_legend_retrieval(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_legend_retrieval(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 6: Legend Retrieval ===");
     tracing::info!("Get legend symbols and labels for all layers");
 
@@ -494,7 +494,7 @@ async fn demonstrate_legend_retrieval(service: &MapServiceClient<'_>) -> Result<
 }
 
 /// Demonstrates retrieving service metadata and capabilities.
-async fn demonstrate_metadata_retrieval(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_metadata_retrieval(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 7: Service Metadata Retrieval ===");
     tracing::info!("Get comprehensive service information and capabilities");
 
@@ -545,7 +545,7 @@ async fn demonstrate_metadata_retrieval(service: &MapServiceClient<'_>) -> Resul
 }
 
 /// Demonstrates exporting cached tiles from a tiled service.
-async fn demonstrate_tile_export(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_tile_export(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 8: Cached Tile Export ===");
     tracing::info!("Export pre-generated tiles from cached service");
 
@@ -591,7 +591,7 @@ async fn demonstrate_tile_export(service: &MapServiceClient<'_>) -> Result<()> {
 }
 
 /// Demonstrates querying field domains and subtypes.
-async fn demonstrate_query_domains(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_query_domains(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 9: Query Domains ===");
     tracing::info!("Retrieve domain and subtype information for layers");
 
@@ -636,7 +636,7 @@ async fn demonstrate_query_domains(service: &MapServiceClient<'_>) -> Result<()>
 }
 
 /// Demonstrates generating KML output for Google Earth.
-async fn demonstrate_generate_kml(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_generate_kml(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 10: Generate KML ===");
     tracing::info!("Export map service as KML for Google Earth");
 
@@ -680,7 +680,7 @@ async fn demonstrate_generate_kml(service: &MapServiceClient<'_>) -> Result<()>
 }
 
 /// Demonstrates generating dynamic classification renderers.
-async fn demonstrate_generate_renderer(service: &MapServiceClient<'_>) -> Result<()> {
+async fn demonstrate_generate_renderer(service: &MapServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 11: Generate Renderer ===");
     tracing::info!("Create dynamic classification renderer for data visualization");
 
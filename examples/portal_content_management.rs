@@ -93,7 +93,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates basic content search using Lucene query syntax.
-async fn demonstrate_basic_search(portal: &PortalClient<'_>) -> Result<()> {
+async fn demonstrate_basic_search(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Basic Content Search ===");
     tracing::info!("Find feature services related to 'parks'");
 
@@ -132,7 +132,7 @@ async fn demonstrate_basic_search(portal: &PortalClient<'_>) -> Result<()> {
 }
 
 /// Demonstrates advanced search with sorting by modification date.
-async fn demonstrate_advanced_query(portal: &PortalClient<'_>) -> Result<()> {
+async fn demonstrate_advanced_query(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Advanced Query with Sorting ===");
     tracing::info!("Find recent web maps, sorted by modification date");
 
@@ -171,7 +171,7 @@ async fn demonstrate_advanced_query(portal: &PortalClient<'_>) -> Result<()> {
 }
 
 /// Demonstrates retrieving detailed item metadata.
-async fn demonstrate_item_details(portal: &PortalClient<'_>) -> Result<()> {
+async fn demonstrate_item_details(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Retrieve Item Metadata ===");
     tracing::info!("Get detailed information about a specific item");
 
@@ -233,7 +233,7 @@ async fn demonstrate_item_details(portal: &PortalClient<'_>) -> Result<()> {
 }
 
 /// Demonstrates finding and exploring public groups.
-async fn demonstrate_group_discovery(portal: &PortalClient<'_>) -> Result<()> {
+async fn demonstrate_group_discovery(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Group Discovery ===");
     tracing::info!("Find public groups related to 'open data'");
 
@@ -277,7 +277,7 @@ async fn demonstrate_group_discovery(portal: &PortalClient<'_>) -> Result<()> {
 }
 
 /// Demonstrates pagination for handling large result sets.
-async fn demonstrate_pagination(portal: &PortalClient<'_>) -> Result<()> {
+async fn demonstrate_pagination(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Pagination Pattern ===");
     tracing::info!("Retrieve multiple pages of search results");
 
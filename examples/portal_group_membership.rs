@@ -98,7 +98,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates the complete group membership workflow.
-async fn run_membership_workflow(portal: &PortalClient<'_>) -> Result<()> {
+async fn run_membership_workflow(portal: &PortalClient) -> Result<()> {
     // ========================================================================
     // STEP 1: Create a test group (public so we can join it)
     // ========================================================================
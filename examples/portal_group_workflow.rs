@@ -85,7 +85,7 @@ async fn main() -> Result<()> {
 }
 
 /// Executes the complete group lifecycle workflow.
-async fn run_group_workflow(portal: &PortalClient<'_>) -> Result<()> {
+async fn run_group_workflow(portal: &PortalClient) -> Result<()> {
     // Step 1: Create a test item (we need something to add to the group)
     tracing::info!("=== Step 1: Creating Test Item ===");
     tracing::info!("Creating a simple web map item to add to group");
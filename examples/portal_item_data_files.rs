@@ -93,7 +93,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates uploading CSV data as a file.
-async fn test_csv_file_upload(portal: &PortalClient<'_>) -> Result<()> {
+async fn test_csv_file_upload(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 1: CSV File Upload ===");
     tracing::info!("Create CSV item and upload tabular data as file");
     tracing::info!("");
@@ -201,7 +201,7 @@ Sacramento,California,524943,38.5816,-121.4944
 }
 
 /// Demonstrates uploading an image file.
-async fn test_image_file_upload(portal: &PortalClient<'_>) -> Result<()> {
+async fn test_image_file_upload(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Image File Upload ===");
     tracing::info!("Create image item and upload PNG data as file");
     tracing::info!("");
@@ -305,7 +305,7 @@ async fn test_image_file_upload(portal: &PortalClient<'_>) -> Result<()> {
 }
 
 /// Demonstrates uploading a PDF file.
-async fn test_pdf_file_upload(portal: &PortalClient<'_>) -> Result<()> {
+async fn test_pdf_file_upload(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 3: PDF File Upload ===");
     tracing::info!("Create PDF item and upload document data as file");
     tracing::info!("");
@@ -101,7 +101,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates uploading GeoJSON as text data.
-async fn test_webmap_text_upload(portal: &PortalClient<'_>) -> Result<()> {
+async fn test_webmap_text_upload(portal: &PortalClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Web Map Text Upload ===");
     tracing::info!("Create Web Map item and upload map definition as text");
     tracing::info!("");
@@ -204,7 +204,7 @@ async fn query_and_convert_to_geojson()
 
 /// Workflow A: Direct service creation - create service with schema and add features.
 async fn demonstrate_workflow_a_direct_service(
-    portal: &PortalClient<'_>,
+    portal: &PortalClient,
     client: &ArcGISClient,
 ) -> Result<()> {
     tracing::info!("\n========================================");
@@ -556,7 +556,7 @@ async fn demonstrate_workflow_a_direct_service(
 
 /// Workflow B: Portal item data management - upload and retrieve file data.
 async fn demonstrate_workflow_b_geojson_item(
-    portal: &PortalClient<'_>,
+    portal: &PortalClient,
     _client: &ArcGISClient,
 ) -> Result<()> {
     tracing::info!("\n========================================");
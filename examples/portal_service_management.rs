@@ -97,7 +97,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates the complete service management workflow.
-async fn run_service_management_workflow(portal: &PortalClient<'_>) -> Result<()> {
+async fn run_service_management_workflow(portal: &PortalClient) -> Result<()> {
     // ========================================================================
     // STEP 1: Upload initial GeoJSON data
     // ========================================================================
@@ -77,7 +77,7 @@ async fn main() -> anyhow::Result<()> {
 
 /// Demonstrates basic WHERE clause queries.
 async fn demonstrate_basic_where_query(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 1: Basic WHERE Clause Query ===");
@@ -132,7 +132,7 @@ async fn demonstrate_basic_where_query(
 
 /// Demonstrates field filtering without geometry for better performance.
 async fn demonstrate_field_filtering(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 2: Field Filtering (No Geometry) ===");
@@ -184,7 +184,7 @@ async fn demonstrate_field_filtering(
 
 /// Demonstrates count-only queries without returning features.
 async fn demonstrate_count_only(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 3: Count-Only Query ===");
@@ -219,7 +219,7 @@ async fn demonstrate_count_only(
 
 /// Demonstrates querying by specific Object IDs.
 async fn demonstrate_object_id_query(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 4: Query by Object IDs ===");
@@ -249,7 +249,7 @@ async fn demonstrate_object_id_query(
 
 /// Demonstrates manual pagination using offset and limit.
 async fn demonstrate_manual_pagination(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 5: Manual Pagination ===");
@@ -299,7 +299,7 @@ async fn demonstrate_manual_pagination(
 
 /// Demonstrates automatic pagination using execute_all().
 async fn demonstrate_auto_pagination(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 6: Auto-Pagination ===");
@@ -325,7 +325,7 @@ async fn demonstrate_auto_pagination(
 
 /// Demonstrates alternative response formats (GeoJSON and PBF).
 async fn demonstrate_alternative_formats(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 7: Alternative Response Formats ===");
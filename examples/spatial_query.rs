@@ -66,7 +66,7 @@ async fn main() -> anyhow::Result<()> {
 
 /// Demonstrates bounding box (envelope) queries.
 async fn demonstrate_bounding_box_query(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 1: Bounding Box Query ===");
@@ -128,7 +128,7 @@ async fn demonstrate_bounding_box_query(
 
 /// Demonstrates polygon queries with complex shapes.
 async fn demonstrate_polygon_query(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 2: Polygon Query ===");
@@ -186,7 +186,7 @@ async fn demonstrate_polygon_query(
 
 /// Demonstrates combining spatial and attribute queries.
 async fn demonstrate_combined_spatial_attribute(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 3: Combined Spatial + Attribute Query ===");
@@ -240,7 +240,7 @@ async fn demonstrate_combined_spatial_attribute(
 
 /// Demonstrates different spatial relationship types.
 async fn demonstrate_spatial_relationships(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 4: Different Spatial Relationships ===");
@@ -291,7 +291,7 @@ async fn demonstrate_spatial_relationships(
 
 /// Demonstrates large area queries with automatic pagination.
 async fn demonstrate_large_area_pagination(
-    service: &FeatureServiceClient<'_>,
+    service: &FeatureServiceClient,
     layer_id: LayerId,
 ) -> anyhow::Result<()> {
     tracing::info!("\n=== Example 5: Large Area with Auto-Pagination ===");
@@ -110,7 +110,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates retrieving and inspecting the Mapbox GL style document.
-async fn demonstrate_style_retrieval(vt_service: &VectorTileServiceClient<'_>) -> Result<()> {
+async fn demonstrate_style_retrieval(vt_service: &VectorTileServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 1: Style Document Retrieval ===");
     tracing::info!("Get Mapbox GL style JSON for rendering configuration");
     tracing::info!("");
@@ -170,7 +170,7 @@ async fn demonstrate_style_retrieval(vt_service: &VectorTileServiceClient<'_>) -
 }
 
 /// Demonstrates fetching a single vector tile.
-async fn demonstrate_single_tile(vt_service: &VectorTileServiceClient<'_>) -> Result<()> {
+async fn demonstrate_single_tile(vt_service: &VectorTileServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Single Tile Fetch ===");
     tracing::info!("Download one MVT tile in Protocol Buffer format");
     tracing::info!("");
@@ -203,7 +203,7 @@ async fn demonstrate_single_tile(vt_service: &VectorTileServiceClient<'_>) -> Re
 }
 
 /// Demonstrates batch fetching multiple tiles efficiently.
-async fn demonstrate_batch_tiles(vt_service: &VectorTileServiceClient<'_>) -> Result<()> {
+async fn demonstrate_batch_tiles(vt_service: &VectorTileServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Batch Tile Fetch ===");
     tracing::info!("Download multiple tiles in a single operation");
     tracing::info!("");
@@ -255,7 +255,7 @@ async fn demonstrate_batch_tiles(vt_service: &VectorTileServiceClient<'_>) -> Re
 }
 
 /// Demonstrates downloading font glyphs for text rendering.
-async fn demonstrate_font_glyphs(vt_service: &VectorTileServiceClient<'_>) -> Result<()> {
+async fn demonstrate_font_glyphs(vt_service: &VectorTileServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Font Glyph Retrieval ===");
     tracing::info!("Download font data for rendering text labels");
     tracing::info!("");
@@ -305,7 +305,7 @@ async fn demonstrate_font_glyphs(vt_service: &VectorTileServiceClient<'_>) -> Re
 }
 
 /// Demonstrates retrieving sprite resources (icons and symbols).
-async fn demonstrate_sprite_resources(vt_service: &VectorTileServiceClient<'_>) -> Result<()> {
+async fn demonstrate_sprite_resources(vt_service: &VectorTileServiceClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Sprite Sheet Resources ===");
     tracing::info!("Download icon/symbol sprite metadata and images");
     tracing::info!("");
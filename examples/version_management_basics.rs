@@ -189,7 +189,7 @@ async fn main() -> Result<()> {
 }
 
 /// Demonstrates listing all versions in the service.
-async fn demonstrate_list_versions(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_list_versions(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 1: List All Versions ===");
     tracing::info!("Query all versions available in the service");
     tracing::info!("");
@@ -243,7 +243,7 @@ async fn demonstrate_list_versions(vm_client: &VersionManagementClient<'_>) -> R
 }
 
 /// Demonstrates creating a new version.
-async fn demonstrate_create_version(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_create_version(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 2: Create Named Version ===");
     tracing::info!("Create a new version (branch) for isolated editing");
     tracing::info!("");
@@ -297,7 +297,7 @@ async fn demonstrate_create_version(vm_client: &VersionManagementClient<'_>) ->
 }
 
 /// Demonstrates querying version metadata.
-async fn demonstrate_version_metadata(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_version_metadata(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 3: Query Version Metadata ===");
     tracing::info!("Get detailed information about a specific version");
     tracing::info!("");
@@ -362,7 +362,7 @@ async fn demonstrate_version_metadata(vm_client: &VersionManagementClient<'_>) -
 }
 
 /// Demonstrates edit session workflow.
-async fn demonstrate_edit_session(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_edit_session(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 4: Edit Session Workflow ===");
     tracing::info!("Start and stop editing session on a version");
     tracing::info!("");
@@ -442,7 +442,7 @@ async fn demonstrate_edit_session(vm_client: &VersionManagementClient<'_>) -> Re
 }
 
 /// Demonstrates altering version properties.
-async fn demonstrate_alter_version(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_alter_version(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 5: Alter Version Properties ===");
     tracing::info!("Modify version name, description, and access level");
     tracing::info!("");
@@ -508,7 +508,7 @@ async fn demonstrate_alter_version(vm_client: &VersionManagementClient<'_>) -> R
 }
 
 /// Demonstrates read session workflow.
-async fn demonstrate_read_session(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_read_session(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 6: Read Session Workflow ===");
     tracing::info!("Start and stop read session for consistent data view");
     tracing::info!("");
@@ -582,7 +582,7 @@ async fn demonstrate_read_session(vm_client: &VersionManagementClient<'_>) -> Re
 }
 
 /// Demonstrates differences utility for comparing versions.
-async fn demonstrate_differences(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_differences(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 7: Compare Version Differences ===");
     tracing::info!("Retrieve differences between version and DEFAULT");
     tracing::info!("");
@@ -659,7 +659,7 @@ async fn demonstrate_differences(vm_client: &VersionManagementClient<'_>) -> Res
 }
 
 /// Demonstrates delete forward edits (undo functionality).
-async fn demonstrate_delete_forward_edits(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_delete_forward_edits(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 8: Delete Forward Edits (Undo) ===");
     tracing::info!("Demonstrate undo functionality by deleting edits after a checkpoint");
     tracing::info!("");
@@ -741,7 +741,7 @@ async fn demonstrate_delete_forward_edits(vm_client: &VersionManagementClient<'_
 }
 
 /// Demonstrates reconcile and post workflow.
-async fn demonstrate_reconcile_and_post(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_reconcile_and_post(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 9: Reconcile and Post Workflow ===");
     tracing::info!("Merge changes with DEFAULT and post edits");
     tracing::info!("");
@@ -853,7 +853,7 @@ async fn demonstrate_reconcile_and_post(vm_client: &VersionManagementClient<'_>)
 }
 
 /// Demonstrates conflict management operations.
-async fn demonstrate_conflict_management(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_conflict_management(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 10: Conflict Management ===");
     tracing::info!("Query conflicts, inspect them, and resolve with restore_rows");
     tracing::info!("");
@@ -987,7 +987,7 @@ async fn demonstrate_conflict_management(vm_client: &VersionManagementClient<'_>
 }
 
 /// Demonstrates deleting a version.
-async fn demonstrate_delete_version(vm_client: &VersionManagementClient<'_>) -> Result<()> {
+async fn demonstrate_delete_version(vm_client: &VersionManagementClient) -> Result<()> {
     tracing::info!("\n=== Example 11: Delete Version ===");
     tracing::info!("Permanently remove a version from the geodatabase");
     tracing::info!("");
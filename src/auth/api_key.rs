@@ -1,6 +1,6 @@
 //! API Key authentication provider.
 
-use crate::{AuthProvider, Result};
+use crate::{AuthProvider, Result, ServiceKind};
 use async_trait::async_trait;
 use secrecy::{ExposeSecret, SecretString};
 use tracing::instrument;
@@ -18,6 +18,35 @@ pub enum ApiKeyTier {
     Public,
 }
 
+impl ApiKeyTier {
+    /// Returns the tier whose key should authenticate requests to `kind`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arcgis::{ApiKeyTier, ServiceKind};
+    ///
+    /// assert_eq!(ApiKeyTier::for_service(ServiceKind::Geocode), ApiKeyTier::Location);
+    /// assert_eq!(ApiKeyTier::for_service(ServiceKind::Feature), ApiKeyTier::Features);
+    /// ```
+    pub fn for_service(kind: ServiceKind) -> Self {
+        match kind {
+            ServiceKind::Geocode
+            | ServiceKind::Routing
+            | ServiceKind::Geometry
+            | ServiceKind::Geoprocessing
+            | ServiceKind::Elevation
+            | ServiceKind::Places => Self::Location,
+            ServiceKind::Feature | ServiceKind::VersionManagement => Self::Features,
+            ServiceKind::Portal => Self::Content,
+            ServiceKind::Map
+            | ServiceKind::Image
+            | ServiceKind::VectorTile
+            | ServiceKind::Other => Self::Public,
+        }
+    }
+}
+
 /// API Key authentication provider.
 ///
 /// This is the simplest authentication method for ArcGIS services.
@@ -189,6 +218,11 @@ impl AuthProvider for ApiKeyAuth {
         Ok(self.api_key.expose_secret().to_string())
     }
 
+    /// Returns the API key, which identifies its owner and scope.
+    fn principal(&self, _url: &str) -> Option<String> {
+        Some(self.api_key.expose_secret().to_string())
+    }
+
     #[instrument(skip(self))]
     fn requires_token_param(&self) -> bool {
         true
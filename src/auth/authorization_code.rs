@@ -0,0 +1,823 @@
+//! OAuth 2.0 Authorization Code Flow with PKCE for signing in as a user.
+//!
+//! This module implements the authorization code grant with Proof Key for
+//! Code Exchange (PKCE), the flow ArcGIS recommends for desktop and command
+//! line applications where users sign in with their own ArcGIS account.
+//!
+//! # How It Works
+//!
+//! 1. A one-shot HTTP listener is opened on `127.0.0.1`
+//! 2. The user opens the authorization URL and signs in to the portal
+//! 3. The portal redirects the browser to the listener with a code
+//! 4. The code is exchanged for an access token and a refresh token
+//! 5. Access tokens are renewed with the refresh token as they expire
+//!
+//! # Security
+//!
+//! - No client secret is needed; PKCE (S256) protects the code exchange
+//! - The `state` parameter is checked and forged redirects are ignored
+//! - The listener only binds the loopback interface
+//! - The refresh token is held as a [`SecretString`] and never logged
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::{ArcGISClient, AuthorizationCodeAuth};
+//!
+//! # fn example() -> arcgis::Result<()> {
+//! // Register http://127.0.0.1:7890/callback as a redirect URI of the app
+//! let auth = AuthorizationCodeAuth::builder("your_client_id")
+//!     .redirect_port(7890)
+//!     .print_authorize_url()
+//!     .build()?;
+//!
+//! // The first request opens the sign-in flow
+//! let client = ArcGISClient::new(auth);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::portal::{ARCGIS_ONLINE_PORTAL, build_token_client, sharing_rest_url};
+use super::token_store::{PersistedToken, StoreSlot, TokenKey, TokenStore};
+use crate::{AuthProvider, HttpConfig, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use oauth2::basic::{
+    BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse,
+    BasicTokenType,
+};
+use oauth2::{
+    AccessToken, AuthType, AuthUrl, AuthorizationCode, ClientId, CsrfToken, EndpointNotSet,
+    EndpointSet, HttpRequest, HttpResponse, PkceCodeChallenge, RedirectUrl, RefreshToken,
+    RequestTokenError, Scope, StandardRevocableToken, TokenResponse, TokenUrl,
+};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tracing::instrument;
+
+/// Path the portal redirects to on the loopback listener.
+const CALLBACK_PATH: &str = "/callback";
+
+/// How long to wait for the user to finish signing in by default.
+const DEFAULT_SIGN_IN_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Access token lifetime assumed when the portal does not report one.
+const DEFAULT_TOKEN_LIFETIME: Duration = Duration::from_secs(1800);
+
+/// Longest margin before expiry at which the access token is renewed.
+const REFRESH_BUFFER: Duration = Duration::from_secs(300);
+
+/// Largest callback request head read from the browser.
+const MAX_REQUEST_HEAD: usize = 16 * 1024;
+
+/// How long a connection to the listener may take to send its request line.
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Receives the authorization URL the user must open to sign in.
+pub type AuthorizeUrlHandler = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Token response from the ArcGIS OAuth endpoint.
+///
+/// ArcGIS omits `token_type`, which `oauth2`'s standard response requires.
+#[derive(Debug, Deserialize, Serialize)]
+struct ArcGISTokenResponse {
+    access_token: AccessToken,
+    #[serde(default = "bearer")]
+    token_type: BasicTokenType,
+    expires_in: Option<u64>,
+    refresh_token: Option<RefreshToken>,
+}
+
+fn bearer() -> BasicTokenType {
+    BasicTokenType::Bearer
+}
+
+impl TokenResponse for ArcGISTokenResponse {
+    type TokenType = BasicTokenType;
+
+    fn access_token(&self) -> &AccessToken {
+        &self.access_token
+    }
+
+    fn token_type(&self) -> &BasicTokenType {
+        &self.token_type
+    }
+
+    fn expires_in(&self) -> Option<Duration> {
+        self.expires_in.map(Duration::from_secs)
+    }
+
+    fn refresh_token(&self) -> Option<&RefreshToken> {
+        self.refresh_token.as_ref()
+    }
+
+    fn scopes(&self) -> Option<&Vec<Scope>> {
+        None
+    }
+}
+
+/// `oauth2` client configured with ArcGIS endpoints.
+type OAuthClient = oauth2::Client<
+    BasicErrorResponse,
+    ArcGISTokenResponse,
+    BasicTokenIntrospectionResponse,
+    StandardRevocableToken,
+    BasicRevocationErrorResponse,
+    EndpointSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointSet,
+>;
+
+/// Access token with renewal tracking.
+#[derive(Debug, Clone)]
+struct StoredToken {
+    /// The access token
+    access_token: String,
+    /// When the token should be renewed
+    refresh_at: Instant,
+    /// When the token expires
+    expires_at: DateTime<Utc>,
+}
+
+impl StoredToken {
+    /// Creates a token with the given remaining lifetime.
+    fn new(access_token: String, lifetime: Duration) -> Self {
+        let buffer = REFRESH_BUFFER.min(lifetime / 5);
+        Self {
+            access_token,
+            refresh_at: Instant::now() + (lifetime - buffer),
+            expires_at: Utc::now() + chrono::Duration::from_std(lifetime).unwrap_or_default(),
+        }
+    }
+}
+
+/// Tokens of the signed-in user.
+#[derive(Default)]
+struct Session {
+    /// Current access token
+    access: Option<StoredToken>,
+    /// Refresh token from the last sign-in (kept confidential, never logged)
+    refresh_token: Option<SecretString>,
+}
+
+/// OAuth 2.0 authorization code + PKCE authentication provider.
+///
+/// Signs in a user through their browser and acts on their behalf. This
+/// provider:
+///
+/// - Runs the sign-in flow on first use, or when no refresh token is valid
+/// - Receives the redirect on a loopback listener (`127.0.0.1`)
+/// - Keeps the refresh token and renews access tokens before they expire
+/// - Is thread-safe; concurrent requests share one sign-in or renewal
+///
+/// The authorization URL is passed to the handler set with
+/// [`AuthorizationCodeAuthBuilder::on_authorize_url`]; by default it is
+/// only logged at `INFO` level. Call
+/// [`AuthorizationCodeAuthBuilder::print_authorize_url`] to print it instead.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{AuthProvider, AuthorizationCodeAuth};
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let auth = AuthorizationCodeAuth::builder("your_client_id")
+///     .portal_url("https://gis.example.com/portal")
+///     .redirect_port(7890)
+///     .on_authorize_url(|url| println!("Sign in at {}", url))
+///     .build()?;
+///
+/// auth.sign_in().await?;
+/// let token = auth.get_token().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AuthorizationCodeAuth {
+    /// Client ID of the registered application
+    client_id: String,
+    /// Portal authorization endpoint
+    authorize_url: String,
+    /// Portal token endpoint
+    token_url: String,
+    /// Loopback listener port (0 for any free port)
+    redirect_port: u16,
+    /// How long to wait for the user to finish signing in
+    sign_in_timeout: Duration,
+    /// Receives the authorization URL
+    on_authorize_url: AuthorizeUrlHandler,
+    /// HTTP client with security configuration
+    http_client: reqwest::Client,
+    /// Tokens, locked across sign-in and renewal
+    session: Arc<Mutex<Session>>,
+    /// Persistent token store, if configured
+    store: Option<StoreSlot>,
+}
+
+impl std::fmt::Debug for AuthorizationCodeAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthorizationCodeAuth")
+            .field("client_id", &self.client_id)
+            .field("authorize_url", &self.authorize_url)
+            .field("token_url", &self.token_url)
+            .field("redirect_port", &self.redirect_port)
+            .field("sign_in_timeout", &self.sign_in_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuthorizationCodeAuth {
+    /// Creates a builder for the given application client ID.
+    ///
+    /// Without further configuration, users sign in to ArcGIS Online and the
+    /// redirect listener uses any free port. Apps registered with a fixed
+    /// redirect URI should set [`redirect_port`](AuthorizationCodeAuthBuilder::redirect_port).
+    pub fn builder(client_id: impl Into<String>) -> AuthorizationCodeAuthBuilder {
+        AuthorizationCodeAuthBuilder {
+            client_id: client_id.into(),
+            portal_url: ARCGIS_ONLINE_PORTAL.to_string(),
+            redirect_port: 0,
+            sign_in_timeout: DEFAULT_SIGN_IN_TIMEOUT,
+            on_authorize_url: None,
+            refresh_token: None,
+            store: None,
+        }
+    }
+
+    /// Returns the OAuth token endpoint this provider requests tokens from.
+    pub fn token_url(&self) -> &str {
+        &self.token_url
+    }
+
+    /// Returns the current refresh token, if the user has signed in.
+    ///
+    /// Persist it and pass it to
+    /// [`AuthorizationCodeAuthBuilder::refresh_token`] to resume the session
+    /// later without signing in again.
+    pub async fn refresh_token(&self) -> Option<SecretString> {
+        self.session.lock().await.refresh_token.clone()
+    }
+
+    /// Runs the interactive sign-in flow, replacing any existing session.
+    ///
+    /// Opens the loopback listener, passes the authorization URL to the
+    /// handler, waits for the redirect, and exchanges the code for tokens.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be bound, the user denies
+    /// access, the redirect does not arrive within the sign-in timeout, or
+    /// the code exchange fails.
+    #[instrument(skip(self))]
+    pub async fn sign_in(&self) -> Result<()> {
+        let mut session = self.session.lock().await;
+        self.sign_in_locked(&mut session).await
+    }
+
+    /// Builds the `oauth2` client for the given redirect URI.
+    fn oauth_client(&self, redirect_uri: Option<String>) -> Result<OAuthClient> {
+        let client = oauth2::Client::new(ClientId::new(self.client_id.clone()))
+            .set_auth_type(AuthType::RequestBody)
+            .set_auth_uri(AuthUrl::new(self.authorize_url.clone())?)
+            .set_token_uri(TokenUrl::new(self.token_url.clone())?);
+        Ok(match redirect_uri {
+            Some(uri) => client.set_redirect_uri(RedirectUrl::new(uri)?),
+            None => client,
+        })
+    }
+
+    /// Runs the sign-in flow with the session lock held.
+    async fn sign_in_locked(&self, session: &mut Session) -> Result<()> {
+        tracing::debug!("Starting authorization code sign-in");
+
+        let listener = TcpListener::bind(("127.0.0.1", self.redirect_port)).await?;
+        let redirect_uri = format!(
+            "http://127.0.0.1:{}{}",
+            listener.local_addr()?.port(),
+            CALLBACK_PATH
+        );
+        let client = self.oauth_client(Some(redirect_uri.clone()))?;
+
+        let (challenge, verifier) = PkceCodeChallenge::new_random_sha256();
+        let (authorize_url, state) = client
+            .authorize_url(CsrfToken::new_random)
+            .set_pkce_challenge(challenge)
+            .url();
+
+        tracing::info!(redirect_uri = %redirect_uri, "Waiting for user to sign in");
+        (self.on_authorize_url)(authorize_url.as_str());
+
+        let code = tokio::time::timeout(
+            self.sign_in_timeout,
+            receive_code(&listener, state.secret()),
+        )
+        .await
+        .map_err(|_| {
+            crate::Error::from(crate::ErrorKind::OAuth(format!(
+                "Sign-in not completed within {:?}",
+                self.sign_in_timeout
+            )))
+        })??;
+
+        tracing::debug!("Exchanging authorization code for tokens");
+        let http = self.http_client.clone();
+        let response = client
+            .exchange_code(AuthorizationCode::new(code))
+            .set_pkce_verifier(verifier)
+            .request_async(&|request| send_token_request(http.clone(), request))
+            .await
+            .map_err(token_error)?;
+
+        self.store(session, response);
+        self.persist(session).await;
+        tracing::info!("User signed in");
+        Ok(())
+    }
+
+    /// Exchanges the refresh token for a new access token.
+    ///
+    /// Returns `Ok(false)` if the portal rejected the refresh token.
+    async fn renew_locked(
+        &self,
+        session: &mut Session,
+        refresh_token: &SecretString,
+    ) -> Result<bool> {
+        tracing::debug!("Renewing access token with refresh token");
+
+        let client = self.oauth_client(None)?;
+        let refresh_token = RefreshToken::new(refresh_token.expose_secret().to_string());
+        let http = self.http_client.clone();
+        let result = client
+            .exchange_refresh_token(&refresh_token)
+            .request_async(&|request| send_token_request(http.clone(), request))
+            .await;
+
+        match result {
+            Ok(response) => {
+                self.store(session, response);
+                self.persist(session).await;
+                Ok(true)
+            }
+            Err(RequestTokenError::ServerResponse(e)) => {
+                tracing::warn!(error = %e, "Refresh token rejected");
+                session.refresh_token = None;
+                if let Some(slot) = &self.store {
+                    slot.evict().await;
+                }
+                Ok(false)
+            }
+            Err(e) => Err(token_error(e)),
+        }
+    }
+
+    /// Stores the tokens of a successful exchange.
+    ///
+    /// Refresh responses usually omit the refresh token; the existing one is kept.
+    fn store(&self, session: &mut Session, response: ArcGISTokenResponse) {
+        let lifetime = response.expires_in().unwrap_or(DEFAULT_TOKEN_LIFETIME);
+
+        tracing::info!(expires_in = lifetime.as_secs(), "Access token obtained");
+
+        session.access = Some(StoredToken::new(
+            response.access_token.secret().clone(),
+            lifetime,
+        ));
+        if let Some(refresh_token) = response.refresh_token {
+            session.refresh_token = Some(SecretString::new(
+                refresh_token.into_secret().into_boxed_str(),
+            ));
+        }
+    }
+
+    /// Writes the session's tokens to the token store, if configured.
+    async fn persist(&self, session: &Session) {
+        let (Some(slot), Some(access)) = (&self.store, &session.access) else {
+            return;
+        };
+        let mut persisted = PersistedToken::new(access.access_token.clone(), access.expires_at);
+        if let Some(refresh_token) = &session.refresh_token {
+            persisted = persisted.with_refresh_token(refresh_token.expose_secret());
+        }
+        slot.save(persisted).await;
+    }
+
+    /// Restores tokens from the token store, if configured.
+    ///
+    /// A stored refresh token is only used when the session has none.
+    async fn restore_locked(&self, session: &mut Session) {
+        let Some(slot) = &self.store else {
+            return;
+        };
+        let Some(persisted) = slot.load().await else {
+            return;
+        };
+
+        if session.refresh_token.is_none() {
+            session.refresh_token = persisted
+                .refresh_token()
+                .as_ref()
+                .map(|token| SecretString::new(token.clone().into_boxed_str()));
+        }
+        let remaining = persisted.remaining();
+        if !remaining.is_zero() {
+            tracing::debug!("Restored access token from store");
+            session.access = Some(StoredToken::new(persisted.token().clone(), remaining));
+        }
+    }
+}
+
+/// Sends an `oauth2` token request through the SDK's HTTP client.
+///
+/// ArcGIS reports token errors with HTTP 200, sometimes labelled
+/// `text/plain`. OAuth errors (`invalid_grant` etc.), including ESRI's nested
+/// `{"error": {"error": ...}}` form, are handed to `oauth2` in the flat RFC
+/// 6749 form with an error status; other ESRI errors in successful responses
+/// are surfaced as SDK errors. JSON bodies are relabelled so `oauth2` accepts them.
+async fn send_token_request(http: reqwest::Client, request: HttpRequest) -> Result<HttpResponse> {
+    let request = reqwest::Request::try_from(request)?;
+    let response = http.execute(request).await?;
+
+    let mut status = response.status();
+    let mut builder = oauth2::http::Response::builder();
+    for (name, value) in response.headers() {
+        if name != reqwest::header::CONTENT_TYPE {
+            builder = builder.header(name, value);
+        }
+    }
+    let mut body = response.bytes().await?.to_vec();
+    if body.trim_ascii_start().starts_with(b"{") {
+        if let Some(error) = oauth_error(&body) {
+            tracing::debug!("Token endpoint returned an OAuth error");
+            if status.is_success() {
+                status = reqwest::StatusCode::BAD_REQUEST;
+            }
+            body = error;
+        } else if status.is_success() {
+            crate::check_esri_error(&String::from_utf8_lossy(&body), "oauth2/token")?;
+        }
+        builder = builder.header(reqwest::header::CONTENT_TYPE, "application/json");
+    }
+
+    builder
+        .status(status)
+        .body(body)
+        .map_err(|e| crate::ErrorKind::OAuth(format!("Invalid token response: {}", e)).into())
+}
+
+/// Returns the body in the flat RFC 6749 error form if it carries an OAuth
+/// error code, either as `{"error": "invalid_grant"}` or nested as ESRI
+/// reports it: `{"error": {"code": 400, "error": "invalid_grant", ...}}`.
+fn oauth_error(body: &[u8]) -> Option<Vec<u8>> {
+    let value: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let error = value.get("error")?;
+    if error.is_string() {
+        return Some(body.to_vec());
+    }
+    let code = error.get("error")?.as_str()?;
+    let description = error
+        .get("error_description")
+        .or_else(|| error.get("message"))
+        .and_then(serde_json::Value::as_str);
+
+    let mut flat = serde_json::json!({ "error": code });
+    if let Some(description) = description {
+        flat["error_description"] = description.into();
+    }
+    serde_json::to_vec(&flat).ok()
+}
+
+/// Converts an `oauth2` token request error into an SDK error.
+fn token_error(error: RequestTokenError<crate::Error, BasicErrorResponse>) -> crate::Error {
+    match error {
+        RequestTokenError::Request(e) => e,
+        RequestTokenError::ServerResponse(e) => {
+            crate::ErrorKind::OAuth(format!("Token request rejected: {}", e)).into()
+        }
+        RequestTokenError::Parse(e, _) => {
+            crate::ErrorKind::OAuth(format!("Failed to parse token response: {}", e)).into()
+        }
+        RequestTokenError::Other(message) => crate::ErrorKind::OAuth(message).into(),
+    }
+}
+
+/// Waits for the redirect on the loopback listener and returns the code.
+///
+/// Requests to other paths (e.g. `/favicon.ico`), redirects with the wrong
+/// `state`, and connections that stall or fail are ignored.
+async fn receive_code(listener: &TcpListener, expected_state: &str) -> Result<String> {
+    let base = url::Url::parse("http://127.0.0.1")?;
+    loop {
+        let (mut stream, peer) = listener.accept().await?;
+        tracing::debug!(peer = %peer, "Redirect listener accepted connection");
+
+        let target = match tokio::time::timeout(
+            REQUEST_READ_TIMEOUT,
+            read_request_target(&mut stream),
+        )
+        .await
+        {
+            Ok(Ok(Some(target))) => target,
+            Ok(Ok(None)) => continue,
+            Ok(Err(e)) => {
+                tracing::debug!(peer = %peer, error = %e, "Failed to read redirect request");
+                continue;
+            }
+            Err(_) => {
+                tracing::debug!(peer = %peer, "Timed out reading redirect request");
+                continue;
+            }
+        };
+        let Ok(url) = base.join(&target) else {
+            respond(&mut stream, "400 Bad Request", "Bad request.").await;
+            continue;
+        };
+        if url.path() != CALLBACK_PATH {
+            respond(&mut stream, "404 Not Found", "Not found.").await;
+            continue;
+        }
+
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        if params.get("state").map(String::as_str) != Some(expected_state) {
+            tracing::warn!(peer = %peer, "Ignoring redirect with mismatched state");
+            respond(
+                &mut stream,
+                "400 Bad Request",
+                "Sign-in response not recognized.",
+            )
+            .await;
+            continue;
+        }
+        if let Some(error) = params.get("error") {
+            respond(
+                &mut stream,
+                "400 Bad Request",
+                "Sign-in failed. You can close this window.",
+            )
+            .await;
+            let description = params.get("error_description").unwrap_or(error);
+            return Err(
+                crate::ErrorKind::OAuth(format!("Authorization denied: {}", description)).into(),
+            );
+        }
+        let Some(code) = params.get("code") else {
+            respond(
+                &mut stream,
+                "400 Bad Request",
+                "Sign-in failed. You can close this window.",
+            )
+            .await;
+            return Err(
+                crate::ErrorKind::OAuth("Authorization response has no code".to_string()).into(),
+            );
+        };
+
+        respond(
+            &mut stream,
+            "200 OK",
+            "Sign-in complete. You can close this window.",
+        )
+        .await;
+        return Ok(code.clone());
+    }
+}
+
+/// Reads the request line and returns its target (path and query).
+async fn read_request_target(stream: &mut TcpStream) -> Result<Option<String>> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(2).any(|window| window == b"\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 || head.len() > MAX_REQUEST_HEAD {
+            return Ok(None);
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    Ok(head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .map(str::to_string))
+}
+
+/// Writes a minimal plain-text response to the browser.
+async fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        message.len(),
+        message
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        tracing::debug!(error = %e, "Failed to answer redirect");
+    }
+    let _ = stream.shutdown().await;
+}
+
+#[async_trait]
+impl AuthProvider for AuthorizationCodeAuth {
+    /// Returns the current access token, renewing it or signing in as needed.
+    #[instrument(skip(self))]
+    async fn get_token(&self) -> Result<String> {
+        let mut session = self.session.lock().await;
+
+        if session.access.is_none() {
+            self.restore_locked(&mut session).await;
+        }
+        if let Some(access) = &session.access {
+            if Instant::now() < access.refresh_at {
+                tracing::debug!("Returning cached access token");
+                return Ok(access.access_token.clone());
+            }
+        }
+
+        let renewed = match session.refresh_token.clone() {
+            Some(refresh_token) => self.renew_locked(&mut session, &refresh_token).await?,
+            None => false,
+        };
+        if !renewed {
+            self.sign_in_locked(&mut session).await?;
+        }
+
+        session
+            .access
+            .as_ref()
+            .map(|access| access.access_token.clone())
+            .ok_or_else(|| {
+                crate::ErrorKind::OAuth("Token missing after successful exchange".to_string())
+                    .into()
+            })
+    }
+
+    /// Marks the cached access token for renewal if it is the one the server
+    /// rejected.
+    ///
+    /// The next request renews it with the refresh token, so a replay is only
+    /// worthwhile when one is held; without it the user would have to sign in.
+    #[instrument(skip(self, token))]
+    async fn invalidate_token(&self, token: &str) -> bool {
+        let mut session = self.session.lock().await;
+        let has_refresh_token = session.refresh_token.is_some();
+        match session.access.as_mut() {
+            Some(access) if access.access_token == token => {
+                // Keep the token so concurrent rejections recognize it
+                if Instant::now() < access.refresh_at {
+                    tracing::debug!("Discarding rejected access token");
+                    access.refresh_at = Instant::now();
+                    if let Some(slot) = &self.store {
+                        slot.evict().await;
+                    }
+                }
+                has_refresh_token
+            }
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Rebuilds the token HTTP client with the client's transport settings.
+    #[instrument(skip(self, config))]
+    fn configure_http(&mut self, config: &HttpConfig) -> Result<()> {
+        tracing::debug!("Applying HTTP settings to token client");
+        self.http_client = build_token_client(config)?;
+        Ok(())
+    }
+}
+
+/// Builder for [`AuthorizationCodeAuth`].
+///
+/// Created by [`AuthorizationCodeAuth::builder`].
+pub struct AuthorizationCodeAuthBuilder {
+    client_id: String,
+    portal_url: String,
+    redirect_port: u16,
+    sign_in_timeout: Duration,
+    on_authorize_url: Option<AuthorizeUrlHandler>,
+    refresh_token: Option<SecretString>,
+    store: Option<Arc<dyn TokenStore>>,
+}
+
+impl std::fmt::Debug for AuthorizationCodeAuthBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthorizationCodeAuthBuilder")
+            .field("client_id", &self.client_id)
+            .field("portal_url", &self.portal_url)
+            .field("redirect_port", &self.redirect_port)
+            .field("sign_in_timeout", &self.sign_in_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuthorizationCodeAuthBuilder {
+    /// Signs users in to the given portal.
+    ///
+    /// Accepts the portal root (`https://host/portal`) or its sharing API
+    /// (`https://host/portal/sharing/rest`). Defaults to ArcGIS Online.
+    pub fn portal_url(mut self, portal_url: impl AsRef<str>) -> Self {
+        self.portal_url = sharing_rest_url(portal_url.as_ref());
+        self
+    }
+
+    /// Sets the loopback listener port.
+    ///
+    /// The redirect URI is `http://127.0.0.1:<port>/callback` and must be
+    /// registered with the application. Defaults to 0 (any free port).
+    pub fn redirect_port(mut self, port: u16) -> Self {
+        self.redirect_port = port;
+        self
+    }
+
+    /// Sets how long to wait for the user to finish signing in. Defaults to 5 minutes.
+    pub fn sign_in_timeout(mut self, timeout: Duration) -> Self {
+        self.sign_in_timeout = timeout;
+        self
+    }
+
+    /// Sets the handler that presents the authorization URL to the user,
+    /// e.g. by opening a browser.
+    ///
+    /// Defaults to logging the URL at `INFO` level.
+    pub fn on_authorize_url(mut self, handler: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.on_authorize_url = Some(Arc::new(handler));
+        self
+    }
+
+    /// Prints the authorization URL to standard error for the user to open.
+    ///
+    /// Suited to command line tools; replaces any handler set with
+    /// [`on_authorize_url`](Self::on_authorize_url).
+    pub fn print_authorize_url(self) -> Self {
+        self.on_authorize_url(|url| {
+            eprintln!("Open this URL in your browser to sign in:\n{}", url);
+        })
+    }
+
+    /// Resumes a session from a previously issued refresh token.
+    ///
+    /// The first access token is obtained with it instead of an interactive
+    /// sign-in. If the portal rejects it, the user is asked to sign in.
+    pub fn refresh_token(mut self, refresh_token: impl Into<String>) -> Self {
+        self.refresh_token = Some(SecretString::new(refresh_token.into().into_boxed_str()));
+        self
+    }
+
+    /// Keeps tokens in `store`, so a later run reuses a valid access token
+    /// or resumes the session with the stored refresh token instead of
+    /// asking the user to sign in again.
+    pub fn token_store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Builds the provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    #[instrument(skip(self), fields(client_id = %self.client_id))]
+    pub fn build(self) -> Result<AuthorizationCodeAuth> {
+        tracing::debug!("Creating authorization code authenticator");
+
+        let http_client = build_token_client(&HttpConfig::default())?;
+        let on_authorize_url = self.on_authorize_url.unwrap_or_else(|| {
+            Arc::new(|url: &str| {
+                tracing::info!(url = %url, "Open this URL in a browser to sign in");
+            })
+        });
+
+        let token_url = format!("{}/oauth2/token", self.portal_url);
+        let store = self.store.map(|store| {
+            StoreSlot::new(
+                store,
+                TokenKey::new(
+                    "authorization_code",
+                    self.client_id.clone(),
+                    token_url.clone(),
+                ),
+            )
+        });
+
+        Ok(AuthorizationCodeAuth {
+            client_id: self.client_id,
+            authorize_url: format!("{}/oauth2/authorize", self.portal_url),
+            token_url,
+            redirect_port: self.redirect_port,
+            sign_in_timeout: self.sign_in_timeout,
+            on_authorize_url,
+            http_client,
+            session: Arc::new(Mutex::new(Session {
+                access: None,
+                refresh_token: self.refresh_token,
+            })),
+            store,
+        })
+    }
+}
@@ -18,6 +18,13 @@
 //! - Automatic token refresh before expiration
 //! - HTTPS required (enforced by ArcGIS)
 //!
+//! # ArcGIS Enterprise
+//!
+//! Tokens are requested from ArcGIS Online by default. For an Enterprise
+//! portal, set the portal URL with [`ClientCredentialsAuth::builder`], set
+//! `ARCGIS_ENTERPRISE_PORTAL` for [`ClientCredentialsAuth::from_env`], or
+//! discover it from a federated server's `rest/info`.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -33,7 +40,11 @@
 //! # }
 //! ```
 
-use crate::{AuthProvider, Result};
+use super::portal::{
+    ARCGIS_ONLINE_PORTAL, build_token_client, discover_portal_url, sharing_rest_url,
+};
+use super::token_store::{PersistedToken, StoreSlot, TokenKey, TokenStore};
+use crate::{AuthProvider, HttpConfig, Result};
 use async_trait::async_trait;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
@@ -69,6 +80,7 @@ struct StoredToken {
 ///
 /// - Fetches tokens automatically on first use
 /// - Refreshes tokens automatically before expiration
+/// - Refreshes early when the server rejects a token (ESRI code 498)
 /// - Requires no human interaction or browser
 /// - Is thread-safe for concurrent use
 ///
@@ -104,10 +116,14 @@ pub struct ClientCredentialsAuth {
     client_id: String,
     /// Client secret (kept confidential, never logged)
     client_secret: SecretString,
+    /// OAuth token endpoint
+    token_url: String,
     /// HTTP client with security configuration
     http_client: reqwest::Client,
     /// Stored access token
     token: Arc<RwLock<Option<StoredToken>>>,
+    /// Persistent token store, if configured
+    store: Option<StoreSlot>,
 }
 
 impl ClientCredentialsAuth {
@@ -143,21 +159,48 @@ impl ClientCredentialsAuth {
     /// ```
     #[instrument(skip(client_id, client_secret))]
     pub fn new(client_id: String, client_secret: String) -> Result<Self> {
-        tracing::debug!("Creating OAuth Client Credentials authenticator");
-
-        // Security: disable redirects to prevent SSRF vulnerabilities
-        let http_client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
-            .build()?;
+        Self::builder(client_id, client_secret).build()
+    }
 
-        tracing::debug!("OAuth Client Credentials authenticator created");
+    /// Creates a builder for configuring the portal or token endpoint.
+    ///
+    /// Without further configuration, tokens are requested from ArcGIS Online.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::ClientCredentialsAuth;
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// // Explicit Enterprise portal
+    /// let auth = ClientCredentialsAuth::builder("client_id", "client_secret")
+    ///     .portal_url("https://gis.example.com/portal")
+    ///     .build()?;
+    ///
+    /// // Portal discovered from a federated server
+    /// let auth = ClientCredentialsAuth::builder("client_id", "client_secret")
+    ///     .discover_portal("https://gis.example.com/server")
+    ///     .await?
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> ClientCredentialsAuthBuilder {
+        ClientCredentialsAuthBuilder {
+            client_id: client_id.into(),
+            client_secret: SecretString::new(client_secret.into().into_boxed_str()),
+            token_url: None,
+            http_config: HttpConfig::default(),
+            store: None,
+        }
+    }
 
-        Ok(Self {
-            client_id,
-            client_secret: SecretString::new(client_secret.into_boxed_str()),
-            http_client,
-            token: Arc::new(RwLock::new(None)),
-        })
+    /// Returns the OAuth token endpoint this provider requests tokens from.
+    pub fn token_url(&self) -> &str {
+        &self.token_url
     }
 
     /// Creates a new OAuth Client Credentials authenticator from environment variables.
@@ -169,6 +212,8 @@ impl ClientCredentialsAuth {
     ///
     /// - `ARCGIS_CLIENT_ID` - Application client ID from ArcGIS Developer dashboard
     /// - `ARCGIS_CLIENT_SECRET` - Application client secret (keep confidential)
+    /// - `ARCGIS_ENTERPRISE_PORTAL` - Optional portal URL; tokens are requested
+    ///   from ArcGIS Online when unset
     ///
     /// # Errors
     ///
@@ -215,10 +260,15 @@ impl ClientCredentialsAuth {
 
         tracing::debug!("Successfully loaded OAuth credentials from environment");
 
-        Self::new(
+        let mut builder = Self::builder(
             client_id.expose_secret().to_string(),
             client_secret.expose_secret().to_string(),
-        )
+        );
+        if let Some(portal_url) = &config.arcgis_enterprise_portal {
+            tracing::debug!(portal_url = %portal_url, "Using Enterprise portal for tokens");
+            builder = builder.portal_url(portal_url);
+        }
+        builder.build()
     }
 
     /// Fetches a new access token from the ArcGIS token endpoint.
@@ -245,7 +295,7 @@ impl ClientCredentialsAuth {
 
         let response = self
             .http_client
-            .post("https://www.arcgis.com/sharing/rest/oauth2/token")
+            .post(&self.token_url)
             .form(&params)
             .send()
             .await?;
@@ -270,6 +320,15 @@ impl ClientCredentialsAuth {
             "Access token obtained successfully"
         );
 
+        if let Some(slot) = &self.store {
+            let lifetime = chrono::Duration::seconds(token_response.expires_in as i64);
+            slot.save(PersistedToken::new(
+                token_response.access_token.clone(),
+                chrono::Utc::now() + lifetime,
+            ))
+            .await;
+        }
+
         let stored_token = StoredToken {
             access_token: token_response.access_token,
             fetched_at: Instant::now(),
@@ -295,6 +354,31 @@ impl ClientCredentialsAuth {
 
         age + buffer >= expires_in
     }
+
+    /// Loads a token that is still valid from the token store, if configured.
+    ///
+    /// Returns `true` if a token was loaded.
+    async fn load_stored_token(&self) -> bool {
+        let Some(slot) = &self.store else {
+            return false;
+        };
+        let Some(persisted) = slot.load().await else {
+            return false;
+        };
+
+        let stored = StoredToken {
+            access_token: persisted.token().clone(),
+            fetched_at: Instant::now(),
+            expires_in: persisted.remaining().as_secs(),
+        };
+        if Self::is_token_expired(&stored) {
+            return false;
+        }
+
+        tracing::debug!("Using access token from store");
+        *self.token.write().await = Some(stored);
+        true
+    }
 }
 
 #[async_trait]
@@ -356,8 +440,10 @@ impl AuthProvider for ClientCredentialsAuth {
         } else {
             // No token exists - fetch one
             drop(token_guard);
-            tracing::debug!("No token exists, fetching initial token");
-            self.fetch_token().await?;
+            if !self.load_stored_token().await {
+                tracing::debug!("No token exists, fetching initial token");
+                self.fetch_token().await?;
+            }
 
             let guard = self.token.read().await;
             let token = guard.as_ref().ok_or_else(|| {
@@ -366,4 +452,158 @@ impl AuthProvider for ClientCredentialsAuth {
             Ok(token.access_token.clone())
         }
     }
+
+    /// Discards the cached token if it is the one the server rejected.
+    ///
+    /// The next [`get_token`](AuthProvider::get_token) call fetches a new
+    /// token, regardless of the locally computed expiry. Returns `true` if
+    /// the rejected token was this provider's current token, or was already
+    /// replaced by a concurrent refresh, and `false` for tokens it never
+    /// held.
+    #[instrument(skip(self, token))]
+    async fn invalidate_token(&self, token: &str) -> bool {
+        let mut guard = self.token.write().await;
+        match guard.as_mut() {
+            Some(stored) if stored.access_token == token => {
+                // Keep the token so concurrent rejections recognize it
+                if !Self::is_token_expired(stored) {
+                    tracing::debug!("Discarding rejected access token");
+                    stored.expires_in = 0;
+                    if let Some(slot) = &self.store {
+                        slot.evict().await;
+                    }
+                }
+                true
+            }
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Returns the client ID and the token endpoint it signs in at.
+    fn principal(&self, _url: &str) -> Option<String> {
+        Some(format!("{} {}", self.token_url, self.client_id))
+    }
+
+    /// Rebuilds the token HTTP client with the client's transport settings.
+    #[instrument(skip(self, config))]
+    fn configure_http(&mut self, config: &HttpConfig) -> Result<()> {
+        tracing::debug!("Applying HTTP settings to token client");
+        self.http_client = build_token_client(config)?;
+        Ok(())
+    }
+}
+
+/// Builder for [`ClientCredentialsAuth`].
+///
+/// Created by [`ClientCredentialsAuth::builder`].
+pub struct ClientCredentialsAuthBuilder {
+    client_id: String,
+    client_secret: SecretString,
+    token_url: Option<String>,
+    http_config: HttpConfig,
+    store: Option<Arc<dyn TokenStore>>,
+}
+
+impl std::fmt::Debug for ClientCredentialsAuthBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCredentialsAuthBuilder")
+            .field("client_id", &self.client_id)
+            .field("token_url", &self.token_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClientCredentialsAuthBuilder {
+    /// Requests tokens from the given portal.
+    ///
+    /// Accepts the portal root (`https://host/portal`) or its sharing API
+    /// (`https://host/portal/sharing/rest`); tokens are requested from
+    /// `<sharing API>/oauth2/token`.
+    pub fn portal_url(mut self, portal_url: impl AsRef<str>) -> Self {
+        self.token_url = Some(format!(
+            "{}/oauth2/token",
+            sharing_rest_url(portal_url.as_ref())
+        ));
+        self
+    }
+
+    /// Requests tokens from the given OAuth token endpoint, used as-is.
+    pub fn token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = Some(token_url.into());
+        self
+    }
+
+    /// Sets the transport settings for portal discovery and token requests,
+    /// e.g. from [`ArcGISClientBuilder::http_config`](crate::ArcGISClientBuilder::http_config).
+    ///
+    /// Set this before [`discover_portal`](Self::discover_portal) when the
+    /// server is only reachable through a proxy or uses an internal CA. The
+    /// client builder applies its own settings again when the client is built.
+    pub fn http_config(mut self, config: &HttpConfig) -> Self {
+        self.http_config = config.clone();
+        self
+    }
+
+    /// Requests tokens from the portal a server is federated with.
+    ///
+    /// Reads `owningSystemUrl` from the server's `rest/info`, using the
+    /// transport settings from [`http_config`](Self::http_config). `server_url`
+    /// may be the server root (`https://host/server`) or any service URL
+    /// on it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rest/info` cannot be fetched, or if the server is
+    /// not federated with a portal.
+    #[instrument(skip(self))]
+    pub async fn discover_portal(self, server_url: &str) -> Result<Self> {
+        let http = build_token_client(&self.http_config)?;
+        let portal_url = discover_portal_url(&http, server_url).await?;
+        Ok(self.portal_url(portal_url))
+    }
+
+    /// Keeps tokens in `store`, so a token that is still valid is reused
+    /// across runs instead of requesting a new one.
+    pub fn token_store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Builds the provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    #[instrument(skip(self))]
+    pub fn build(self) -> Result<ClientCredentialsAuth> {
+        tracing::debug!("Creating OAuth Client Credentials authenticator");
+
+        let token_url = self
+            .token_url
+            .unwrap_or_else(|| format!("{}/oauth2/token", ARCGIS_ONLINE_PORTAL));
+        let http_client = build_token_client(&self.http_config)?;
+
+        let store = self.store.map(|store| {
+            StoreSlot::new(
+                store,
+                TokenKey::new(
+                    "client_credentials",
+                    self.client_id.clone(),
+                    token_url.clone(),
+                ),
+            )
+        });
+
+        tracing::debug!(token_url = %token_url, "OAuth Client Credentials authenticator created");
+
+        Ok(ClientCredentialsAuth {
+            client_id: self.client_id,
+            client_secret: self.client_secret,
+            token_url,
+            http_client,
+            token: Arc::new(RwLock::new(None)),
+            store,
+        })
+    }
 }
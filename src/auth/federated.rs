@@ -0,0 +1,344 @@
+//! Token exchange for ArcGIS Servers federated with a portal.
+//!
+//! Servers federated with an ArcGIS Enterprise portal trust the portal for
+//! sign-in, but their services expect a server token rather than the portal
+//! token. The portal issues server tokens from `/sharing/rest/generateToken`
+//! in exchange for a portal token and the server URL.
+//!
+//! [`FederatedServerAuth`] wraps any portal provider, detects which server a
+//! request targets from its URL, confirms the server is federated with the
+//! portal, and exchanges and caches a token per server.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::{ArcGISClient, FederatedServerAuth, UsernamePasswordAuth};
+//!
+//! # fn example() -> arcgis::Result<()> {
+//! let portal = UsernamePasswordAuth::builder("gis_analyst", "password")
+//!     .portal_url("https://gis.example.com/portal")
+//!     .build()?;
+//! let auth = FederatedServerAuth::new("https://gis.example.com/portal", portal)?;
+//!
+//! let client = ArcGISClient::new(auth);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::portal::{
+    GeneratedToken, build_token_client, generate_token, owning_portal_url, sharing_rest_url,
+};
+use crate::{AuthProvider, HttpConfig, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use tracing::instrument;
+use url::Url;
+
+/// Returns the root of the ArcGIS Server hosting `url`, if it is one.
+///
+/// `https://host/server/rest/services/Parcels/MapServer` maps to
+/// `https://host/server`. URLs without a `/rest/` segment are not server URLs.
+fn server_root(url: &str) -> Option<&str> {
+    url.find("/rest/").map(|index| &url[..index])
+}
+
+/// Returns the key servers are cached and registered under.
+///
+/// ArcGIS treats hosts and paths case-insensitively.
+fn server_key(server: &str) -> String {
+    let server = server.trim_end_matches('/');
+    server_root(server).unwrap_or(server).to_ascii_lowercase()
+}
+
+/// Returns whether `url` lies under `root`, comparing hosts and paths
+/// case-insensitively.
+fn is_under(url: &Url, root: &Url) -> bool {
+    let same_host = match (url.host_str(), root.host_str()) {
+        (Some(host), Some(root_host)) => host.eq_ignore_ascii_case(root_host),
+        _ => false,
+    };
+    if !same_host || url.port_or_known_default() != root.port_or_known_default() {
+        return false;
+    }
+    let path = url.path().to_ascii_lowercase();
+    let root_path = root.path().trim_end_matches('/').to_ascii_lowercase();
+    path == root_path || path.starts_with(&format!("{}/", root_path))
+}
+
+/// Returns whether `url` is hosted by ArcGIS Online (e.g.
+/// `services.arcgis.com/<org>/arcgis/rest/services`), which accepts
+/// portal tokens directly.
+fn is_arcgis_online(url: &Url) -> bool {
+    url.host_str().is_some_and(|host| {
+        let host = host.to_ascii_lowercase();
+        host == "arcgis.com" || host.ends_with(".arcgis.com")
+    })
+}
+
+/// Authentication provider for services on portal-federated servers.
+///
+/// Wraps the provider that signs in to the portal. For each request it:
+///
+/// - Uses the portal token for URLs under the portal (e.g.
+///   `/portal/sharing/rest`), and for services hosted by ArcGIS Online when
+///   the portal is ArcGIS Online
+/// - Exchanges the portal token for a server token the first time a
+///   federated server is used, via the portal's `generateToken`. A server
+///   is federated if its `rest/info` lists the portal as `owningSystemUrl`,
+///   or if it was registered with [`federated_server`](Self::federated_server)
+/// - Sends other requests without a token, so the portal token
+///   never reaches servers that do not trust the portal
+/// - Caches server tokens per server and refreshes them before expiration
+/// - Discards a server token the server rejects (ESRI code 498) so the
+///   request is replayed with a fresh exchange
+///
+/// [`get_token`](AuthProvider::get_token) returns the portal token; the
+/// client resolves per-server tokens through
+/// [`token_for_url`](AuthProvider::token_for_url).
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{
+///     ArcGISClient, ClientCredentialsAuth, FeatureServiceClient, FederatedServerAuth, LayerId,
+/// };
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let portal = ClientCredentialsAuth::builder("client_id", "client_secret")
+///     .portal_url("https://gis.example.com/portal")
+///     .build()?;
+/// let auth = FederatedServerAuth::new("https://gis.example.com/portal", portal)?;
+/// let client = ArcGISClient::new(auth);
+///
+/// // Requests to the federated server use an exchanged server token
+/// let service = FeatureServiceClient::new(
+///     "https://gis.example.com/server/rest/services/Parcels/FeatureServer",
+///     &client,
+/// );
+/// let features = service.query(LayerId::new(0)).where_clause("1=1").execute().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FederatedServerAuth {
+    /// Provider that signs in to the portal
+    portal: Box<dyn AuthProvider>,
+    /// Portal sharing REST API root
+    sharing_url: String,
+    /// Portal root, e.g. `https://host/portal`
+    portal_root: Option<Url>,
+    /// Whether the portal is ArcGIS Online, whose hosted services accept
+    /// its tokens
+    online: bool,
+    /// HTTP client with security configuration
+    http_client: reqwest::Client,
+    /// Server tokens keyed by server root
+    server_tokens: RwLock<HashMap<String, GeneratedToken>>,
+    /// Whether each server seen is federated with the portal, keyed by
+    /// [`server_key`]
+    federated: RwLock<HashMap<String, bool>>,
+}
+
+impl std::fmt::Debug for FederatedServerAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FederatedServerAuth")
+            .field("sharing_url", &self.sharing_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FederatedServerAuth {
+    /// Creates a provider that exchanges tokens from `portal` for server tokens.
+    ///
+    /// `portal_url` accepts the portal root (`https://host/portal`) or its
+    /// sharing API (`https://host/portal/sharing/rest`), and must be the
+    /// portal `portal` signs in to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    #[instrument(skip(portal_url, portal), fields(portal_url = %portal_url.as_ref()))]
+    pub fn new(portal_url: impl AsRef<str>, portal: impl AuthProvider + 'static) -> Result<Self> {
+        tracing::debug!("Creating federated server authenticator");
+
+        let sharing_url = sharing_rest_url(portal_url.as_ref());
+        let portal_root = Url::parse(sharing_url.trim_end_matches("/sharing/rest")).ok();
+        let online = portal_root.as_ref().is_some_and(is_arcgis_online);
+        let http_client = build_token_client(&HttpConfig::default())?;
+
+        Ok(Self {
+            portal: Box::new(portal),
+            sharing_url,
+            portal_root,
+            online,
+            http_client,
+            server_tokens: RwLock::new(HashMap::new()),
+            federated: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Registers a server as federated with the portal, so its tokens are
+    /// exchanged without checking its `owningSystemUrl`.
+    ///
+    /// `server_url` accepts the server root (`https://host/server`) or any
+    /// URL below its `/rest/` segment.
+    pub fn federated_server(mut self, server_url: impl AsRef<str>) -> Self {
+        self.federated
+            .get_mut()
+            .insert(server_key(server_url.as_ref()), true);
+        self
+    }
+
+    /// Returns the `generateToken` endpoint server tokens are exchanged at.
+    pub fn token_url(&self) -> String {
+        format!("{}/generateToken", self.sharing_url)
+    }
+
+    /// Returns the server token for `server`, exchanging a portal token if
+    /// none is cached or the cached one is about to expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the portal token cannot be obtained or the portal
+    /// refuses the exchange (e.g. the server is not federated with it).
+    #[instrument(skip(self))]
+    pub async fn server_token(&self, server: &str) -> Result<String> {
+        if let Some(stored) = self.server_tokens.read().await.get(server) {
+            if stored.is_fresh() {
+                tracing::debug!("Returning cached server token");
+                return Ok(stored.token.clone());
+            }
+        }
+
+        let mut tokens = self.server_tokens.write().await;
+        // Another task may have exchanged while we waited for the lock
+        if let Some(stored) = tokens.get(server) {
+            if stored.is_fresh() {
+                return Ok(stored.token.clone());
+            }
+        }
+
+        tracing::debug!("Exchanging portal token for server token");
+        let portal_token = self.portal.get_token().await?;
+        let params = [
+            ("request", "getToken"),
+            ("serverUrl", server),
+            ("token", portal_token.as_str()),
+            ("f", "json"),
+        ];
+        let stored = generate_token(&self.http_client, &self.token_url(), &params).await?;
+        let token = stored.token.clone();
+        tokens.insert(server.to_string(), stored);
+        Ok(token)
+    }
+
+    /// Returns whether `server` is federated with the portal, checking its
+    /// `owningSystemUrl` until the server answers.
+    ///
+    /// Standalone servers are remembered as not federated. A server that
+    /// cannot be checked (unreachable, server error) is treated as not
+    /// federated for this request only, and checked again on the next.
+    #[instrument(skip(self))]
+    async fn is_federated(&self, server: &str) -> bool {
+        let key = server_key(server);
+        if let Some(federated) = self.federated.read().await.get(&key) {
+            return *federated;
+        }
+
+        let federated = match owning_portal_url(&self.http_client, server).await {
+            Ok(Some(owning_portal)) => owning_portal.eq_ignore_ascii_case(&self.sharing_url),
+            Ok(None) => false,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not confirm server federation, will retry");
+                return false;
+            }
+        };
+        tracing::debug!(federated, "Checked server federation");
+        self.federated.write().await.insert(key, federated);
+        federated
+    }
+}
+
+#[async_trait]
+impl AuthProvider for FederatedServerAuth {
+    /// Returns the portal token.
+    #[instrument(skip(self))]
+    async fn get_token(&self) -> Result<String> {
+        self.portal.get_token().await
+    }
+
+    /// Returns the portal token for portal and ArcGIS Online URLs, a server
+    /// token for federated server URLs, and no token for other URLs.
+    ///
+    /// Returns `None` if the portal provider sends no token parameter.
+    #[instrument(skip(self))]
+    async fn token_for_url(&self, url: &str) -> Result<Option<String>> {
+        if !self.requires_token_param() {
+            return Ok(None);
+        }
+
+        let parsed = Url::parse(url).ok();
+        let online_hosted = parsed.as_ref().is_some_and(is_arcgis_online);
+        let portal_hosted = parsed.as_ref().is_some_and(|parsed| {
+            self.portal_root
+                .as_ref()
+                .is_some_and(|root| is_under(parsed, root))
+        });
+        if portal_hosted || (self.online && online_hosted) {
+            return Ok(Some(self.portal.get_token().await?));
+        }
+        // ArcGIS Online servers are never federated with an Enterprise portal
+        if online_hosted {
+            tracing::debug!("ArcGIS Online URL outside the portal's realm, sending no token");
+            return Ok(None);
+        }
+
+        match server_root(url) {
+            Some(server) if self.is_federated(server).await => {
+                Ok(Some(self.server_token(server).await?))
+            }
+            _ => {
+                tracing::debug!(
+                    "URL is not under the portal or a federated server, sending no token"
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Discards a rejected server token, or passes a rejected portal token
+    /// on to the portal provider.
+    #[instrument(skip(self, token))]
+    async fn invalidate_token(&self, token: &str) -> bool {
+        {
+            let mut tokens = self.server_tokens.write().await;
+            let before = tokens.len();
+            tokens.retain(|_, stored| stored.token != token);
+            if tokens.len() < before {
+                tracing::debug!("Discarding rejected server token");
+                return true;
+            }
+        }
+        self.portal.invalidate_token(token).await
+    }
+
+    /// Returns whether the portal provider requires a token parameter.
+    fn requires_token_param(&self) -> bool {
+        self.portal.requires_token_param()
+    }
+
+    /// Returns the principal of the portal provider, whose identity server
+    /// tokens are issued for.
+    fn principal(&self, url: &str) -> Option<String> {
+        self.portal.principal(url)
+    }
+
+    /// Rebuilds the token HTTP client and configures the portal provider
+    /// with the client's transport settings.
+    #[instrument(skip(self, config))]
+    fn configure_http(&mut self, config: &HttpConfig) -> Result<()> {
+        tracing::debug!("Applying HTTP settings to token client");
+        self.http_client = build_token_client(config)?;
+        self.portal.configure_http(config)
+    }
+}
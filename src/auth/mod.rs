@@ -1,11 +1,26 @@
 //! Authentication providers for ArcGIS services.
 
 mod api_key;
+mod authorization_code;
 mod client_credentials;
+mod federated;
 mod no_auth;
+mod portal;
 mod provider;
+mod router;
+mod token_store;
+mod username_password;
 
 pub use api_key::{ApiKeyAuth, ApiKeyTier};
-pub use client_credentials::ClientCredentialsAuth;
+pub use authorization_code::{
+    AuthorizationCodeAuth, AuthorizationCodeAuthBuilder, AuthorizeUrlHandler,
+};
+pub use client_credentials::{ClientCredentialsAuth, ClientCredentialsAuthBuilder};
+pub use federated::FederatedServerAuth;
 pub use no_auth::NoAuth;
+pub(crate) use portal::rest_info_url;
 pub use provider::AuthProvider;
+pub use router::{AuthRouter, AuthRouterBuilder};
+pub use token_store::{FileTokenStore, MemoryTokenStore, PersistedToken, TokenKey, TokenStore};
+pub(crate) use token_store::{create_private_dir, write_private};
+pub use username_password::{TokenClient, UsernamePasswordAuth, UsernamePasswordAuthBuilder};
@@ -0,0 +1,186 @@
+//! Portal URL handling and HTTP setup shared by token-based providers.
+
+use crate::{HttpConfig, Result};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use tracing::instrument;
+
+/// Sharing REST API of ArcGIS Online.
+pub(crate) const ARCGIS_ONLINE_PORTAL: &str = "https://www.arcgis.com/sharing/rest";
+
+/// Longest margin before expiry at which a token is refreshed.
+const REFRESH_BUFFER: Duration = Duration::from_secs(300);
+
+/// Returns when a token with the given lifetime should be refreshed.
+///
+/// Refreshes five minutes early, or after 80% of the lifetime for tokens
+/// shorter than that.
+pub(crate) fn refresh_deadline(lifetime: Duration) -> Instant {
+    Instant::now() + (lifetime - REFRESH_BUFFER.min(lifetime / 5))
+}
+
+/// A token issued by `generateToken`.
+#[derive(Debug, Clone)]
+pub(crate) struct GeneratedToken {
+    /// The token
+    pub(crate) token: String,
+    /// When the token should be replaced
+    pub(crate) refresh_at: Instant,
+    /// When the token expires
+    pub(crate) expires_at: DateTime<Utc>,
+}
+
+impl GeneratedToken {
+    /// Creates a token expiring at `expires_at`.
+    pub(crate) fn new(token: String, expires_at: DateTime<Utc>) -> Self {
+        let lifetime = (expires_at - Utc::now()).to_std().unwrap_or_default();
+        Self {
+            token,
+            refresh_at: refresh_deadline(lifetime),
+            expires_at,
+        }
+    }
+
+    /// Returns whether the token is still usable.
+    pub(crate) fn is_fresh(&self) -> bool {
+        Instant::now() < self.refresh_at
+    }
+}
+
+/// Response from the `generateToken` endpoint.
+#[derive(Debug, Deserialize)]
+struct GenerateTokenResponse {
+    /// The token
+    token: String,
+    /// Expiration time in milliseconds since the Unix epoch
+    expires: i64,
+}
+
+/// Posts `params` to a `generateToken` endpoint and returns the token.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, the endpoint reports an error
+/// (e.g. invalid credentials), or the response cannot be parsed.
+#[instrument(skip(http, params))]
+pub(crate) async fn generate_token(
+    http: &reqwest::Client,
+    token_url: &str,
+    params: &[(&str, &str)],
+) -> Result<GeneratedToken> {
+    let response = http.post(token_url).form(params).send().await?;
+
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(crate::ErrorKind::Auth(format!(
+            "Token request failed with status {}: {}",
+            status, body
+        ))
+        .into());
+    }
+    crate::check_esri_error(&body, "generateToken")?;
+
+    let response: GenerateTokenResponse = serde_json::from_str(&body)?;
+    let expires_at = DateTime::from_timestamp_millis(response.expires).unwrap_or_else(Utc::now);
+    let token = GeneratedToken::new(response.token, expires_at);
+
+    tracing::info!(
+        expires_in = (expires_at - Utc::now()).num_seconds(),
+        "Token obtained successfully"
+    );
+
+    Ok(token)
+}
+
+/// Normalizes a portal URL to its sharing REST API root.
+///
+/// Accepts the portal root (`https://host/portal`) or the sharing API
+/// (`https://host/portal/sharing/rest`), with or without a trailing slash.
+pub(crate) fn sharing_rest_url(portal_url: &str) -> String {
+    let trimmed = portal_url.trim_end_matches('/');
+    if trimmed.ends_with("/sharing/rest") {
+        trimmed.to_string()
+    } else if trimmed.ends_with("/sharing") {
+        format!("{}/rest", trimmed)
+    } else {
+        format!("{}/sharing/rest", trimmed)
+    }
+}
+
+/// Builds the token HTTP client from the given transport settings.
+///
+/// A preconfigured client is used as-is; otherwise redirects are disabled
+/// to prevent SSRF vulnerabilities.
+pub(crate) fn build_token_client(config: &HttpConfig) -> Result<reqwest::Client> {
+    if let Some(client) = config.client() {
+        return Ok(client.clone());
+    }
+
+    // Security: disable redirects to prevent SSRF vulnerabilities
+    Ok(config
+        .client_builder()?
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?)
+}
+
+/// Returns the `rest/info` URL of the server hosting `server_url`.
+///
+/// `server_url` may be the server root (`https://host/server`), its REST
+/// root, or any service URL below it.
+pub(crate) fn rest_info_url(server_url: &str) -> String {
+    let trimmed = server_url.trim_end_matches('/');
+    match trimmed.find("/rest/") {
+        Some(index) => format!("{}/rest/info", &trimmed[..index]),
+        None if trimmed.ends_with("/rest") => format!("{}/info", trimmed),
+        None => format!("{}/rest/info", trimmed),
+    }
+}
+
+/// Discovers the portal a server is federated with from its `rest/info`.
+///
+/// Returns the portal's sharing REST API root.
+///
+/// # Errors
+///
+/// Returns an error if the request fails, or if the server does not report
+/// an `owningSystemUrl` (a standalone server).
+#[instrument(skip(http))]
+pub(crate) async fn discover_portal_url(
+    http: &reqwest::Client,
+    server_url: &str,
+) -> Result<String> {
+    let portal_url = owning_portal_url(http, server_url).await?.ok_or_else(|| {
+        tracing::error!(server_url = %server_url, "Server reports no owningSystemUrl");
+        crate::Error::from(crate::ErrorKind::Auth(format!(
+            "Server at {} is not federated with a portal (no owningSystemUrl)",
+            server_url
+        )))
+    })?;
+
+    tracing::debug!(portal_url = %portal_url, "Discovered owning portal");
+    Ok(portal_url)
+}
+
+/// Reads the sharing REST API root of the portal a server is federated
+/// with from its `rest/info`, or `None` for a standalone server.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or the server reports an error.
+#[instrument(skip(http))]
+pub(crate) async fn owning_portal_url(
+    http: &reqwest::Client,
+    server_url: &str,
+) -> Result<Option<String>> {
+    let url = rest_info_url(server_url);
+    tracing::debug!(url = %url, "Reading server info");
+
+    let response = http.get(&url).query(&[("f", "json")]).send().await?;
+    let text = response.error_for_status()?.text().await?;
+    crate::check_esri_error(&text, "rest/info")?;
+    let info: crate::ServerInfo = serde_json::from_str(&text)?;
+
+    Ok(info.owning_system_url().as_deref().map(sharing_rest_url))
+}
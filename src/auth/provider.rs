@@ -1,6 +1,6 @@
 //! Authentication provider trait.
 
-use crate::Result;
+use crate::{HttpConfig, Result};
 use async_trait::async_trait;
 
 /// Trait for authentication providers.
@@ -12,8 +12,100 @@ pub trait AuthProvider: Send + Sync {
     /// Returns the authentication token or API key to use for requests.
     async fn get_token(&self) -> Result<String>;
 
+    /// Returns the token for a request to `url`, or `None` if the request
+    /// should be sent without one.
+    ///
+    /// The client calls this for every authenticated request. The default
+    /// implementation returns [`get_token`](Self::get_token) when
+    /// [`requires_token_param`](Self::requires_token_param) is true. Providers
+    /// that issue different tokens per server or service override it.
+    async fn token_for_url(&self, _url: &str) -> Result<Option<String>> {
+        if self.requires_token_param() {
+            Ok(Some(self.get_token().await?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Discards a token the server rejected as invalid or expired.
+    ///
+    /// Called by the client when a request authenticated with `token` fails
+    /// with ESRI code 498 or 499. Returns `true` if the next
+    /// [`get_token`](Self::get_token) call can produce a different token, in
+    /// which case the client replays the request once with it.
+    ///
+    /// Implementations should only discard their cached token if it still
+    /// equals `token`, so concurrent requests that hit the same rejection
+    /// trigger a single refresh. The default implementation does nothing and
+    /// returns `false`, which suits static credentials like API keys.
+    async fn invalidate_token(&self, _token: &str) -> bool {
+        false
+    }
+
+    /// Discards a token the server rejected for a request to `url`.
+    ///
+    /// The client calls this rather than
+    /// [`invalidate_token`](Self::invalidate_token), so providers that issue
+    /// tokens per URL can pass the rejection to the one that issued it. The
+    /// default implementation calls [`invalidate_token`](Self::invalidate_token).
+    async fn invalidate_token_for_url(&self, _url: &str, token: &str) -> bool {
+        self.invalidate_token(token).await
+    }
+
+    /// Returns a stable identifier for the identity requests to `url` are
+    /// made as, such as a client ID or username, or `None` if there is none.
+    ///
+    /// Used only to keep cached responses of different identities apart; it
+    /// is hashed and never sent. Unlike the token, it survives token
+    /// refreshes. The default returns `None`, in which case the
+    /// [`ResponseCache`](crate::ResponseCache) keys entries by the token.
+    fn principal(&self, _url: &str) -> Option<String> {
+        None
+    }
+
     /// Returns whether this provider requires a token parameter.
     fn requires_token_param(&self) -> bool {
         true
     }
+
+    /// Applies the client's HTTP transport settings to this provider.
+    ///
+    /// Called by [`ArcGISClientBuilder::build`](crate::ArcGISClientBuilder::build)
+    /// so providers that make their own HTTP requests (e.g. to fetch tokens)
+    /// use the same proxy, certificates, and timeouts as the client.
+    /// The default implementation does nothing.
+    fn configure_http(&mut self, _config: &HttpConfig) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P: AuthProvider + ?Sized> AuthProvider for Box<P> {
+    async fn get_token(&self) -> Result<String> {
+        (**self).get_token().await
+    }
+
+    async fn token_for_url(&self, url: &str) -> Result<Option<String>> {
+        (**self).token_for_url(url).await
+    }
+
+    async fn invalidate_token(&self, token: &str) -> bool {
+        (**self).invalidate_token(token).await
+    }
+
+    async fn invalidate_token_for_url(&self, url: &str, token: &str) -> bool {
+        (**self).invalidate_token_for_url(url, token).await
+    }
+
+    fn principal(&self, url: &str) -> Option<String> {
+        (**self).principal(url)
+    }
+
+    fn requires_token_param(&self) -> bool {
+        (**self).requires_token_param()
+    }
+
+    fn configure_http(&mut self, config: &HttpConfig) -> Result<()> {
+        (**self).configure_http(config)
+    }
 }
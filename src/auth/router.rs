@@ -0,0 +1,264 @@
+//! Routing requests to different authentication providers.
+//!
+//! A single [`ArcGISClient`](crate::ArcGISClient) holds one provider. When a
+//! workflow mixes public basemaps, location services, and Enterprise feature
+//! services, [`AuthRouter`] lets that one client pick a delegate provider
+//! per request, based on the request URL.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::{ApiKeyAuth, ApiKeyTier, ArcGISClient, AuthRouter, NoAuth, ServiceKind};
+//!
+//! # fn example() -> arcgis::Result<()> {
+//! let auth = AuthRouter::builder(NoAuth)
+//!     .service_kind(ServiceKind::Geocode, ApiKeyAuth::from_env(ApiKeyTier::Location)?)
+//!     .host("gis.example.com", ApiKeyAuth::enterprise()?)
+//!     .build();
+//!
+//! let client = ArcGISClient::new(auth);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{ApiKeyTier, AuthProvider, HttpConfig, Result, ServiceKind};
+use async_trait::async_trait;
+use tracing::instrument;
+use url::Url;
+
+/// Condition under which a route applies to a request URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Route {
+    /// URL host equals the value (case-insensitive)
+    Host(String),
+    /// URL path starts with the value
+    PathPrefix(String),
+    /// URL targets one of the service kinds
+    Kind(Vec<ServiceKind>),
+    /// URL targets a service kind in the API key tier
+    Tier(ApiKeyTier),
+}
+
+impl Route {
+    /// Returns whether this route applies to `url`.
+    fn matches(&self, url: &Url) -> bool {
+        match self {
+            Self::Host(host) => url
+                .host_str()
+                .is_some_and(|candidate| candidate.eq_ignore_ascii_case(host)),
+            Self::PathPrefix(prefix) => url.path().starts_with(prefix.as_str()),
+            Self::Kind(kinds) => kinds.contains(&ServiceKind::from_url(url)),
+            Self::Tier(tier) => ApiKeyTier::for_service(ServiceKind::from_url(url)) == *tier,
+        }
+    }
+}
+
+/// Authentication provider that delegates to other providers by request URL.
+///
+/// Routes are checked in the order they were added, and the first route
+/// matching a request URL selects its provider. Requests matching no route
+/// use the fallback provider. Routes can match on:
+///
+/// - The URL host, e.g. `services.arcgisonline.com`
+/// - A URL path prefix, e.g. `/server/rest/services/Secure`
+/// - The [`ServiceKind`] of the request
+/// - The [`ApiKeyTier`] of the request's service kind, see
+///   [`ApiKeyTier::for_service`]
+///
+/// [`get_token`](AuthProvider::get_token),
+/// [`invalidate_token`](AuthProvider::invalidate_token), and
+/// [`requires_token_param`](AuthProvider::requires_token_param) report the
+/// fallback provider; the client resolves each request's token through
+/// [`token_for_url`](AuthProvider::token_for_url) and reports rejections
+/// through [`invalidate_token_for_url`](AuthProvider::invalidate_token_for_url).
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ApiKeyAuth, ApiKeyTier, AuthRouter, ClientCredentialsAuth, NoAuth};
+///
+/// # fn example() -> arcgis::Result<()> {
+/// let enterprise = ClientCredentialsAuth::builder("client_id", "client_secret")
+///     .portal_url("https://gis.example.com/portal")
+///     .build()?;
+///
+/// // Location services use the location key, Enterprise services use OAuth,
+/// // and everything else (e.g. public basemaps) is anonymous
+/// let auth = AuthRouter::builder(NoAuth)
+///     .host("gis.example.com", enterprise)
+///     .api_key_tier(ApiKeyTier::Location, ApiKeyAuth::from_env(ApiKeyTier::Location)?)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+pub struct AuthRouter {
+    /// Routes and the index of the provider each selects
+    routes: Vec<(Route, usize)>,
+    /// Delegate providers; the fallback is first
+    providers: Vec<Box<dyn AuthProvider>>,
+}
+
+impl std::fmt::Debug for AuthRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthRouter")
+            .field("routes", &self.routes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuthRouter {
+    /// Creates a builder that uses `fallback` for requests matching no route.
+    pub fn builder(fallback: impl AuthProvider + 'static) -> AuthRouterBuilder {
+        AuthRouterBuilder {
+            routes: Vec::new(),
+            providers: vec![Box::new(fallback)],
+        }
+    }
+
+    /// Returns the provider for a request to `url`.
+    fn provider_for(&self, url: &str) -> &dyn AuthProvider {
+        let index = Url::parse(url).ok().and_then(|url| {
+            self.routes
+                .iter()
+                .find(|(route, _)| route.matches(&url))
+                .map(|(_, index)| *index)
+        });
+        match index {
+            Some(index) => {
+                tracing::debug!(route = index, "Request matched auth route");
+                self.providers[index].as_ref()
+            }
+            None => {
+                tracing::debug!("Request matched no auth route, using fallback");
+                self.providers[0].as_ref()
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AuthProvider for AuthRouter {
+    /// Returns the fallback provider's token.
+    #[instrument(skip(self))]
+    async fn get_token(&self) -> Result<String> {
+        self.providers[0].get_token().await
+    }
+
+    /// Returns the token of the provider routed to for `url`.
+    #[instrument(skip(self))]
+    async fn token_for_url(&self, url: &str) -> Result<Option<String>> {
+        self.provider_for(url).token_for_url(url).await
+    }
+
+    /// Passes the rejected token to the fallback provider.
+    #[instrument(skip(self, token))]
+    async fn invalidate_token(&self, token: &str) -> bool {
+        self.providers[0].invalidate_token(token).await
+    }
+
+    /// Passes the rejected token to the provider routed to for `url`, which
+    /// issued it.
+    ///
+    /// Other delegates are left alone, so a rejected API key never causes a
+    /// replay because an unrelated OAuth provider could refresh its own token.
+    #[instrument(skip(self, token))]
+    async fn invalidate_token_for_url(&self, url: &str, token: &str) -> bool {
+        self.provider_for(url)
+            .invalidate_token_for_url(url, token)
+            .await
+    }
+
+    /// Returns the principal of the provider routed to for `url`.
+    fn principal(&self, url: &str) -> Option<String> {
+        self.provider_for(url).principal(url)
+    }
+
+    /// Returns whether the fallback provider requires a token parameter.
+    fn requires_token_param(&self) -> bool {
+        self.providers[0].requires_token_param()
+    }
+
+    /// Applies the client's HTTP transport settings to every delegate provider.
+    #[instrument(skip(self, config))]
+    fn configure_http(&mut self, config: &HttpConfig) -> Result<()> {
+        for provider in &mut self.providers {
+            provider.configure_http(config)?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`AuthRouter`].
+///
+/// Created by [`AuthRouter::builder`].
+pub struct AuthRouterBuilder {
+    routes: Vec<(Route, usize)>,
+    providers: Vec<Box<dyn AuthProvider>>,
+}
+
+impl std::fmt::Debug for AuthRouterBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthRouterBuilder")
+            .field("routes", &self.routes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuthRouterBuilder {
+    /// Adds a route and its provider.
+    fn route(mut self, route: Route, provider: impl AuthProvider + 'static) -> Self {
+        self.routes.push((route, self.providers.len()));
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// Routes requests to `host` (e.g. `services.arcgis.com`) to `provider`.
+    ///
+    /// Hosts are compared case-insensitively and without the port.
+    pub fn host(self, host: impl Into<String>, provider: impl AuthProvider + 'static) -> Self {
+        self.route(Route::Host(host.into()), provider)
+    }
+
+    /// Routes requests whose URL path starts with `prefix` to `provider`.
+    ///
+    /// `prefix` is matched against the path only, e.g.
+    /// `/server/rest/services/Secure`.
+    pub fn path_prefix(
+        self,
+        prefix: impl Into<String>,
+        provider: impl AuthProvider + 'static,
+    ) -> Self {
+        self.route(Route::PathPrefix(prefix.into()), provider)
+    }
+
+    /// Routes requests to services of the given kind to `provider`.
+    pub fn service_kind(self, kind: ServiceKind, provider: impl AuthProvider + 'static) -> Self {
+        self.route(Route::Kind(vec![kind]), provider)
+    }
+
+    /// Routes requests to services of any of the given kinds to `provider`.
+    pub fn service_kinds(
+        self,
+        kinds: impl IntoIterator<Item = ServiceKind>,
+        provider: impl AuthProvider + 'static,
+    ) -> Self {
+        self.route(Route::Kind(kinds.into_iter().collect()), provider)
+    }
+
+    /// Routes requests to every service kind in `tier` to `provider`.
+    ///
+    /// See [`ApiKeyTier::for_service`] for which kinds belong to each tier.
+    pub fn api_key_tier(self, tier: ApiKeyTier, provider: impl AuthProvider + 'static) -> Self {
+        self.route(Route::Tier(tier), provider)
+    }
+
+    /// Builds the router.
+    #[instrument(skip(self))]
+    pub fn build(self) -> AuthRouter {
+        tracing::debug!(routes = self.routes.len(), "Creating auth router");
+        AuthRouter {
+            routes: self.routes,
+            providers: self.providers,
+        }
+    }
+}
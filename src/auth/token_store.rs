@@ -0,0 +1,417 @@
+//! Persistent storage for issued tokens.
+//!
+//! Token-based providers keep their token in memory, so every process start
+//! requests a new one. Attaching a [`TokenStore`] lets a provider reuse a
+//! token that is still valid from an earlier run, which saves short-lived
+//! command line tools a token request per invocation.
+//!
+//! [`FileTokenStore`] keeps tokens in a JSON file readable only by the
+//! current user; [`MemoryTokenStore`] shares tokens between providers in one
+//! process.
+
+use crate::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::instrument;
+
+/// File name of the token store in the default location.
+const TOKEN_FILE: &str = "tokens.json";
+
+/// Identifies a stored token.
+///
+/// Tokens are keyed by the grant that issued them, the client ID (or
+/// username), the portal token endpoint, and the referer a token is bound
+/// to, so providers for different users, applications, portals, or referers
+/// never share a token.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenKey {
+    /// Grant that issued the token, e.g. `client_credentials`.
+    grant: String,
+    /// Client ID of the application, or the portal username.
+    client_id: String,
+    /// Token endpoint of the portal.
+    portal: String,
+    /// HTTP referer the token is bound to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    referer: Option<String>,
+}
+
+impl TokenKey {
+    /// Creates a key.
+    pub fn new(
+        grant: impl Into<String>,
+        client_id: impl Into<String>,
+        portal: impl Into<String>,
+    ) -> Self {
+        Self {
+            grant: grant.into(),
+            client_id: client_id.into(),
+            portal: portal.into(),
+            referer: None,
+        }
+    }
+
+    /// Binds the key to the referer the token is issued for.
+    pub fn with_referer(mut self, referer: impl Into<String>) -> Self {
+        self.referer = Some(referer.into());
+        self
+    }
+}
+
+/// A token as kept by a [`TokenStore`].
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedToken {
+    /// The access token.
+    token: String,
+    /// When the token expires.
+    expires_at: DateTime<Utc>,
+    /// Refresh token issued with the access token, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+}
+
+impl std::fmt::Debug for PersistedToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersistedToken")
+            .field("expires_at", &self.expires_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PersistedToken {
+    /// Creates an entry for a token expiring at `expires_at`.
+    pub fn new(token: impl Into<String>, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            token: token.into(),
+            expires_at,
+            refresh_token: None,
+        }
+    }
+
+    /// Attaches the refresh token issued with the access token.
+    pub fn with_refresh_token(mut self, refresh_token: impl Into<String>) -> Self {
+        self.refresh_token = Some(refresh_token.into());
+        self
+    }
+
+    /// Returns how long the token remains valid, or zero if it has expired.
+    pub fn remaining(&self) -> Duration {
+        (self.expires_at - Utc::now()).to_std().unwrap_or_default()
+    }
+}
+
+/// Storage backend for tokens that outlive the process.
+///
+/// Implemented by [`FileTokenStore`] and [`MemoryTokenStore`]; implement it
+/// to keep tokens in another store, such as the OS keychain. Attach a store
+/// with the `token_store` method of
+/// [`ClientCredentialsAuthBuilder`](crate::ClientCredentialsAuthBuilder),
+/// [`UsernamePasswordAuthBuilder`](crate::UsernamePasswordAuthBuilder), or
+/// [`AuthorizationCodeAuthBuilder`](crate::AuthorizationCodeAuthBuilder).
+///
+/// Providers treat store errors as cache misses and log them, so a broken
+/// store never prevents authentication.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Returns the token stored for `key`, if present.
+    async fn get(&self, key: &TokenKey) -> Result<Option<PersistedToken>>;
+
+    /// Stores a token, replacing any existing token for `key`.
+    async fn put(&self, key: &TokenKey, token: PersistedToken) -> Result<()>;
+
+    /// Removes the token for `key`, if present.
+    async fn evict(&self, key: &TokenKey) -> Result<()>;
+}
+
+/// In-memory token store, shared by clones.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTokenStore {
+    tokens: Arc<Mutex<HashMap<TokenKey, PersistedToken>>>,
+}
+
+impl MemoryTokenStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<TokenKey, PersistedToken>>> {
+        self.tokens.lock().map_err(|_| {
+            crate::Error::from(crate::ErrorKind::Other(
+                "Token store lock poisoned".to_string(),
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl TokenStore for MemoryTokenStore {
+    async fn get(&self, key: &TokenKey) -> Result<Option<PersistedToken>> {
+        Ok(self.lock()?.get(key).cloned())
+    }
+
+    async fn put(&self, key: &TokenKey, token: PersistedToken) -> Result<()> {
+        self.lock()?.insert(key.clone(), token);
+        Ok(())
+    }
+
+    async fn evict(&self, key: &TokenKey) -> Result<()> {
+        self.lock()?.remove(key);
+        Ok(())
+    }
+}
+
+/// Token store backed by a JSON file.
+///
+/// On Unix the file is created with mode `0600` and its directory with mode
+/// `0700`, so only the current user can read the tokens. Expired tokens are
+/// dropped whenever the file is written.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ClientCredentialsAuth, FileTokenStore};
+///
+/// # fn example() -> arcgis::Result<()> {
+/// let auth = ClientCredentialsAuth::builder("client_id", "client_secret")
+///     .token_store(FileTokenStore::default_location()?)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Getters)]
+pub struct FileTokenStore {
+    /// File holding the tokens.
+    path: PathBuf,
+    /// Serializes read-modify-write cycles within the process.
+    #[getter(skip)]
+    lock: tokio::sync::Mutex<()>,
+}
+
+/// Entry as written to disk.
+#[derive(Serialize, Deserialize)]
+struct FileEntry {
+    key: TokenKey,
+    token: PersistedToken,
+}
+
+impl FileTokenStore {
+    /// Creates a store in `path`. The file is created on first write.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Creates a store in the user's cache directory.
+    ///
+    /// Uses `$XDG_CACHE_HOME/arcgis/tokens.json`, falling back to
+    /// `~/.cache/arcgis/tokens.json`, or `%LOCALAPPDATA%\arcgis\tokens.json`
+    /// on Windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if none of these locations is set in the environment.
+    #[instrument]
+    pub fn default_location() -> Result<Self> {
+        let dir = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+            .ok_or_else(|| {
+                tracing::error!("No cache directory found for token store");
+                crate::Error::from(crate::ErrorKind::Env(crate::EnvError::new(
+                    std::env::VarError::NotPresent,
+                )))
+            })?;
+
+        let path = dir.join("arcgis").join(TOKEN_FILE);
+        tracing::debug!(path = %path.display(), "Using default token store");
+        Ok(Self::new(path))
+    }
+
+    /// Reads the file, returning `None` if it does not exist.
+    async fn read_bytes(&self) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Reads all entries, treating a missing file as empty.
+    async fn read(&self) -> Result<Vec<FileEntry>> {
+        match self.read_bytes().await? {
+            Some(contents) => Ok(serde_json::from_slice(&contents)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Reads all entries for an update.
+    ///
+    /// A file that cannot be parsed is moved aside to `<path>.bak` rather
+    /// than overwritten; a file that cannot be read is an error.
+    async fn read_for_update(&self) -> Result<Vec<FileEntry>> {
+        let Some(contents) = self.read_bytes().await? else {
+            return Ok(Vec::new());
+        };
+        match serde_json::from_slice(&contents) {
+            Ok(entries) => Ok(entries),
+            Err(e) => {
+                let mut backup = self.path.clone().into_os_string();
+                backup.push(".bak");
+                let backup = PathBuf::from(backup);
+                tokio::fs::rename(&self.path, &backup).await?;
+                tracing::warn!(
+                    error = %e,
+                    backup = %backup.display(),
+                    "Moved unreadable token store aside"
+                );
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    /// Writes all entries, dropping expired ones.
+    async fn write(&self, mut entries: Vec<FileEntry>) -> Result<()> {
+        let now = Utc::now();
+        entries.retain(|entry| entry.token.expires_at > now || entry.token.refresh_token.is_some());
+
+        if let Some(dir) = self.path.parent() {
+            if !dir.as_os_str().is_empty() {
+                create_private_dir(dir).await?;
+            }
+        }
+        let contents = serde_json::to_vec_pretty(&entries)?;
+        write_private(&self.path, &contents).await
+    }
+}
+
+#[async_trait]
+impl TokenStore for FileTokenStore {
+    #[instrument(skip(self), fields(path = %self.path.display()))]
+    async fn get(&self, key: &TokenKey) -> Result<Option<PersistedToken>> {
+        let _guard = self.lock.lock().await;
+        Ok(self
+            .read()
+            .await?
+            .into_iter()
+            .find(|entry| &entry.key == key)
+            .map(|entry| entry.token))
+    }
+
+    #[instrument(skip(self, token), fields(path = %self.path.display()))]
+    async fn put(&self, key: &TokenKey, token: PersistedToken) -> Result<()> {
+        let _guard = self.lock.lock().await;
+        let mut entries = self.read_for_update().await?;
+        entries.retain(|entry| &entry.key != key);
+        entries.push(FileEntry {
+            key: key.clone(),
+            token,
+        });
+        self.write(entries).await
+    }
+
+    #[instrument(skip(self), fields(path = %self.path.display()))]
+    async fn evict(&self, key: &TokenKey) -> Result<()> {
+        let _guard = self.lock.lock().await;
+        let mut entries = self.read().await?;
+        let before = entries.len();
+        entries.retain(|entry| &entry.key != key);
+        if entries.len() < before {
+            self.write(entries).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Creates `dir` and its parents, readable only by the current user on Unix.
+pub(crate) async fn create_private_dir(dir: &Path) -> Result<()> {
+    let mut builder = tokio::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    builder.mode(0o700);
+    builder.create(dir).await?;
+    Ok(())
+}
+
+/// Writes through a temporary file created readable only by the current
+/// user on Unix, so the contents are never exposed, even partially, and
+/// concurrent readers never see a partial file.
+pub(crate) async fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let tmp = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4().simple()));
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let result = async {
+        let mut file = options.open(&tmp).await?;
+        file.write_all(contents).await?;
+        file.sync_all().await?;
+        tokio::fs::rename(&tmp, path).await
+    }
+    .await;
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&tmp).await;
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// A provider's store and the key its tokens are kept under.
+///
+/// Store errors are logged and treated as misses.
+#[derive(Clone)]
+pub(crate) struct StoreSlot {
+    store: Arc<dyn TokenStore>,
+    key: TokenKey,
+}
+
+impl StoreSlot {
+    /// Pairs a store with a key.
+    pub(crate) fn new(store: Arc<dyn TokenStore>, key: TokenKey) -> Self {
+        Self { store, key }
+    }
+
+    /// Returns the stored token, if any.
+    pub(crate) async fn load(&self) -> Option<PersistedToken> {
+        match self.store.get(&self.key).await {
+            Ok(token) => {
+                if token.is_some() {
+                    tracing::debug!(grant = %self.key.grant, "Found stored token");
+                }
+                token
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read token store");
+                None
+            }
+        }
+    }
+
+    /// Stores a token.
+    pub(crate) async fn save(&self, token: PersistedToken) {
+        if let Err(e) = self.store.put(&self.key, token).await {
+            tracing::warn!(error = %e, "Failed to write token store");
+        }
+    }
+
+    /// Removes the stored token.
+    pub(crate) async fn evict(&self) {
+        if let Err(e) = self.store.evict(&self.key).await {
+            tracing::warn!(error = %e, "Failed to evict token from store");
+        }
+    }
+}
@@ -0,0 +1,391 @@
+//! Username/password authentication via the portal `generateToken` endpoint.
+//!
+//! ArcGIS Enterprise portals with built-in users issue tokens from
+//! `/sharing/rest/generateToken` in exchange for a username and password.
+//! This provider requests a token on first use, caches it, and requests a
+//! new one shortly before it expires.
+//!
+//! # Security
+//!
+//! - The password is held as a [`SecretString`] and never logged
+//! - Tokens are bound to the caller's IP address or to an HTTP referer
+//! - HTTP redirects are disabled for token requests
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::{ArcGISClient, UsernamePasswordAuth};
+//!
+//! # fn example() -> arcgis::Result<()> {
+//! let auth = UsernamePasswordAuth::builder("gis_analyst", "password")
+//!     .portal_url("https://gis.example.com/portal")
+//!     .build()?;
+//!
+//! let client = ArcGISClient::new(auth);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::portal::{
+    ARCGIS_ONLINE_PORTAL, GeneratedToken, build_token_client, discover_portal_url, generate_token,
+    sharing_rest_url,
+};
+use super::token_store::{PersistedToken, StoreSlot, TokenKey, TokenStore};
+use crate::{AuthProvider, HttpConfig, Result};
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, SecretString};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::instrument;
+
+/// Token lifetime requested when none is configured (60 minutes).
+const DEFAULT_EXPIRATION: Duration = Duration::from_secs(60 * 60);
+
+/// What a generated token is bound to.
+///
+/// The portal only accepts the token from the same client it was issued to.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TokenClient {
+    /// The IP address the token request came from (`client=requestip`).
+    #[default]
+    RequestIp,
+    /// An HTTP referer (`client=referer`).
+    ///
+    /// Requests using the token must send the same `Referer` header; set it
+    /// with [`ArcGISClientBuilder::referer`](crate::ArcGISClientBuilder::referer).
+    Referer(String),
+}
+
+/// Username/password authentication provider using `generateToken`.
+///
+/// Suited to ArcGIS Enterprise portals with built-in users. This provider:
+///
+/// - Requests a token on first use
+/// - Caches the token and refreshes it before expiration
+/// - Refreshes early when the server rejects a token (ESRI code 498)
+/// - Is thread-safe for concurrent use
+///
+/// Tokens are requested from ArcGIS Online unless a portal is configured
+/// with [`UsernamePasswordAuth::builder`].
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{AuthProvider, TokenClient, UsernamePasswordAuth};
+/// use std::time::Duration;
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let auth = UsernamePasswordAuth::builder("gis_analyst", "password")
+///     .portal_url("https://gis.example.com/portal")
+///     .client(TokenClient::Referer("https://app.example.com".to_string()))
+///     .expiration(Duration::from_secs(2 * 60 * 60))
+///     .build()?;
+///
+/// let token = auth.get_token().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct UsernamePasswordAuth {
+    /// Portal username
+    username: String,
+    /// Portal password (kept confidential, never logged)
+    password: SecretString,
+    /// `generateToken` endpoint
+    token_url: String,
+    /// What the token is bound to
+    client: TokenClient,
+    /// Requested token lifetime
+    expiration: Duration,
+    /// HTTP client with security configuration
+    http_client: reqwest::Client,
+    /// Stored token
+    token: Arc<RwLock<Option<GeneratedToken>>>,
+    /// Persistent token store, if configured
+    store: Option<StoreSlot>,
+}
+
+impl std::fmt::Debug for UsernamePasswordAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UsernamePasswordAuth")
+            .field("username", &self.username)
+            .field("token_url", &self.token_url)
+            .field("client", &self.client)
+            .field("expiration", &self.expiration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl UsernamePasswordAuth {
+    /// Creates a builder for the given portal user.
+    ///
+    /// Without further configuration, tokens are requested from ArcGIS
+    /// Online, bound to the request IP, and valid for 60 minutes.
+    pub fn builder(
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> UsernamePasswordAuthBuilder {
+        UsernamePasswordAuthBuilder {
+            username: username.into(),
+            password: SecretString::new(password.into().into_boxed_str()),
+            token_url: None,
+            client: TokenClient::default(),
+            expiration: DEFAULT_EXPIRATION,
+            http_config: HttpConfig::default(),
+            store: None,
+        }
+    }
+
+    /// Returns the portal username.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Returns the `generateToken` endpoint this provider requests tokens from.
+    pub fn token_url(&self) -> &str {
+        &self.token_url
+    }
+
+    /// Requests a new token from the `generateToken` endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or the portal rejects the
+    /// credentials.
+    #[instrument(skip(self), fields(username = %self.username))]
+    async fn fetch_token(&self) -> Result<GeneratedToken> {
+        tracing::debug!("Requesting token via generateToken");
+
+        let expiration = (self.expiration.as_secs() / 60).max(1).to_string();
+        let mut params = vec![
+            ("username", self.username.as_str()),
+            ("password", self.password.expose_secret()),
+            ("expiration", expiration.as_str()),
+            ("f", "json"),
+        ];
+        match &self.client {
+            TokenClient::RequestIp => params.push(("client", "requestip")),
+            TokenClient::Referer(referer) => {
+                params.push(("client", "referer"));
+                params.push(("referer", referer.as_str()));
+            }
+        }
+
+        generate_token(&self.http_client, &self.token_url, &params).await
+    }
+}
+
+#[async_trait]
+impl AuthProvider for UsernamePasswordAuth {
+    /// Returns the cached token, requesting a new one if it is missing or
+    /// about to expire.
+    #[instrument(skip(self))]
+    async fn get_token(&self) -> Result<String> {
+        if let Some(stored) = self.token.read().await.as_ref() {
+            if stored.is_fresh() {
+                tracing::debug!("Returning cached token");
+                return Ok(stored.token.clone());
+            }
+        }
+
+        let mut guard = self.token.write().await;
+        // Another task may have refreshed while we waited for the lock
+        if let Some(stored) = guard.as_ref() {
+            if stored.is_fresh() {
+                return Ok(stored.token.clone());
+            }
+        }
+
+        if let Some(slot) = &self.store {
+            if let Some(persisted) = slot.load().await {
+                let stored =
+                    GeneratedToken::new(persisted.token().clone(), *persisted.expires_at());
+                if stored.is_fresh() {
+                    tracing::debug!("Using token from store");
+                    let token = stored.token.clone();
+                    *guard = Some(stored);
+                    return Ok(token);
+                }
+            }
+        }
+
+        tracing::debug!("No valid token, requesting a new one");
+        let stored = self.fetch_token().await?;
+        let token = stored.token.clone();
+        if let Some(slot) = &self.store {
+            slot.save(PersistedToken::new(token.clone(), stored.expires_at))
+                .await;
+        }
+        *guard = Some(stored);
+        Ok(token)
+    }
+
+    /// Discards the cached token if it is the one the server rejected.
+    ///
+    /// Returns `true` if the rejected token was this provider's current
+    /// token, or was already replaced by a concurrent refresh, and `false`
+    /// for tokens it never held.
+    #[instrument(skip(self, token))]
+    async fn invalidate_token(&self, token: &str) -> bool {
+        let mut guard = self.token.write().await;
+        match guard.as_mut() {
+            Some(stored) if stored.token == token => {
+                // Keep the token so concurrent rejections recognize it
+                if stored.is_fresh() {
+                    tracing::debug!("Discarding rejected token");
+                    stored.refresh_at = Instant::now();
+                    if let Some(slot) = &self.store {
+                        slot.evict().await;
+                    }
+                }
+                true
+            }
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Returns the username and the token endpoint it signs in at.
+    fn principal(&self, _url: &str) -> Option<String> {
+        Some(format!("{} {}", self.token_url, self.username))
+    }
+
+    /// Rebuilds the token HTTP client with the client's transport settings.
+    #[instrument(skip(self, config))]
+    fn configure_http(&mut self, config: &HttpConfig) -> Result<()> {
+        tracing::debug!("Applying HTTP settings to token client");
+        self.http_client = build_token_client(config)?;
+        Ok(())
+    }
+}
+
+/// Builder for [`UsernamePasswordAuth`].
+///
+/// Created by [`UsernamePasswordAuth::builder`].
+pub struct UsernamePasswordAuthBuilder {
+    username: String,
+    password: SecretString,
+    token_url: Option<String>,
+    client: TokenClient,
+    expiration: Duration,
+    http_config: HttpConfig,
+    store: Option<Arc<dyn TokenStore>>,
+}
+
+impl std::fmt::Debug for UsernamePasswordAuthBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UsernamePasswordAuthBuilder")
+            .field("username", &self.username)
+            .field("token_url", &self.token_url)
+            .field("client", &self.client)
+            .field("expiration", &self.expiration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl UsernamePasswordAuthBuilder {
+    /// Requests tokens from the given portal.
+    ///
+    /// Accepts the portal root (`https://host/portal`) or its sharing API
+    /// (`https://host/portal/sharing/rest`); tokens are requested from
+    /// `<sharing API>/generateToken`.
+    pub fn portal_url(mut self, portal_url: impl AsRef<str>) -> Self {
+        self.token_url = Some(format!(
+            "{}/generateToken",
+            sharing_rest_url(portal_url.as_ref())
+        ));
+        self
+    }
+
+    /// Requests tokens from the given `generateToken` endpoint, used as-is.
+    pub fn token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = Some(token_url.into());
+        self
+    }
+
+    /// Sets the transport settings for portal discovery and token requests,
+    /// e.g. from [`ArcGISClientBuilder::http_config`](crate::ArcGISClientBuilder::http_config).
+    ///
+    /// Set this before [`discover_portal`](Self::discover_portal) when the
+    /// server is only reachable through a proxy or uses an internal CA. The
+    /// client builder applies its own settings again when the client is built.
+    pub fn http_config(mut self, config: &HttpConfig) -> Self {
+        self.http_config = config.clone();
+        self
+    }
+
+    /// Requests tokens from the portal a server is federated with.
+    ///
+    /// Reads `owningSystemUrl` from the server's `rest/info`, using the
+    /// transport settings from [`http_config`](Self::http_config).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rest/info` cannot be fetched, or if the server is
+    /// not federated with a portal.
+    #[instrument(skip(self))]
+    pub async fn discover_portal(self, server_url: &str) -> Result<Self> {
+        let http = build_token_client(&self.http_config)?;
+        let portal_url = discover_portal_url(&http, server_url).await?;
+        Ok(self.portal_url(portal_url))
+    }
+
+    /// Sets what tokens are bound to. Defaults to [`TokenClient::RequestIp`].
+    pub fn client(mut self, client: TokenClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Sets the requested token lifetime, rounded down to whole minutes.
+    ///
+    /// Defaults to 60 minutes. The portal may cap the lifetime; the token is
+    /// refreshed based on the expiry the portal reports.
+    pub fn expiration(mut self, expiration: Duration) -> Self {
+        self.expiration = expiration;
+        self
+    }
+
+    /// Keeps tokens in `store`, so a token that is still valid is reused
+    /// across runs instead of requesting a new one.
+    pub fn token_store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Builds the provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    #[instrument(skip(self), fields(username = %self.username))]
+    pub fn build(self) -> Result<UsernamePasswordAuth> {
+        tracing::debug!("Creating username/password authenticator");
+
+        let token_url = self
+            .token_url
+            .unwrap_or_else(|| format!("{}/generateToken", ARCGIS_ONLINE_PORTAL));
+        let http_client = build_token_client(&self.http_config)?;
+
+        let store = self.store.map(|store| {
+            let key = TokenKey::new("password", self.username.clone(), token_url.clone());
+            let key = match &self.client {
+                TokenClient::Referer(referer) => key.with_referer(referer.clone()),
+                TokenClient::RequestIp => key,
+            };
+            StoreSlot::new(store, key)
+        });
+
+        tracing::debug!(token_url = %token_url, "Username/password authenticator created");
+
+        Ok(UsernamePasswordAuth {
+            username: self.username,
+            password: self.password,
+            token_url,
+            client: self.client,
+            expiration: self.expiration,
+            http_client,
+            token: Arc::new(RwLock::new(None)),
+            store,
+        })
+    }
+}
@@ -0,0 +1,80 @@
+//! Blocking ArcGIS client and its runtime.
+
+use crate::{AuthProvider, Result};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use tracing::instrument;
+
+/// Blocking counterpart of [`crate::ArcGISClient`].
+///
+/// Wraps an async client together with the runtime its requests run on.
+/// The runtime has a single worker thread, which keeps pooled connections
+/// and timers alive between calls. Cloning is cheap: clones and the service
+/// clients created from this client share both.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::blocking::{ArcGISClient, GeocodeServiceClient};
+/// use arcgis::ApiKeyAuth;
+///
+/// # fn example() -> arcgis::Result<()> {
+/// let client = ArcGISClient::new(ApiKeyAuth::new("YOUR_API_KEY"))?;
+/// let geocoder = GeocodeServiceClient::new(
+///     "https://geocode-api.arcgis.com/arcgis/rest/services/World/GeocodeServer",
+///     &client,
+/// );
+///
+/// let response = geocoder.find_address_candidates("380 New York St, Redlands, CA")?;
+/// println!("{} candidates", response.candidates().len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ArcGISClient {
+    inner: crate::ArcGISClient,
+    runtime: Arc<Runtime>,
+}
+
+impl ArcGISClient {
+    /// Creates a blocking client with the given authentication provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started.
+    pub fn new(auth: impl AuthProvider + 'static) -> Result<Self> {
+        Self::from_async(crate::ArcGISClient::new(auth))
+    }
+
+    /// Wraps an async client, e.g. one configured with
+    /// [`ArcGISClient::builder`](crate::ArcGISClient::builder).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started.
+    #[instrument(skip(client))]
+    pub fn from_async(client: crate::ArcGISClient) -> Result<Self> {
+        tracing::debug!("Starting blocking client runtime");
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("arcgis-blocking")
+            .enable_all()
+            .build()?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client this client wraps.
+    pub fn as_async(&self) -> &crate::ArcGISClient {
+        &self.inner
+    }
+
+    /// Returns the shared runtime for service clients.
+    pub(super) fn runtime(&self) -> Arc<Runtime> {
+        Arc::clone(&self.runtime)
+    }
+}
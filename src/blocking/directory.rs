@@ -0,0 +1,34 @@
+//! Blocking services directory client.
+
+use super::ArcGISClient;
+use crate::{DirectoryFolder, DirectoryListing, DirectoryService, ServiceEntry};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::ServicesDirectoryClient`].
+    ServicesDirectoryClient {
+        fn list(&self, folder: Option<&str>) -> DirectoryListing;
+        fn service(&self, entry: &ServiceEntry) -> DirectoryService;
+        fn crawl(&self) -> DirectoryFolder;
+        fn crawl_folder(&self, folder: &str) -> DirectoryFolder;
+    }
+}
+
+impl ServicesDirectoryClient {
+    /// Creates a new blocking services directory client.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Services root URL (e.g., `https://host/arcgis/rest/services`)
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::ServicesDirectoryClient::new(url, client.as_async()),
+            client,
+        )
+    }
+
+    /// Returns the services root URL.
+    pub fn url(&self) -> &str {
+        self.inner.url()
+    }
+}
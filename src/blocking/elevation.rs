@@ -0,0 +1,40 @@
+//! Blocking Elevation client.
+
+use super::ArcGISClient;
+use crate::{
+    ProfileParameters, ProfileResult, SummarizeElevationParameters, SummarizeElevationResult,
+    ViewshedParameters, ViewshedResult,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::ElevationClient`].
+    ElevationClient {
+        fn profile(&self, params: ProfileParameters) -> ProfileResult;
+        fn submit_summarize_elevation(
+            &self,
+            params: SummarizeElevationParameters,
+        ) -> crate::GPJobInfo;
+        fn poll_summarize_elevation(
+            &self,
+            job_id: &str,
+            timeout_ms: Option<u64>,
+        ) -> SummarizeElevationResult;
+        fn submit_viewshed(&self, params: ViewshedParameters) -> crate::GPJobInfo;
+        fn poll_viewshed(&self, job_id: &str, timeout_ms: Option<u64>) -> ViewshedResult;
+    }
+}
+
+impl ElevationClient {
+    /// Creates a new blocking Elevation client for the ArcGIS Online service.
+    pub fn new(client: &ArcGISClient) -> Self {
+        Self::wrap(crate::ElevationClient::new(client.as_async()), client)
+    }
+
+    /// Creates a new blocking Elevation client for a custom service URL.
+    pub fn with_url(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::ElevationClient::with_url(url, client.as_async()),
+            client,
+        )
+    }
+}
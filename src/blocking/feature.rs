@@ -0,0 +1,203 @@
+//! Blocking Feature Service client.
+
+use super::ArcGISClient;
+use crate::{
+    AddAttachmentResult, ArcGISGeometry, AttachmentId, AttachmentInfo, AttachmentSource,
+    DeleteAttachmentsResponse, DownloadResult, DownloadTarget, EditOptions, EditResult, Feature,
+    FeatureQueryParams, FeatureSet, GeometryType, LayerDefinition, LayerId, ObjectId,
+    ResponseFormat, ServerInfo, ServiceCapabilities, ServiceDefinition, SpatialRel,
+    StatisticDefinition, TableDefinition,
+};
+use futures::StreamExt;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::FeatureServiceClient`].
+    FeatureServiceClient {
+        fn truncate(&self, layer_id: LayerId) -> crate::TruncateResult;
+        fn query_domains(&self, layers: Vec<LayerId>) -> crate::QueryDomainsResponse;
+        fn query_attachments(&self, layer_id: LayerId, object_id: ObjectId) -> Vec<AttachmentInfo>;
+        fn delete_attachments(
+            &self,
+            layer_id: LayerId,
+            object_id: ObjectId,
+            attachment_ids: Vec<AttachmentId>,
+        ) -> DeleteAttachmentsResponse;
+        fn add_attachment(
+            &self,
+            layer_id: LayerId,
+            object_id: ObjectId,
+            source: AttachmentSource,
+        ) -> AddAttachmentResult;
+        fn update_attachment(
+            &self,
+            layer_id: LayerId,
+            object_id: ObjectId,
+            attachment_id: AttachmentId,
+            source: AttachmentSource,
+        ) -> crate::UpdateAttachmentResult;
+        fn download_attachment(
+            &self,
+            layer_id: LayerId,
+            object_id: ObjectId,
+            attachment_id: AttachmentId,
+            target: DownloadTarget,
+        ) -> DownloadResult;
+        fn server_info(&self) -> ServerInfo;
+        fn capabilities(&self) -> ServiceCapabilities;
+        fn layer_capabilities(&self, layer_id: LayerId) -> ServiceCapabilities;
+        fn get_definition(&self) -> ServiceDefinition;
+        fn get_layer_definition(&self, layer_id: LayerId) -> LayerDefinition;
+        fn get_table_definition(&self, table_id: LayerId) -> TableDefinition;
+        fn add_features(
+            &self,
+            layer_id: LayerId,
+            features: Vec<Feature>,
+            options: EditOptions,
+        ) -> EditResult;
+        fn update_features(
+            &self,
+            layer_id: LayerId,
+            features: Vec<Feature>,
+            options: EditOptions,
+        ) -> EditResult;
+        fn delete_features(
+            &self,
+            layer_id: LayerId,
+            object_ids: Vec<ObjectId>,
+            options: EditOptions,
+        ) -> EditResult;
+        fn apply_edits(
+            &self,
+            layer_id: LayerId,
+            adds: Option<Vec<Feature>>,
+            updates: Option<Vec<Feature>>,
+            deletes: Option<Vec<ObjectId>>,
+            options: EditOptions,
+        ) -> EditResult;
+        fn calculate_records(
+            &self,
+            layer_id: LayerId,
+            where_clause: impl Into<String>,
+            calc_expression: Vec<crate::FieldCalculation>,
+            options: EditOptions,
+        ) -> crate::CalculateResult;
+        fn apply_edits_with_global_ids(
+            &self,
+            layer_id: LayerId,
+            adds: Option<Vec<Feature>>,
+            updates: Option<Vec<Feature>>,
+            deletes: Option<Vec<String>>,
+            options: EditOptions,
+        ) -> EditResult;
+        fn query_with_params(&self, layer_id: LayerId, params: FeatureQueryParams) -> FeatureSet;
+        fn query_related_records(
+            &self,
+            layer_id: LayerId,
+            params: crate::RelatedRecordsParams,
+        ) -> crate::RelatedRecordsResponse;
+        fn query_top_features(
+            &self,
+            layer_id: LayerId,
+            params: crate::TopFeaturesParams,
+        ) -> crate::FeatureSet;
+        fn query_feature_count(&self, layer_id: LayerId, where_clause: impl Into<String>) -> u32;
+    }
+}
+
+impl FeatureServiceClient {
+    /// Creates a new blocking Feature Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the feature service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::FeatureServiceClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+
+    /// Creates a query builder for the specified layer.
+    ///
+    /// See [`FeatureServiceClient::query`](crate::FeatureServiceClient::query).
+    pub fn query(&self, layer_id: LayerId) -> QueryBuilder {
+        QueryBuilder {
+            inner: self.inner.query(layer_id),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+
+    /// Returns the capabilities loaded by [`capabilities`](Self::capabilities), if any.
+    pub fn cached_capabilities(&self) -> Option<ServiceCapabilities> {
+        self.inner.cached_capabilities()
+    }
+
+    /// Returns the capabilities checks use for a layer, if any are loaded.
+    ///
+    /// See [`crate::FeatureServiceClient::cached_layer_capabilities`].
+    pub fn cached_layer_capabilities(&self, layer_id: LayerId) -> Option<ServiceCapabilities> {
+        self.inner.cached_layer_capabilities(layer_id)
+    }
+}
+
+/// Blocking counterpart of [`crate::QueryBuilder`].
+///
+/// Created by [`FeatureServiceClient::query`].
+pub struct QueryBuilder {
+    inner: crate::QueryBuilder,
+    runtime: Arc<Runtime>,
+}
+
+impl QueryBuilder {
+    blocking_setters! {
+        QueryBuilder {
+            fn where_clause(clause: impl Into<String>);
+            fn out_fields(fields: &[&str]);
+            fn return_geometry(return_geom: bool);
+            fn format(format: ResponseFormat);
+            fn pbf();
+            fn json();
+            fn geojson();
+            fn spatial_filter(
+                geometry: ArcGISGeometry,
+                geometry_type: GeometryType,
+                spatial_rel: SpatialRel,
+            );
+            fn limit(count: u32);
+            fn offset(offset: u32);
+            fn object_ids(ids: &[ObjectId]);
+            fn distinct(distinct: bool);
+            fn ids_only(ids_only: bool);
+            fn count_only(count_only: bool);
+            fn order_by(fields: &[&str]);
+            fn group_by(fields: &[&str]);
+            fn statistics(stats: Vec<StatisticDefinition>);
+            fn having(clause: impl Into<String>);
+            fn out_sr(wkid: i32);
+            fn prefetch(pages: usize);
+        }
+    }
+
+    /// Blocking version of [`QueryBuilder::execute`](crate::QueryBuilder::execute).
+    pub fn execute(self) -> crate::Result<FeatureSet> {
+        self.runtime.block_on(self.inner.execute())
+    }
+
+    /// Blocking version of [`QueryBuilder::execute_all`](crate::QueryBuilder::execute_all).
+    pub fn execute_all(self) -> crate::Result<FeatureSet> {
+        self.runtime.block_on(self.inner.execute_all())
+    }
+
+    /// Blocking version of [`QueryBuilder::stream`](crate::QueryBuilder::stream).
+    ///
+    /// Returns an iterator that fetches pages as it is advanced, with the
+    /// same bounded prefetch as the async stream.
+    pub fn stream(self) -> impl Iterator<Item = crate::Result<Feature>> {
+        let runtime = self.runtime;
+        let mut features = Box::pin(self.inner.stream());
+        std::iter::from_fn(move || runtime.block_on(features.next()))
+    }
+}
@@ -0,0 +1,66 @@
+//! Blocking Geocode Service client.
+
+use super::ArcGISClient;
+use crate::{
+    GeocodeResponse, LocationType, ProjectedPoint, ReverseGeocodeResponse, SuggestResponse,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::GeocodeServiceClient`].
+    GeocodeServiceClient {
+        fn find_address_candidates(&self, address: impl Into<String>) -> GeocodeResponse;
+        fn find_address_candidates_with_options(
+            &self,
+            address: impl Into<String>,
+            max_locations: Option<u32>,
+            location_type: Option<LocationType>,
+        ) -> GeocodeResponse;
+        fn find_address_candidates_with_sr(
+            &self,
+            address: impl Into<String>,
+            out_sr: i32,
+        ) -> GeocodeResponse;
+        fn suggest(&self, text: impl Into<String>) -> SuggestResponse;
+        fn suggest_with_category(
+            &self,
+            text: impl Into<String> + AsRef<str>,
+            category: crate::Category,
+        ) -> SuggestResponse;
+        fn geocode_addresses(
+            &self,
+            addresses: Vec<crate::BatchGeocodeRecord>,
+        ) -> crate::BatchGeocodeResponse;
+    }
+}
+
+impl GeocodeServiceClient {
+    /// Creates a new blocking Geocode Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the geocode service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::GeocodeServiceClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+
+    /// Blocking version of [`GeocodeServiceClient::reverse_geocode`](crate::GeocodeServiceClient::reverse_geocode).
+    pub fn reverse_geocode<P: ProjectedPoint>(
+        &self,
+        location: &P,
+    ) -> crate::Result<ReverseGeocodeResponse> {
+        self.runtime.block_on(self.inner.reverse_geocode(location))
+    }
+
+    /// Blocking version of [`GeocodeServiceClient::reverse_geocode_to`](crate::GeocodeServiceClient::reverse_geocode_to).
+    pub fn reverse_geocode_to<In: ProjectedPoint, Out: ProjectedPoint>(
+        &self,
+        location: &In,
+    ) -> crate::Result<ReverseGeocodeResponse> {
+        self.runtime
+            .block_on(self.inner.reverse_geocode_to::<In, Out>(location))
+    }
+}
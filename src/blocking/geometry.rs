@@ -0,0 +1,48 @@
+//! Blocking Geometry Service client.
+
+use super::ArcGISClient;
+use crate::{
+    ArcGISEnvelope, ArcGISGeometry, AreasAndLengthsParameters, AreasAndLengthsResult,
+    BufferParameters, BufferResult, DistanceParameters, DistanceResult, ProjectParameters,
+    ProjectResult, SimplifyParameters, SimplifyResult, Transformation, UnionParameters,
+    UnionResult,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::GeometryServiceClient`].
+    GeometryServiceClient {
+        fn project(
+            &self,
+            geometries: Vec<ArcGISGeometry>,
+            in_sr: i32,
+            out_sr: i32,
+        ) -> ProjectResult;
+        fn project_with_params(&self, params: ProjectParameters) -> ProjectResult;
+        fn buffer(&self, params: BufferParameters) -> BufferResult;
+        fn find_transformations(
+            &self,
+            in_sr: i32,
+            out_sr: i32,
+            extent_of_interest: Option<ArcGISEnvelope>,
+        ) -> Vec<Transformation>;
+        fn simplify(&self, params: SimplifyParameters) -> SimplifyResult;
+        fn union(&self, params: UnionParameters) -> UnionResult;
+        fn areas_and_lengths(&self, params: AreasAndLengthsParameters) -> AreasAndLengthsResult;
+        fn distance(&self, params: DistanceParameters) -> DistanceResult;
+    }
+}
+
+impl GeometryServiceClient {
+    /// Creates a new blocking Geometry Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the geometry service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::GeometryServiceClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+}
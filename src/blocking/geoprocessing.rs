@@ -0,0 +1,41 @@
+//! Blocking Geoprocessing Service client.
+
+use super::ArcGISClient;
+use crate::{GPExecuteResult, GPJobInfo, GPMessage};
+use serde_json::Value;
+use std::collections::HashMap;
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::GeoprocessingServiceClient`].
+    GeoprocessingServiceClient {
+        fn execute(&self, parameters: HashMap<String, Value>) -> GPExecuteResult;
+        fn submit_job(&self, parameters: HashMap<String, Value>) -> GPJobInfo;
+        fn get_job_status(&self, job_id: &str) -> GPJobInfo;
+        fn get_job_result(&self, job_id: &str) -> GPJobInfo;
+        fn cancel_job(&self, job_id: &str) -> GPJobInfo;
+        fn get_job_messages(&self, job_id: &str) -> Vec<GPMessage>;
+        fn get_result_data(&self, job_id: &str, param_name: &str) -> Value;
+        fn poll_until_complete(
+            &self,
+            job_id: &str,
+            initial_delay_ms: u64,
+            max_delay_ms: u64,
+            timeout_ms: Option<u64>,
+        ) -> GPJobInfo;
+    }
+}
+
+impl GeoprocessingServiceClient {
+    /// Creates a new blocking Geoprocessing Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the geoprocessing task
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::GeoprocessingServiceClient::new(url, client.as_async()),
+            client,
+        )
+    }
+}
@@ -0,0 +1,34 @@
+//! Blocking Image Service client.
+
+use super::ArcGISClient;
+use crate::{
+    ArcGISGeometry, ExportImageParameters, ExportImageResult, HistogramParameters, HistogramResult,
+    IdentifyParameters, ImageIdentifyResult, RasterInfo, SampleParameters, SampleResult,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::ImageServiceClient`].
+    ImageServiceClient {
+        fn export_image(&self, params: ExportImageParameters) -> ExportImageResult;
+        fn identify(&self, geometry: &ArcGISGeometry) -> ImageIdentifyResult;
+        fn identify_with_params(&self, params: IdentifyParameters) -> ImageIdentifyResult;
+        fn get_samples(&self, params: SampleParameters) -> SampleResult;
+        fn compute_histograms(&self, params: HistogramParameters) -> HistogramResult;
+        fn get_raster_info(&self) -> RasterInfo;
+    }
+}
+
+impl ImageServiceClient {
+    /// Creates a new blocking Image Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The base URL of the image service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::ImageServiceClient::new(url, client.as_async()),
+            client,
+        )
+    }
+}
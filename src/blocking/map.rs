@@ -0,0 +1,94 @@
+//! Blocking Map Service client.
+
+use super::ArcGISClient;
+use crate::{
+    ExportMapParams, ExportResult, ExportTarget, IdentifyParams, IdentifyResponse, ImageFormat,
+    LayerOperation, LegendResponse, MapServiceMetadata, TileCoordinate, TimeRelation,
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::MapServiceClient`].
+    MapServiceClient {
+        fn export_map(&self, params: ExportMapParams, target: ExportTarget) -> ExportResult;
+        fn export_tile(&self, coord: TileCoordinate, target: ExportTarget) -> ExportResult;
+        fn get_legend(&self) -> LegendResponse;
+        fn get_metadata(&self) -> MapServiceMetadata;
+        fn identify(&self, params: IdentifyParams) -> IdentifyResponse;
+        fn find(&self, params: crate::FindParams) -> crate::FindResponse;
+        fn generate_kml(&self, params: crate::GenerateKmlParams) -> String;
+        fn generate_renderer(
+            &self,
+            layer_id: i32,
+            params: crate::GenerateRendererParams,
+        ) -> crate::RendererResponse;
+        fn query_domains(&self, layers: Vec<crate::LayerId>) -> crate::QueryDomainsResponse;
+    }
+}
+
+impl MapServiceClient {
+    /// Creates a new blocking Map Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the map service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::MapServiceClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+
+    /// Creates a fluent export builder.
+    ///
+    /// See [`MapServiceClient::export`](crate::MapServiceClient::export).
+    pub fn export(&self) -> ExportMapBuilder {
+        ExportMapBuilder {
+            inner: self.inner.export(),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+}
+
+/// Blocking counterpart of [`crate::ExportMapBuilder`].
+///
+/// Created by [`MapServiceClient::export`].
+pub struct ExportMapBuilder {
+    inner: crate::ExportMapBuilder,
+    runtime: Arc<Runtime>,
+}
+
+impl ExportMapBuilder {
+    blocking_setters! {
+        ExportMapBuilder {
+            fn bbox(bbox: impl Into<String>);
+            fn bbox_sr(wkid: i32);
+            fn layers(layers: impl Into<String>);
+            fn layer_visibility(operation: LayerOperation, layer_ids: &[i32]);
+            fn layer_defs(defs: impl Into<String>);
+            fn size(width: u32, height: u32);
+            fn dpi(dpi: i32);
+            fn image_sr(wkid: i32);
+            fn format(format: ImageFormat);
+            fn transparent(transparent: bool);
+            fn time(time: impl Into<String>);
+            fn time_relation(relation: TimeRelation);
+            fn layer_time_options(options: impl Into<String>);
+            fn dynamic_layers(layers: impl Into<String>);
+            fn gdb_version(version: impl Into<String>);
+            fn map_scale(scale: f64);
+            fn rotation(degrees: f64);
+            fn datum_transformations(transformations: impl Into<String>);
+            fn map_range_values(values: impl Into<String>);
+            fn layer_parameter_values(values: impl Into<String>);
+            fn pretty_json(pretty: bool);
+        }
+    }
+
+    /// Blocking version of [`ExportMapBuilder::execute`](crate::ExportMapBuilder::execute).
+    pub fn execute(self, target: ExportTarget) -> crate::Result<ExportResult> {
+        self.runtime.block_on(self.inner.execute(target))
+    }
+}
@@ -0,0 +1,139 @@
+//! Blocking (synchronous) service clients.
+//!
+//! Enabled with the `blocking` Cargo feature. Each client here mirrors the
+//! async client of the same name, with every `async fn` replaced by a method
+//! that blocks the calling thread until the request completes. Requests run
+//! on a runtime owned by the blocking [`ArcGISClient`] and shared by the
+//! service clients created from it, in the spirit of `reqwest::blocking`.
+//!
+//! The blocking clients must not be used from within an async runtime: calls
+//! from async code panic, as does dropping the last client there. Async
+//! applications should use the async clients directly.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::blocking::{ArcGISClient, FeatureServiceClient};
+//! use arcgis::{ApiKeyAuth, LayerId};
+//!
+//! # fn example() -> arcgis::Result<()> {
+//! let client = ArcGISClient::new(ApiKeyAuth::new("YOUR_API_KEY"))?;
+//! let service = FeatureServiceClient::new(
+//!     "https://services.arcgis.com/org/arcgis/rest/services/Dataset/FeatureServer",
+//!     &client,
+//! );
+//!
+//! let features = service
+//!     .query(LayerId::new(0))
+//!     .where_clause("POPULATION > 100000")
+//!     .execute()?;
+//! println!("Retrieved {} features", features.features().len());
+//! # Ok(())
+//! # }
+//! ```
+
+/// Defines a blocking mirror of an async service client.
+///
+/// Generates the wrapper struct, `as_async`, a `wrap` constructor for the
+/// hand-written constructors, and one blocking method per listed async method.
+macro_rules! blocking_client {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $(
+                fn $method:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name {
+            inner: crate::$name,
+            runtime: std::sync::Arc<tokio::runtime::Runtime>,
+        }
+
+        impl $name {
+            /// Wraps an async client, sharing the runtime of `client`.
+            fn wrap(inner: crate::$name, client: &super::ArcGISClient) -> Self {
+                Self {
+                    inner,
+                    runtime: client.runtime(),
+                }
+            }
+
+            #[doc = concat!(
+                "Returns the async [`", stringify!($name), "`](crate::", stringify!($name), ") ",
+                "this client wraps."
+            )]
+            pub fn as_async(&self) -> &crate::$name {
+                &self.inner
+            }
+
+            $(
+                #[doc = concat!(
+                    "Blocking version of [`", stringify!($name), "::", stringify!($method), "`]",
+                    "(crate::", stringify!($name), "::", stringify!($method), ")."
+                )]
+                pub fn $method(&self $(, $arg: $ty)*) -> crate::Result<$ret> {
+                    self.runtime.block_on(self.inner.$method($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+/// Defines blocking mirrors of a request builder's setters.
+///
+/// The blocking builder must have `inner` and `runtime` fields.
+macro_rules! blocking_setters {
+    (
+        $builder:ident {
+            $(
+                fn $method:ident($($arg:ident: $ty:ty),* $(,)?);
+            )*
+        }
+    ) => {
+        $(
+            #[doc = concat!(
+                "See [`", stringify!($builder), "::", stringify!($method), "`]",
+                "(crate::", stringify!($builder), "::", stringify!($method), ")."
+            )]
+            pub fn $method(self $(, $arg: $ty)*) -> Self {
+                Self {
+                    inner: self.inner.$method($($arg),*),
+                    runtime: self.runtime,
+                }
+            }
+        )*
+    };
+}
+
+mod client;
+mod directory;
+mod elevation;
+mod feature;
+mod geocode;
+mod geometry;
+mod geoprocessing;
+mod image;
+mod map;
+mod places;
+mod portal;
+mod routing;
+mod vector_tile;
+mod version_management;
+
+pub use client::ArcGISClient;
+pub use directory::ServicesDirectoryClient;
+pub use elevation::ElevationClient;
+pub use feature::{FeatureServiceClient, QueryBuilder};
+pub use geocode::GeocodeServiceClient;
+pub use geometry::GeometryServiceClient;
+pub use geoprocessing::GeoprocessingServiceClient;
+pub use image::ImageServiceClient;
+pub use map::{ExportMapBuilder, MapServiceClient};
+pub use places::PlacesClient;
+pub use portal::PortalClient;
+pub use routing::RoutingServiceClient;
+pub use vector_tile::VectorTileServiceClient;
+pub use version_management::VersionManagementClient;
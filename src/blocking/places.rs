@@ -0,0 +1,28 @@
+//! Blocking Places client.
+
+use super::ArcGISClient;
+use crate::{CategoriesResult, PlaceDetailsResult, PlaceSearchParameters, PlaceSearchResult};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::PlacesClient`].
+    PlacesClient {
+        fn find_places_near_point(&self, params: PlaceSearchParameters) -> PlaceSearchResult;
+        fn get_place_details(&self, place_id: &str) -> PlaceDetailsResult;
+        fn get_categories(&self) -> CategoriesResult;
+    }
+}
+
+impl PlacesClient {
+    /// Creates a new blocking Places client for the ArcGIS Online service.
+    pub fn new(client: &ArcGISClient) -> Self {
+        Self::wrap(crate::PlacesClient::new(client.as_async()), client)
+    }
+
+    /// Creates a new blocking Places client for a custom service URL.
+    pub fn with_url(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::PlacesClient::with_url(url, client.as_async()),
+            client,
+        )
+    }
+}
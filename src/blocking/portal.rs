@@ -0,0 +1,114 @@
+//! Blocking Portal client.
+
+use super::ArcGISClient;
+use crate::{
+    AddItemParams, AddItemResult, CreateGroupParams, CreateServiceParams, CreateServiceResult,
+    DeleteItemResult, DeleteServiceResult, GroupInfo, GroupResult, GroupSearchParameters,
+    GroupSearchResult, ItemDataUpload, ItemInfo, OverwriteParameters, OverwriteResult,
+    PublishParameters, PublishResult, PublishStatus, SearchParameters, SearchResult,
+    ShareItemResult, SharingParameters, UnshareItemResult, UpdateGroupParams, UpdateItemParams,
+    UpdateItemResult, UpdateServiceDefinitionParams, UpdateServiceDefinitionResult, UserInfo,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::PortalClient`].
+    PortalClient {
+        fn search_groups(&self, params: GroupSearchParameters) -> GroupSearchResult;
+        fn get_group(&self, group_id: impl AsRef<str>) -> GroupInfo;
+        fn create_group(&self, params: CreateGroupParams) -> GroupResult;
+        fn update_group(&self, group_id: impl AsRef<str>, params: UpdateGroupParams) -> GroupResult;
+        fn delete_group(&self, group_id: impl AsRef<str>) -> GroupResult;
+        fn join_group(&self, group_id: impl AsRef<str>) -> GroupResult;
+        fn leave_group(&self, group_id: impl AsRef<str>) -> GroupResult;
+        fn add_to_group(
+            &self,
+            group_id: impl AsRef<str>,
+            item_id: impl AsRef<str>,
+        ) -> crate::ShareItemResult;
+        fn remove_from_group(
+            &self,
+            group_id: impl AsRef<str>,
+            item_id: impl AsRef<str>,
+        ) -> crate::UnshareItemResult;
+        fn get_item(&self, item_id: impl AsRef<str>) -> ItemInfo;
+        fn add_item(&self, params: AddItemParams) -> AddItemResult;
+        fn update_item(
+            &self,
+            item_id: impl AsRef<str>,
+            params: UpdateItemParams,
+        ) -> UpdateItemResult;
+        fn delete_item(&self, item_id: impl AsRef<str>) -> DeleteItemResult;
+        fn get_item_data(&self, item_id: impl AsRef<str>) -> bytes::Bytes;
+        fn get_item_data_zip(&self, item_id: impl AsRef<str>) -> bytes::Bytes;
+        fn update_item_data_v2(
+            &self,
+            item_id: impl AsRef<str>,
+            upload: ItemDataUpload,
+        ) -> UpdateItemResult;
+        fn create_service(&self, params: CreateServiceParams) -> CreateServiceResult;
+        fn publish(&self, item_id: impl AsRef<str>, params: PublishParameters) -> PublishResult;
+        fn get_publish_status(&self, job_id: impl AsRef<str>) -> PublishStatus;
+        fn update_service_definition(
+            &self,
+            service_item_id: impl AsRef<str>,
+            params: UpdateServiceDefinitionParams,
+        ) -> UpdateServiceDefinitionResult;
+        fn add_to_definition(
+            &self,
+            service_item_id: impl AsRef<str>,
+            params: crate::AddToDefinitionParams,
+        ) -> crate::AddToDefinitionResult;
+        fn delete_service(&self, service_item_id: impl AsRef<str>) -> DeleteServiceResult;
+        fn overwrite_service(&self, params: OverwriteParameters) -> OverwriteResult;
+        fn search(&self, params: SearchParameters) -> SearchResult;
+        fn share_item(
+            &self,
+            item_id: impl AsRef<str>,
+            params: SharingParameters,
+        ) -> ShareItemResult;
+        fn unshare_item(
+            &self,
+            item_id: impl AsRef<str>,
+            params: SharingParameters,
+        ) -> UnshareItemResult;
+        fn get_self(&self) -> UserInfo;
+    }
+}
+
+impl PortalClient {
+    /// Creates a new blocking Portal client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The portal sharing REST URL
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::PortalClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+
+    /// Creates a blocking Portal client for ArcGIS Online.
+    pub fn agol(client: &ArcGISClient) -> Self {
+        Self::wrap(crate::PortalClient::agol(client.as_async()), client)
+    }
+
+    /// Creates a blocking Portal client for ArcGIS Enterprise from
+    /// `ARCGIS_ENTERPRISE_PORTAL`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ARCGIS_ENTERPRISE_PORTAL` is not set.
+    pub fn enterprise(client: &ArcGISClient) -> crate::Result<Self> {
+        Ok(Self::wrap(
+            crate::PortalClient::enterprise(client.as_async())?,
+            client,
+        ))
+    }
+
+    /// Returns the portal's base URL.
+    pub fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+}
@@ -0,0 +1,35 @@
+//! Blocking Routing Service client.
+
+use super::ArcGISClient;
+use crate::{
+    ClosestFacilityParameters, ClosestFacilityResult, ODCostMatrixParameters, ODCostMatrixResult,
+    RouteParameters, RouteResult, ServiceAreaParameters, ServiceAreaResult,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::RoutingServiceClient`].
+    RoutingServiceClient {
+        fn solve_route(&self, params: RouteParameters) -> RouteResult;
+        fn solve_service_area(&self, params: ServiceAreaParameters) -> ServiceAreaResult;
+        fn solve_closest_facility(
+            &self,
+            params: ClosestFacilityParameters,
+        ) -> ClosestFacilityResult;
+        fn generate_od_cost_matrix(&self, params: ODCostMatrixParameters) -> ODCostMatrixResult;
+    }
+}
+
+impl RoutingServiceClient {
+    /// Creates a new blocking Routing Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the routing service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::RoutingServiceClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+}
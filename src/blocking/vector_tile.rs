@@ -0,0 +1,31 @@
+//! Blocking Vector Tile Service client.
+
+use super::ArcGISClient;
+use crate::{FontStack, GlyphRange, TileCoordinate, VectorTileStyle};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::VectorTileServiceClient`].
+    VectorTileServiceClient {
+        fn get_tile(&self, tile: &TileCoordinate) -> Vec<u8>;
+        fn get_tiles(&self, tiles: &[TileCoordinate]) -> Vec<Vec<u8>>;
+        fn get_style(&self) -> VectorTileStyle;
+        fn get_fonts(&self, font_stack: &FontStack, range: &GlyphRange) -> Vec<u8>;
+        fn get_sprite_metadata(&self) -> serde_json::Value;
+        fn get_sprite_image(&self) -> Vec<u8>;
+    }
+}
+
+impl VectorTileServiceClient {
+    /// Creates a new blocking Vector Tile Service client.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The base URL of the vector tile service
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::VectorTileServiceClient::new(url, client.as_async()),
+            client,
+        )
+    }
+}
@@ -0,0 +1,100 @@
+//! Blocking Version Management client.
+
+use super::ArcGISClient;
+use crate::{
+    AlterResponse, AlterVersionParams, ConflictDetection, ConflictsResponse, CreateVersionParams,
+    CreateVersionResponse, DeleteForwardEditsResponse, DeleteResponse, DifferenceResultType,
+    DifferencesResponse, InspectConflictLayer, InspectConflictsResponse, PartialPostRow,
+    PostResponse, ReconcileResponse, RestoreRowsLayer, RestoreRowsResponse, SessionId,
+    StartEditingResponse, StartReadingResponse, StopEditingResponse, StopReadingResponse,
+    VersionGuid, VersionInfo, VersionInfosResponse,
+};
+
+blocking_client! {
+    /// Blocking counterpart of [`crate::VersionManagementClient`].
+    VersionManagementClient {
+        fn conflicts(&self, version_guid: VersionGuid, session_id: SessionId) -> ConflictsResponse;
+        fn inspect_conflicts(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+            inspect_all: bool,
+            set_inspected: bool,
+            conflicts: Option<Vec<InspectConflictLayer>>,
+        ) -> InspectConflictsResponse;
+        fn restore_rows(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+            rows: Vec<RestoreRowsLayer>,
+        ) -> RestoreRowsResponse;
+        fn reconcile(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+            abort_if_conflicts: bool,
+            conflict_detection: ConflictDetection,
+            with_post: bool,
+        ) -> ReconcileResponse;
+        fn post(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+            partial_rows: Option<Vec<PartialPostRow>>,
+        ) -> PostResponse;
+        fn start_editing(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+        ) -> StartEditingResponse;
+        fn stop_editing(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+            save_edits: bool,
+        ) -> StopEditingResponse;
+        fn start_reading(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+        ) -> StartReadingResponse;
+        fn stop_reading(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+        ) -> StopReadingResponse;
+        fn delete_forward_edits(
+            &self,
+            version_guid: VersionGuid,
+            session_id: SessionId,
+            moment: u64,
+        ) -> DeleteForwardEditsResponse;
+        fn differences(
+            &self,
+            version_guid: VersionGuid,
+            session_id: Option<SessionId>,
+            result_type: DifferenceResultType,
+            layers: Option<Vec<i64>>,
+        ) -> DifferencesResponse;
+        fn create(&self, params: CreateVersionParams) -> CreateVersionResponse;
+        fn alter(&self, version_guid: VersionGuid, params: AlterVersionParams) -> AlterResponse;
+        fn delete(&self, version_guid: VersionGuid) -> DeleteResponse;
+        fn get_info(&self, version_guid: VersionGuid) -> VersionInfo;
+        fn list_versions(&self) -> VersionInfosResponse;
+    }
+}
+
+impl VersionManagementClient {
+    /// Creates a new blocking Version Management client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The URL of the version management server
+    /// * `client` - Blocking client whose runtime and connections are shared
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
+        Self::wrap(
+            crate::VersionManagementClient::new(base_url, client.as_async()),
+            client,
+        )
+    }
+}
@@ -0,0 +1,369 @@
+//! Builder for [`ArcGISClient`] with HTTP transport configuration.
+
+use super::{
+    ArcGISClient, CreditMeter, DEFAULT_MAX_URL_LENGTH, HttpTransport, MetricsRecorder, Middleware,
+    RateLimiter, ResponseCache, RetryPolicy, TokenPlacement,
+};
+use crate::{AuthProvider, NoAuth, Result};
+use derive_getters::Getters;
+use reqwest::header::{HeaderMap, HeaderValue, REFERER};
+use reqwest::{Certificate, Client as ReqwestClient, ClientBuilder, Proxy};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::instrument;
+
+/// HTTP transport settings shared by the client and its authentication provider.
+///
+/// An [`ArcGISClientBuilder`] collects these settings and hands them to the
+/// [`AuthProvider`] via [`AuthProvider::configure_http`], so token requests
+/// (e.g. [`ClientCredentialsAuth`](crate::ClientCredentialsAuth)) go through
+/// the same proxy, certificates, and timeouts as service requests.
+#[derive(Debug, Clone, Default, Getters)]
+pub struct HttpConfig {
+    /// Total request timeout.
+    timeout: Option<Duration>,
+    /// Timeout for establishing a connection.
+    connect_timeout: Option<Duration>,
+    /// Timeout for each read of the response.
+    read_timeout: Option<Duration>,
+    /// Proxy for all requests.
+    proxy: Option<Proxy>,
+    /// Additional trusted root certificates (e.g. an on-premises Enterprise CA).
+    root_certificates: Vec<Certificate>,
+    /// Value of the `User-Agent` header.
+    user_agent: Option<String>,
+    /// Value of the `Referer` header, required by referer-restricted API keys.
+    referer: Option<String>,
+    /// Preconfigured HTTP client, used instead of building one.
+    client: Option<ReqwestClient>,
+}
+
+impl HttpConfig {
+    /// Returns whether any transport setting other than a preconfigured client is set.
+    fn has_settings(&self) -> bool {
+        self.timeout.is_some()
+            || self.connect_timeout.is_some()
+            || self.read_timeout.is_some()
+            || self.proxy.is_some()
+            || !self.root_certificates.is_empty()
+            || self.user_agent.is_some()
+            || self.referer.is_some()
+    }
+
+    /// Returns a `reqwest` client builder with these settings applied.
+    ///
+    /// A preconfigured [`client`](Self::client) is not consulted; callers that
+    /// need extra options (such as a redirect policy) start from this builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Referer` value is not a valid header value.
+    #[instrument(skip(self))]
+    pub fn client_builder(&self) -> Result<ClientBuilder> {
+        let mut builder = ReqwestClient::builder();
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        if !self.root_certificates.is_empty() {
+            builder = builder.tls_certs_merge(self.root_certificates.iter().cloned());
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.as_str());
+        }
+        if let Some(referer) = &self.referer {
+            let value = HeaderValue::from_str(referer).map_err(|e| {
+                crate::Error::from(crate::ErrorKind::Validation(format!(
+                    "Invalid Referer header '{}': {}",
+                    referer, e
+                )))
+            })?;
+            let mut headers = HeaderMap::new();
+            headers.insert(REFERER, value);
+            builder = builder.default_headers(headers);
+        }
+
+        Ok(builder)
+    }
+
+    /// Returns the preconfigured client, or builds one from these settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are invalid or the client cannot be built.
+    #[instrument(skip(self))]
+    pub fn build_client(&self) -> Result<ReqwestClient> {
+        if let Some(client) = &self.client {
+            tracing::debug!("Using preconfigured HTTP client");
+            return Ok(client.clone());
+        }
+        Ok(self.client_builder()?.build()?)
+    }
+}
+
+/// Builder for [`ArcGISClient`].
+///
+/// Configures the HTTP transport (timeouts, proxy, root certificates,
+/// `User-Agent`, `Referer`), authentication, retry policy, and rate limiter.
+/// Transport settings are also applied to the authentication provider's own
+/// HTTP client.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ApiKeyAuth, ArcGISClient};
+/// use std::time::Duration;
+///
+/// # fn example() -> arcgis::Result<()> {
+/// let client = ArcGISClient::builder()
+///     .auth(ApiKeyAuth::new("YOUR_API_KEY"))
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(60))
+///     .user_agent("my-app/1.0")
+///     .referer("https://my-app.example.com")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ArcGISClientBuilder {
+    auth: Box<dyn AuthProvider>,
+    http_config: HttpConfig,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter,
+    middleware: Vec<Arc<dyn Middleware>>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    credit_meter: Option<CreditMeter>,
+    max_url_length: usize,
+    transport: Option<Arc<dyn HttpTransport>>,
+    cache: Option<ResponseCache>,
+    token_placement: TokenPlacement,
+}
+
+impl Default for ArcGISClientBuilder {
+    fn default() -> Self {
+        Self {
+            auth: Box::new(NoAuth),
+            http_config: HttpConfig::default(),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: RateLimiter::default(),
+            middleware: Vec::new(),
+            metrics: None,
+            credit_meter: None,
+            max_url_length: DEFAULT_MAX_URL_LENGTH,
+            transport: None,
+            cache: None,
+            token_placement: TokenPlacement::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ArcGISClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArcGISClientBuilder")
+            .field("http_config", &self.http_config)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("middleware", &self.middleware.len())
+            .field("metrics", &self.metrics.is_some())
+            .field("credit_meter", &self.credit_meter)
+            .field("max_url_length", &self.max_url_length)
+            .field("custom_transport", &self.transport.is_some())
+            .field("cache", &self.cache)
+            .field("token_placement", &self.token_placement)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArcGISClientBuilder {
+    /// Creates a builder with no authentication and default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the transport settings collected so far.
+    ///
+    /// Pass them to an authentication provider builder that makes requests
+    /// before the client is built, such as
+    /// [`ClientCredentialsAuthBuilder::http_config`](crate::ClientCredentialsAuthBuilder::http_config)
+    /// ahead of portal discovery.
+    pub fn http_config(&self) -> &HttpConfig {
+        &self.http_config
+    }
+
+    /// Sets the authentication provider. Defaults to [`NoAuth`].
+    pub fn auth(mut self, auth: impl AuthProvider + 'static) -> Self {
+        self.auth = Box::new(auth);
+        self
+    }
+
+    /// Sets the total timeout for each request, from connecting until the body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read of the response.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Routes all requests through a proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.http_config.proxy = Some(proxy);
+        self
+    }
+
+    /// Trusts an additional root certificate, e.g. the CA of an on-premises
+    /// ArcGIS Enterprise deployment.
+    pub fn root_certificate(mut self, certificate: Certificate) -> Self {
+        self.http_config.root_certificates.push(certificate);
+        self
+    }
+
+    /// Sets the `User-Agent` header.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http_config.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the `Referer` header sent with every request.
+    ///
+    /// Required for API keys restricted to specific referrers.
+    pub fn referer(mut self, referer: impl Into<String>) -> Self {
+        self.http_config.referer = Some(referer.into());
+        self
+    }
+
+    /// Uses a preconfigured `reqwest` client instead of building one.
+    ///
+    /// The client is also handed to the authentication provider. Other
+    /// transport settings on this builder are ignored when a client is given.
+    pub fn http_client(mut self, client: ReqwestClient) -> Self {
+        self.http_config.client = Some(client);
+        self
+    }
+
+    /// Sets the retry policy. Defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets the rate limiter. Defaults to no limits.
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    /// Adds a middleware. Middleware runs in the order it was added.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the recorder that receives metrics for every request. See
+    /// [`ArcGISClient::with_metrics`].
+    pub fn metrics(mut self, recorder: impl MetricsRecorder + 'static) -> Self {
+        self.metrics = Some(Arc::new(recorder));
+        self
+    }
+
+    /// Sets the meter that estimates and limits credit consumption. See
+    /// [`ArcGISClient::with_credit_meter`].
+    pub fn credit_meter(mut self, meter: CreditMeter) -> Self {
+        self.credit_meter = Some(meter);
+        self
+    }
+
+    /// Sets the longest URL sent as GET; longer requests are sent as
+    /// form-encoded POST. Defaults to 2048 characters.
+    pub fn max_url_length(mut self, max_url_length: usize) -> Self {
+        self.max_url_length = max_url_length;
+        self
+    }
+
+    /// Sends requests through a custom transport instead of the `reqwest`
+    /// client, e.g. an in-memory transport in tests. See [`HttpTransport`].
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Enables caching of service metadata responses. See [`ResponseCache`].
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets where authentication tokens are sent. Defaults to
+    /// [`TokenPlacement::Parameter`]; see
+    /// [`ArcGISClient::with_token_placement`].
+    pub fn token_placement(mut self, placement: TokenPlacement) -> Self {
+        self.token_placement = placement;
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transport settings are invalid, the HTTP client
+    /// cannot be created, or the authentication provider rejects the settings.
+    #[instrument(skip(self))]
+    pub fn build(self) -> Result<ArcGISClient> {
+        tracing::debug!("Building ArcGIS client");
+
+        let Self {
+            mut auth,
+            http_config,
+            retry_policy,
+            rate_limiter,
+            middleware,
+            metrics,
+            credit_meter,
+            max_url_length,
+            transport,
+            cache,
+            token_placement,
+        } = self;
+
+        if http_config.client.is_some() && http_config.has_settings() {
+            tracing::warn!("Preconfigured HTTP client given; ignoring other transport settings");
+        }
+
+        let http = http_config.build_client()?;
+        auth.configure_http(&http_config)?;
+
+        tracing::debug!("ArcGIS client built");
+
+        Ok(ArcGISClient {
+            transport: transport.unwrap_or_else(|| Arc::new(http.clone())),
+            http,
+            auth: Arc::from(auth),
+            retry_policy,
+            rate_limiter: Arc::new(rate_limiter),
+            middleware,
+            metrics,
+            credit_meter,
+            max_url_length,
+            cache,
+            token_placement,
+        })
+    }
+}
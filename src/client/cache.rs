@@ -0,0 +1,338 @@
+//! Response cache for service metadata.
+//!
+//! Service, layer, and style definitions rarely change, yet callers often
+//! fetch them before every operation. [`ResponseCache`] serves such responses
+//! locally while they are fresh and revalidates stale ones with conditional
+//! requests (`If-None-Match` / `If-Modified-Since`), so an unchanged definition
+//! costs a `304 Not Modified` instead of a full download.
+
+use crate::Result;
+use crate::auth::{create_private_dir, write_private};
+use async_trait::async_trait;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tracing::instrument;
+
+/// Default time a cached response is served without revalidation.
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+/// A cached response body with its validators.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct CachedResponse {
+    /// Response body.
+    body: String,
+    /// `ETag` header, used for `If-None-Match` revalidation.
+    etag: Option<String>,
+    /// `Last-Modified` header, used for `If-Modified-Since` revalidation.
+    last_modified: Option<String>,
+    /// When the response was stored or last revalidated.
+    stored_at: SystemTime,
+}
+
+impl CachedResponse {
+    /// Creates an entry stored now.
+    pub fn new(body: String, etag: Option<String>, last_modified: Option<String>) -> Self {
+        Self {
+            body,
+            etag,
+            last_modified,
+            stored_at: SystemTime::now(),
+        }
+    }
+
+    /// Returns a copy marked as revalidated now.
+    pub(crate) fn refreshed(mut self) -> Self {
+        self.stored_at = SystemTime::now();
+        self
+    }
+
+    /// Returns whether the entry carries a validator for conditional requests.
+    pub(crate) fn has_validator(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// Storage backend for a [`ResponseCache`].
+///
+/// Implemented by [`MemoryCacheStore`] and [`DiskCacheStore`]; implement it to
+/// share metadata through another store, such as Redis.
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    /// Returns the entry for `key`, if present.
+    async fn get(&self, key: &str) -> Result<Option<CachedResponse>>;
+
+    /// Stores an entry, replacing any existing entry for `key`.
+    async fn put(&self, key: &str, entry: CachedResponse) -> Result<()>;
+
+    /// Removes the entry for `key`, if present.
+    async fn remove(&self, key: &str) -> Result<()>;
+
+    /// Removes all entries.
+    async fn clear(&self) -> Result<()>;
+}
+
+/// In-memory cache store, shared by clones.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCacheStore {
+    entries: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+impl MemoryCacheStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map(|entries| entries.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns whether the store is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<String, CachedResponse>>> {
+        self.entries.lock().map_err(|_| {
+            crate::Error::from(crate::ErrorKind::Other(
+                "Response cache lock poisoned".to_string(),
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl CacheStore for MemoryCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<CachedResponse>> {
+        Ok(self.lock()?.get(key).cloned())
+    }
+
+    async fn put(&self, key: &str, entry: CachedResponse) -> Result<()> {
+        self.lock()?.insert(key.to_string(), entry);
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.lock()?.remove(key);
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.lock()?.clear();
+        Ok(())
+    }
+}
+
+/// On-disk cache store, one JSON file per entry.
+///
+/// Entries survive restarts, so metadata fetched by one run is revalidated
+/// rather than downloaded again by the next. On Unix the directory is created
+/// with mode `0700` and entries with mode `0600`, since responses may hold
+/// data only the signed-in user can see.
+#[derive(Debug, Clone, Getters)]
+pub struct DiskCacheStore {
+    /// Directory holding the entries.
+    dir: PathBuf,
+}
+
+/// Entry as written to disk; the key guards against file name collisions.
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    entry: CachedResponse,
+}
+
+impl DiskCacheStore {
+    /// Creates a store in `dir`. The directory is created on first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+}
+
+#[async_trait]
+impl CacheStore for DiskCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<CachedResponse>> {
+        let path = self.entry_path(key);
+        let contents = match tokio::fs::read(&path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        match serde_json::from_slice::<DiskEntry>(&contents) {
+            Ok(stored) if stored.key == key => Ok(Some(stored.entry)),
+            Ok(_) => Ok(None),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Ignoring unreadable cache entry");
+                Ok(None)
+            }
+        }
+    }
+
+    async fn put(&self, key: &str, entry: CachedResponse) -> Result<()> {
+        create_private_dir(&self.dir).await?;
+        let stored = DiskEntry {
+            key: key.to_string(),
+            entry,
+        };
+        let contents = serde_json::to_vec(&stored)?;
+        write_private(&self.entry_path(key), &contents).await
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.entry_path(key)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    async fn clear(&self) -> Result<()> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                tokio::fs::remove_file(path).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a hash; stable across builds, unlike `DefaultHasher`.
+fn fnv1a(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Cache for service metadata responses.
+///
+/// Attach a cache with [`ArcGISClient::with_cache`](crate::ArcGISClient::with_cache)
+/// or the builder. Metadata methods such as
+/// [`FeatureServiceClient::get_definition`](crate::FeatureServiceClient::get_definition),
+/// [`MapServiceClient::get_metadata`](crate::MapServiceClient::get_metadata), and
+/// [`VectorTileServiceClient::get_style`](crate::VectorTileServiceClient::get_style)
+/// then answer from the cache while an entry is younger than the TTL. Older
+/// entries are revalidated with a conditional request when the server sent an
+/// `ETag` or `Last-Modified` header, and fetched again otherwise.
+///
+/// Entries are keyed by method, URL, parameters, and the identity the request
+/// is made as: the auth provider's [`principal`](crate::AuthProvider::principal),
+/// or the token if it has none. A store can therefore be shared by clients
+/// with different credentials without serving one identity's metadata to
+/// another. Identities are hashed before they reach the store.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, FeatureServiceClient, NoAuth, ResponseCache};
+/// use std::time::Duration;
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let cache = ResponseCache::disk(".arcgis-cache").with_ttl(Duration::from_secs(600));
+/// let client = ArcGISClient::new(NoAuth).with_cache(cache);
+/// let service = FeatureServiceClient::new("https://example.com/FeatureServer", &client);
+///
+/// // Only the first call reaches the server within the TTL
+/// let definition = service.get_definition().await?;
+/// let again = service.get_definition().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponseCache {
+    /// Creates a cache backed by `store`, with a five-minute TTL.
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        Self {
+            store: Arc::new(store),
+            ttl: DEFAULT_TTL,
+        }
+    }
+
+    /// Creates an in-memory cache.
+    pub fn memory() -> Self {
+        Self::new(MemoryCacheStore::new())
+    }
+
+    /// Creates a cache persisted in `dir`.
+    pub fn disk(dir: impl Into<PathBuf>) -> Self {
+        Self::new(DiskCacheStore::new(dir))
+    }
+
+    /// Sets how long entries are served without revalidation.
+    ///
+    /// A zero TTL revalidates on every call, which still saves the download
+    /// when the server supports conditional requests.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Returns how long entries are served without revalidation.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Removes all cached entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be cleared.
+    #[instrument(skip(self))]
+    pub async fn clear(&self) -> Result<()> {
+        tracing::debug!("Clearing response cache");
+        self.store.clear().await
+    }
+
+    /// Returns whether an entry can be served without revalidation.
+    pub(crate) fn is_fresh(&self, entry: &CachedResponse) -> bool {
+        entry.stored_at.elapsed().is_ok_and(|age| age < self.ttl)
+    }
+
+    /// Looks up an entry. Store failures are logged and treated as a miss.
+    pub(crate) async fn lookup(&self, key: &str) -> Option<CachedResponse> {
+        match self.store.get(key).await {
+            Ok(entry) => entry,
+            Err(e) => {
+                tracing::warn!(error = %e, "Response cache read failed");
+                None
+            }
+        }
+    }
+
+    /// Stores an entry. Store failures are logged and otherwise ignored.
+    pub(crate) async fn store(&self, key: &str, entry: CachedResponse) {
+        if let Err(e) = self.store.put(key, entry).await {
+            tracing::warn!(error = %e, "Response cache write failed");
+        }
+    }
+}
@@ -0,0 +1,422 @@
+//! Record/replay transport for offline, deterministic tests.
+
+use super::HttpTransport;
+use crate::Result;
+use crate::util::{REDACTED, SENSITIVE_PARAMS};
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::instrument;
+
+/// Environment variable selecting the [`CassetteMode`].
+pub const CASSETTE_MODE_ENV: &str = "ARCGIS_CASSETTE_MODE";
+
+/// Response body members whose values are credentials.
+const SENSITIVE_FIELDS: &[&str] = &["token", "access_token", "refresh_token"];
+
+/// Whether a [`CassetteTransport`] records live traffic or replays it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CassetteMode {
+    /// Send requests to the live service and write each interaction to the cassette.
+    Record,
+    /// Answer requests from the cassette without touching the network.
+    #[default]
+    Replay,
+}
+
+impl CassetteMode {
+    /// Reads the mode from `ARCGIS_CASSETTE_MODE` (`record` or `replay`).
+    ///
+    /// Defaults to [`Replay`](Self::Replay) when the variable is unset or
+    /// unrecognized, so test runs stay offline unless recording is requested.
+    pub fn from_env() -> Self {
+        match std::env::var(CASSETTE_MODE_ENV).as_deref() {
+            Ok("record") => Self::Record,
+            _ => Self::Replay,
+        }
+    }
+}
+
+/// A recorded request.
+///
+/// Parameters from the query string and form body are merged and sorted, and
+/// credentials are removed, so a cassette matches regardless of parameter
+/// order, host, or the token in use.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    params: Vec<(String, String)>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    /// UTF-8 body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Binary body (PBF, images), hex-encoded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_hex: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// HTTP transport that records interactions to a cassette file and replays them.
+///
+/// In [`CassetteMode::Record`] requests go to the live service through an
+/// inner transport, and every request/response pair is written to a JSON
+/// cassette with tokens, API keys, and secrets scrubbed. In
+/// [`CassetteMode::Replay`] the same requests are answered from the cassette,
+/// offline and deterministically.
+///
+/// Requests match on method, URL path, and the sorted query and form
+/// parameters (credentials excluded), so cassettes work with every service
+/// client. Identical requests replay their recorded responses in order; once
+/// exhausted, the last one is repeated (e.g. for job status polling).
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, CassetteMode, CassetteTransport, FeatureServiceClient, LayerId};
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// // ARCGIS_CASSETTE_MODE=record to refresh against the live service
+/// let transport = CassetteTransport::new("tests/cassettes/cities.json", CassetteMode::from_env())?;
+/// let client = ArcGISClient::builder().transport(transport).build()?;
+///
+/// let service = FeatureServiceClient::new(
+///     "https://services.arcgis.com/P3ePLMYs2RVChkJx/arcgis/rest/services/World_Cities/FeatureServer",
+///     &client,
+/// );
+/// let cities = service.query(LayerId::new(0)).where_clause("POP > 5000000").execute().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: CassetteMode,
+    inner: Arc<dyn HttpTransport>,
+    /// Interactions, each paired with whether it has been replayed.
+    interactions: Arc<Mutex<Vec<(Interaction, bool)>>>,
+    /// Serializes cassette writes while recording.
+    write_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl std::fmt::Debug for CassetteTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CassetteTransport")
+            .field("path", &self.path)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CassetteTransport {
+    /// Creates a transport for the cassette at `path` in the given mode.
+    ///
+    /// Recording starts a fresh cassette and sends requests with a default
+    /// `reqwest` client; replaying loads the existing cassette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if replaying and the cassette cannot be read or parsed.
+    #[instrument(skip(path), fields(path = %path.as_ref().display()))]
+    pub fn new(path: impl AsRef<Path>, mode: CassetteMode) -> Result<Self> {
+        match mode {
+            CassetteMode::Record => Ok(Self::record(path, reqwest::Client::new())),
+            CassetteMode::Replay => Self::replay(path),
+        }
+    }
+
+    /// Creates a transport that records to `path`, sending requests through `inner`.
+    ///
+    /// Any existing cassette at `path` is replaced when the first interaction
+    /// is written.
+    pub fn record(path: impl AsRef<Path>, inner: impl HttpTransport + 'static) -> Self {
+        tracing::debug!(path = %path.as_ref().display(), "Recording cassette");
+        Self {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Record,
+            inner: Arc::new(inner),
+            interactions: Arc::new(Mutex::new(Vec::new())),
+            write_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Creates a transport that replays the cassette at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cassette cannot be read or parsed.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let contents = std::fs::read_to_string(&path)?;
+        let cassette: CassetteFile = serde_json::from_str(&contents)?;
+
+        tracing::debug!(
+            path = %path.display(),
+            interactions = cassette.interactions.len(),
+            "Replaying cassette"
+        );
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            inner: Arc::new(reqwest::Client::new()),
+            interactions: Arc::new(Mutex::new(
+                cassette
+                    .interactions
+                    .into_iter()
+                    .map(|interaction| (interaction, false))
+                    .collect(),
+            )),
+            write_lock: Arc::new(tokio::sync::Mutex::new(())),
+        })
+    }
+
+    /// Returns the cassette path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the mode.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    fn interactions(&self) -> std::sync::MutexGuard<'_, Vec<(Interaction, bool)>> {
+        self.interactions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    async fn record_interaction(
+        &self,
+        recorded: RecordedRequest,
+        request: Request,
+    ) -> Result<Response> {
+        let response = self.inner.execute(request).await?;
+
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let content_type = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let (text, hex) = match std::str::from_utf8(&body) {
+            Ok(text) => (Some(scrub_body(text)), None),
+            Err(_) => (None, Some(encode_hex(&body))),
+        };
+
+        let interaction = Interaction {
+            request: recorded,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                content_type,
+                body: text,
+                body_hex: hex,
+            },
+        };
+
+        {
+            let _guard = self.write_lock.lock().await;
+            let contents = {
+                let mut interactions = self.interactions();
+                interactions.push((interaction, true));
+                let cassette = CassetteFile {
+                    interactions: interactions.iter().map(|(i, _)| i.clone()).collect(),
+                };
+                serde_json::to_string_pretty(&cassette)?
+            };
+            if let Some(parent) = self.path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&self.path, contents).await?;
+        }
+
+        let mut rebuilt = http::Response::new(body);
+        *rebuilt.status_mut() = status;
+        *rebuilt.version_mut() = version;
+        *rebuilt.headers_mut() = headers;
+        Ok(Response::from(rebuilt))
+    }
+
+    fn replay_interaction(&self, recorded: &RecordedRequest) -> Result<Response> {
+        let response = {
+            let mut interactions = self.interactions();
+            let matching: Vec<usize> = interactions
+                .iter()
+                .enumerate()
+                .filter(|(_, (interaction, _))| interaction.request == *recorded)
+                .map(|(index, _)| index)
+                .collect();
+            let chosen = matching
+                .iter()
+                .find(|&&index| !interactions[index].1)
+                .or(matching.last())
+                .copied();
+
+            match chosen {
+                Some(index) => {
+                    interactions[index].1 = true;
+                    interactions[index].0.response.clone()
+                }
+                None => {
+                    tracing::error!(
+                        method = %recorded.method,
+                        path = %recorded.path,
+                        params = ?recorded.params,
+                        "No cassette interaction matches request"
+                    );
+                    return Err(crate::Error::from(crate::ErrorKind::Other(format!(
+                        "No interaction in cassette {} matches {} {}",
+                        self.path.display(),
+                        recorded.method,
+                        recorded.path
+                    ))));
+                }
+            }
+        };
+
+        let body = match (response.body, response.body_hex) {
+            (_, Some(hex)) => decode_hex(&hex)?,
+            (Some(text), None) => text.into_bytes(),
+            (None, None) => Vec::new(),
+        };
+
+        let mut builder = http::Response::builder().status(response.status);
+        if let Some(content_type) = &response.content_type {
+            builder = builder.header(CONTENT_TYPE, content_type);
+        }
+        let rebuilt = builder.body(body).map_err(|e| {
+            crate::Error::from(crate::ErrorKind::Other(format!(
+                "Invalid recorded response in {}: {}",
+                self.path.display(),
+                e
+            )))
+        })?;
+
+        Ok(Response::from(rebuilt))
+    }
+}
+
+#[async_trait]
+impl HttpTransport for CassetteTransport {
+    #[instrument(skip(self, request), fields(method = %request.method(), path = %request.url().path()))]
+    async fn execute(&self, request: Request) -> Result<Response> {
+        let recorded = normalize(&request);
+        match self.mode {
+            CassetteMode::Record => self.record_interaction(recorded, request).await,
+            CassetteMode::Replay => self.replay_interaction(&recorded),
+        }
+    }
+}
+
+/// Reduces a request to its matching key, dropping credentials.
+fn normalize(request: &Request) -> RecordedRequest {
+    let mut params: Vec<(String, String)> = request
+        .url()
+        .query_pairs()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+
+    let is_form = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+    if is_form {
+        if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+            params.extend(url::form_urlencoded::parse(body).into_owned());
+        }
+    }
+
+    params.retain(|(key, _)| !SENSITIVE_PARAMS.contains(&key.as_str()));
+    params.sort();
+
+    RecordedRequest {
+        method: request.method().to_string(),
+        path: request.url().path().to_string(),
+        params,
+    }
+}
+
+/// Redacts credential members from a JSON response body.
+fn scrub_body(text: &str) -> String {
+    let Ok(mut value) = serde_json::from_str::<serde_json::Value>(text) else {
+        return text.to_string();
+    };
+    if !scrub_value(&mut value) {
+        return text.to_string();
+    }
+    serde_json::to_string(&value).unwrap_or_else(|_| text.to_string())
+}
+
+/// Redacts credential members in place, returning whether anything changed.
+fn scrub_value(value: &mut serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut changed = false;
+            for (key, member) in map.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&key.as_str()) && member.is_string() {
+                    *member = serde_json::Value::String(REDACTED.to_string());
+                    changed = true;
+                } else {
+                    changed |= scrub_value(member);
+                }
+            }
+            changed
+        }
+        serde_json::Value::Array(items) => {
+            let mut changed = false;
+            for item in items.iter_mut() {
+                changed |= scrub_value(item);
+            }
+            changed
+        }
+        _ => false,
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return Err(crate::Error::from(crate::ErrorKind::Other(
+            "Recorded body_hex has odd length".to_string(),
+        )));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| {
+                crate::Error::from(crate::ErrorKind::Other(format!(
+                    "Invalid recorded body_hex: {}",
+                    e
+                )))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,290 @@
+//! Credit consumption estimates and budgets.
+//!
+//! Geocoding, routing, elevation, and places requests consume ArcGIS credits.
+//! Service clients mark such requests [`billable`](crate::ServiceRequest::billable)
+//! with the number of records or transactions they bill for, and the client's
+//! [`CreditMeter`] prices them, keeps running totals, and refuses requests
+//! once a budget is spent.
+
+use crate::{ErrorKind, Result, ServiceKind};
+use derive_getters::Getters;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tracing::instrument;
+
+/// Default credits per billable unit for location services.
+///
+/// Matches `credits_per_request` of the `location` tier in
+/// `config/test-tiers.toml`.
+const DEFAULT_LOCATION_RATE: f64 = 0.004;
+
+/// Tolerance for floating point rounding when comparing against the budget.
+const EPSILON: f64 = 1e-9;
+
+/// Running credit totals for one service kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Getters)]
+pub struct CreditUsage {
+    /// Number of billable requests charged.
+    requests: u64,
+    /// Number of billable units (records, solves) charged.
+    units: u64,
+    /// Estimated credits consumed.
+    credits: f64,
+}
+
+/// A charge made for a request, refunded if the request fails.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreditCharge {
+    kind: ServiceKind,
+    units: u32,
+    credits: f64,
+}
+
+#[derive(Debug)]
+struct MeterState {
+    /// Credits per unit by service kind.
+    rates: BTreeMap<ServiceKind, f64>,
+    /// Credits per unit for specific operations, overriding `rates`.
+    operation_rates: HashMap<(ServiceKind, String), f64>,
+    /// Hard limit on estimated credits, if any.
+    budget: Option<f64>,
+    /// Totals by service kind.
+    usage: BTreeMap<ServiceKind, CreditUsage>,
+}
+
+impl MeterState {
+    fn rate(&self, kind: ServiceKind, operation: &str) -> f64 {
+        self.operation_rates
+            .get(&(kind, operation.to_string()))
+            .or_else(|| self.rates.get(&kind))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    fn spent(&self) -> f64 {
+        self.usage.values().map(|usage| usage.credits).sum()
+    }
+}
+
+/// Estimates credit consumption and enforces a credit budget.
+///
+/// Each [`billable`](crate::ServiceRequest::billable) request is charged
+/// `rate × units` credits before it is sent, where the rate is looked up by
+/// service kind and operation. Built-in service clients bill:
+///
+/// - Geocoding per address (`geocodeAddresses` per record); `suggest` is free
+/// - Routing per solve (route, service area, closest facility, OD cost matrix)
+/// - Elevation per profile, summarize elevation job, or viewshed job
+/// - Places per search or details request
+///
+/// By default every unit of these services costs 0.004 credits; adjust rates
+/// with [`with_rate`](Self::with_rate) and
+/// [`with_operation_rate`](Self::with_operation_rate) to match your pricing.
+/// Charges are refunded for failed requests the server certainly did not
+/// process: those that fail before being sent, cannot connect, or are
+/// rejected with an HTTP error status or ESRI error. Requests that time out
+/// or lose their connection may have been processed and stay charged.
+///
+/// With a budget set, a request whose estimate would exceed the remaining
+/// budget fails with [`ErrorKind::CreditBudgetExceeded`] without being sent.
+/// The meter is shared by clones, so a clone kept by the caller reports the
+/// totals of the client it was given to.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ApiKeyAuth, ArcGISClient, CreditMeter, ServiceKind};
+///
+/// let meter = CreditMeter::new()
+///     .with_budget(25.0)
+///     .with_rate(ServiceKind::Routing, 0.005);
+/// let client = ArcGISClient::new(ApiKeyAuth::new("YOUR_API_KEY")).with_credit_meter(meter.clone());
+///
+/// // ... run geocoding and routing requests ...
+///
+/// println!("Spent {:.3} credits, {:?} remaining", meter.spent(), meter.remaining());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreditMeter {
+    state: Arc<Mutex<MeterState>>,
+}
+
+impl Default for CreditMeter {
+    fn default() -> Self {
+        let rates = [
+            ServiceKind::Geocode,
+            ServiceKind::Routing,
+            ServiceKind::Elevation,
+            ServiceKind::Places,
+        ]
+        .into_iter()
+        .map(|kind| (kind, DEFAULT_LOCATION_RATE))
+        .collect();
+
+        Self {
+            state: Arc::new(Mutex::new(MeterState {
+                rates,
+                operation_rates: HashMap::new(),
+                budget: None,
+                usage: BTreeMap::new(),
+            })),
+        }
+    }
+}
+
+impl CreditMeter {
+    /// Creates a meter with the default rates and no budget.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MeterState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets a hard budget in credits.
+    pub fn with_budget(self, credits: f64) -> Self {
+        self.set_budget(Some(credits));
+        self
+    }
+
+    /// Sets the credits charged per billable unit for a service kind.
+    pub fn with_rate(self, kind: ServiceKind, credits: f64) -> Self {
+        self.state().rates.insert(kind, credits);
+        self
+    }
+
+    /// Sets the credits charged per billable unit for one operation of a
+    /// service kind, e.g. `solveServiceArea` on routing services.
+    ///
+    /// Operations are named as in [`RequestContext::operation`](crate::RequestContext::operation).
+    pub fn with_operation_rate(
+        self,
+        kind: ServiceKind,
+        operation: impl Into<String>,
+        credits: f64,
+    ) -> Self {
+        self.state()
+            .operation_rates
+            .insert((kind, operation.into()), credits);
+        self
+    }
+
+    /// Replaces the budget; `None` removes it.
+    pub fn set_budget(&self, credits: Option<f64>) {
+        self.state().budget = credits;
+    }
+
+    /// Returns the budget, if one is set.
+    pub fn budget(&self) -> Option<f64> {
+        self.state().budget
+    }
+
+    /// Returns the estimated credits consumed so far.
+    pub fn spent(&self) -> f64 {
+        self.state().spent()
+    }
+
+    /// Returns the credits left in the budget, if one is set.
+    pub fn remaining(&self) -> Option<f64> {
+        let state = self.state();
+        state.budget.map(|budget| (budget - state.spent()).max(0.0))
+    }
+
+    /// Returns the running totals for a service kind.
+    pub fn usage(&self, kind: ServiceKind) -> CreditUsage {
+        self.state().usage.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Returns the running totals of every service kind charged so far.
+    pub fn totals(&self) -> BTreeMap<ServiceKind, CreditUsage> {
+        self.state().usage.clone()
+    }
+
+    /// Estimates the credits `units` billable units of an operation cost.
+    pub fn estimate(&self, kind: ServiceKind, operation: &str, units: u32) -> f64 {
+        self.state().rate(kind, operation) * f64::from(units)
+    }
+
+    /// Clears the running totals. The budget and rates are kept.
+    #[instrument(skip(self))]
+    pub fn reset(&self) {
+        tracing::debug!("Resetting credit meter");
+        self.state().usage.clear();
+    }
+
+    /// Charges a billable request, failing if it would exceed the budget.
+    pub(crate) fn charge(
+        &self,
+        kind: ServiceKind,
+        operation: &str,
+        units: u32,
+    ) -> Result<CreditCharge> {
+        let mut state = self.state();
+        let credits = state.rate(kind, operation) * f64::from(units);
+        let spent = state.spent();
+
+        if let Some(budget) = state.budget {
+            if credits > 0.0 && spent + credits > budget + EPSILON {
+                tracing::warn!(
+                    service_kind = %kind,
+                    operation = %operation,
+                    budget,
+                    spent,
+                    estimate = credits,
+                    "Credit budget exhausted, refusing request"
+                );
+                return Err(crate::Error::from(ErrorKind::CreditBudgetExceeded {
+                    budget,
+                    spent,
+                    estimate: credits,
+                }));
+            }
+        }
+
+        let usage = state.usage.entry(kind).or_default();
+        usage.requests += 1;
+        usage.units += u64::from(units);
+        usage.credits += credits;
+        tracing::debug!(
+            service_kind = %kind,
+            operation = %operation,
+            units,
+            credits,
+            "Charged credits"
+        );
+
+        Ok(CreditCharge {
+            kind,
+            units,
+            credits,
+        })
+    }
+
+    /// Reverses a charge for a request the server did not process.
+    pub(crate) fn refund(&self, charge: CreditCharge) {
+        let mut state = self.state();
+        if let Some(usage) = state.usage.get_mut(&charge.kind) {
+            usage.requests = usage.requests.saturating_sub(1);
+            usage.units = usage.units.saturating_sub(u64::from(charge.units));
+            usage.credits = (usage.credits - charge.credits).max(0.0);
+        }
+        tracing::debug!(
+            service_kind = %charge.kind,
+            credits = charge.credits,
+            "Refunded credits for failed request"
+        );
+    }
+}
+
+/// Returns whether a failed request may still have been processed by the
+/// server, e.g. because it timed out or the connection dropped mid-response.
+///
+/// Requests that could not be built or could not connect never reached the
+/// server; other errors either happened before sending or are rejections.
+pub(crate) fn is_unconfirmed(error: &crate::Error) -> bool {
+    match error.kind() {
+        crate::ErrorKind::Http(e) => !(e.source().is_connect() || e.source().is_builder()),
+        _ => false,
+    }
+}
@@ -0,0 +1,438 @@
+//! Unified request executor.
+//!
+//! Every service client routes its requests through these methods so that
+//! authentication, middleware, retries, HTTP status checks, and ESRI error
+//! detection behave identically across services.
+
+use super::{
+    ArcGISClient, CachedResponse, RequestContext, ResponseCache, ServiceRequest, buffer_body,
+    has_text_body,
+};
+use crate::{ErrorKind, Result, check_esri_error};
+use reqwest::header::{
+    CONTENT_TYPE, ETAG, HeaderName, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::{Method, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use tracing::{Instrument, instrument};
+
+impl ArcGISClient {
+    /// Executes a service request and returns the successful response.
+    ///
+    /// Attaches the authentication token, runs middleware, applies rate limits
+    /// and retries, and fails on non-success HTTP statuses and on ESRI error
+    /// payloads in JSON or text responses. If the server rejects the token
+    /// (ESRI code 498 or 499) and the [`AuthProvider`](crate::AuthProvider)
+    /// can refresh it, the request is replayed once with a new token. Binary
+    /// bodies are returned unread, so they can be streamed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token cannot be obtained, the transport fails,
+    /// the server responds with a non-success status, or the body carries an
+    /// ESRI error.
+    #[instrument(skip(self, request), fields(operation = %request.operation_name()))]
+    pub async fn execute(&self, request: ServiceRequest) -> Result<Response> {
+        let (context, result) = self.execute_checked(request).await;
+        self.finish(&context, &result).await;
+        result
+    }
+
+    /// Executes a service request and deserializes the JSON response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`execute`](Self::execute),
+    /// if the server returns an HTML error page, or if the body cannot be
+    /// deserialized into `T`.
+    ///
+    /// Requests marked [`cacheable`](ServiceRequest::cacheable) are answered
+    /// from the client's [`ResponseCache`], if one is configured.
+    #[instrument(skip(self, request), fields(operation = %request.operation_name()))]
+    pub async fn execute_json<T: DeserializeOwned>(&self, request: ServiceRequest) -> Result<T> {
+        if request.is_cacheable() {
+            if let Some(cache) = self.cache() {
+                match self.cache_identity(&request).await {
+                    Ok(identity) => {
+                        return self
+                            .execute_cached(cache, identity.as_deref(), request)
+                            .await;
+                    }
+                    Err(e) => {
+                        tracing::debug!(error = %e, "Caller unknown, bypassing response cache");
+                    }
+                }
+            }
+        }
+
+        let (mut context, result) = self.execute_checked(request).await;
+        let result = match result {
+            Ok(response) => decode_json(response, &mut context).await,
+            Err(e) => Err(e),
+        };
+        self.finish(&context, &result).await;
+        result
+    }
+
+    /// Executes a service request and returns the response body as text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`execute`](Self::execute),
+    /// or if the body is not valid UTF-8.
+    #[instrument(skip(self, request), fields(operation = %request.operation_name()))]
+    pub async fn execute_text(&self, request: ServiceRequest) -> Result<String> {
+        let (mut context, result) = self.execute_checked(request).await;
+        let result = match result {
+            Ok(response) => match response.text().await {
+                Ok(text) => {
+                    context.record_body(text.as_bytes());
+                    Ok(text)
+                }
+                Err(e) => Err(e.into()),
+            },
+            Err(e) => Err(e),
+        };
+        self.finish(&context, &result).await;
+        result
+    }
+
+    /// Executes a service request and returns the raw response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`execute`](Self::execute).
+    #[instrument(skip(self, request), fields(operation = %request.operation_name()))]
+    pub async fn execute_bytes(&self, request: ServiceRequest) -> Result<bytes::Bytes> {
+        let (mut context, result) = self.execute_checked(request).await;
+        let result = match result {
+            Ok(response) => match response.bytes().await {
+                Ok(body) => {
+                    context.record_body(&body);
+                    Ok(body)
+                }
+                Err(e) => Err(e.into()),
+            },
+            Err(e) => Err(e),
+        };
+        self.finish(&context, &result).await;
+        result
+    }
+
+    /// Returns the identity a request is made as, which keys its cache entry.
+    ///
+    /// Uses the auth provider's [`principal`](crate::AuthProvider::principal),
+    /// or the token when the provider has none, so that one identity's
+    /// secured metadata is never served to another. Anonymous requests have
+    /// no identity.
+    async fn cache_identity(&self, request: &ServiceRequest) -> Result<Option<String>> {
+        if !request.authenticate() {
+            return Ok(None);
+        }
+        let Some(token) = self.get_token_for_url(request.url()).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.auth.principal(request.url()).unwrap_or(token)))
+    }
+
+    /// Serves a JSON request from the cache, revalidating stale entries.
+    async fn execute_cached<T: DeserializeOwned>(
+        &self,
+        cache: &ResponseCache,
+        identity: Option<&str>,
+        mut request: ServiceRequest,
+    ) -> Result<T> {
+        let key = request.cache_key(identity);
+        let cached = cache.lookup(&key).await;
+
+        if let Some(entry) = &cached {
+            if cache.is_fresh(entry) {
+                tracing::debug!(key = %key, "Response cache hit");
+                return parse_json(entry.body(), JSON, request.operation_name());
+            }
+            tracing::debug!(key = %key, "Revalidating stale cache entry");
+            if let Some(etag) = entry.etag() {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = entry.last_modified() {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let (mut context, result) = self.execute_checked(request).await;
+        let result = match (result, cached) {
+            (Ok(response), Some(entry)) if response.status() == StatusCode::NOT_MODIFIED => {
+                tracing::debug!(key = %key, "Cache entry not modified");
+                let value = parse_json(entry.body(), JSON, context.operation());
+                cache.store(&key, entry.refreshed()).await;
+                value
+            }
+            (Ok(response), _) => {
+                let etag = header_value(&response, ETAG);
+                let last_modified = header_value(&response, LAST_MODIFIED);
+                let content_type = header_value(&response, CONTENT_TYPE).unwrap_or_default();
+                match response.text().await {
+                    Ok(text) => {
+                        context.record_body(text.as_bytes());
+                        let value = parse_json(&text, &content_type, context.operation());
+                        if value.is_ok() {
+                            let entry = CachedResponse::new(text, etag, last_modified);
+                            if entry.has_validator() || !cache.ttl().is_zero() {
+                                cache.store(&key, entry).await;
+                            }
+                        }
+                        value
+                    }
+                    Err(e) => Err(e.into()),
+                }
+            }
+            (Err(e), _) => Err(e),
+        };
+
+        self.finish(&context, &result).await;
+        result
+    }
+
+    /// Charges billable requests to the credit meter, sends the request, and
+    /// checks the status and ESRI error payload, returning the context for
+    /// error reporting.
+    async fn execute_checked(&self, request: ServiceRequest) -> (RequestContext, Result<Response>) {
+        let mut context = RequestContext::new(
+            request.operation_name(),
+            request.method().clone(),
+            request.url(),
+        );
+
+        let charge = match (self.credit_meter(), request.billable_units()) {
+            (Some(meter), Some(units)) => {
+                match meter.charge(*context.service_kind(), context.operation(), units) {
+                    Ok(charge) => Some((meter, charge)),
+                    Err(e) => return (context, Err(e)),
+                }
+            }
+            _ => None,
+        };
+
+        let span = context.span().clone();
+        let result = self
+            .send_checked(&mut context, request)
+            .instrument(span)
+            .await;
+
+        // Only refund requests the server certainly did not process
+        if let (Some((meter, charge)), Err(e)) = (charge, &result) {
+            if context.is_unconfirmed() || super::credits::is_unconfirmed(e) {
+                tracing::debug!(error = %e, "Request may have been processed, keeping charge");
+            } else {
+                meter.refund(charge);
+            }
+        }
+        (context, result)
+    }
+
+    async fn send_checked(
+        &self,
+        context: &mut RequestContext,
+        mut request: ServiceRequest,
+    ) -> Result<Response> {
+        if let Some(e) = request.take_error() {
+            return Err(e);
+        }
+
+        let token = if request.authenticate() {
+            self.get_token_for_url(request.url()).await?
+        } else {
+            None
+        };
+        let Some(token) = token else {
+            return self.send_attempt(context, request, None).await;
+        };
+
+        // Keep a copy in case the server rejects the token
+        let replay = request.try_clone();
+        let result = self
+            .send_attempt(context, request, Some(token.clone()))
+            .await;
+
+        match (result, replay) {
+            (Err(e), Some(replay)) if matches!(e.kind(), ErrorKind::InvalidToken { .. }) => {
+                if !self
+                    .auth
+                    .invalidate_token_for_url(replay.url(), &token)
+                    .await
+                {
+                    return Err(e);
+                }
+                tracing::warn!(
+                    operation = %context.operation(),
+                    error = %e,
+                    "Token rejected, refreshing and replaying request"
+                );
+                let token = self.get_token_for_url(replay.url()).await?;
+                self.send_attempt(context, replay, token).await
+            }
+            (result, _) => result,
+        }
+    }
+
+    /// Sends a request once with the given token and checks the response.
+    async fn send_attempt(
+        &self,
+        context: &mut RequestContext,
+        mut request: ServiceRequest,
+        token: Option<String>,
+    ) -> Result<Response> {
+        if let Some(token) = token {
+            request.attach_token(token, self.token_placement);
+        }
+
+        if request.method() == Method::GET && request.url_len() > self.max_url_length {
+            let url_len = request.url_len();
+            if request.convert_to_post() {
+                tracing::debug!(
+                    url_len,
+                    max_url_length = self.max_url_length,
+                    "URL exceeds length limit, sending as POST"
+                );
+                context.set_method(Method::POST);
+            }
+        }
+
+        tracing::debug!(
+            method = %context.method(),
+            url = %context.url(),
+            "Executing service request"
+        );
+
+        let idempotent = request.is_idempotent();
+        let request = request.into_builder(self.http()).build()?;
+        let response = self.dispatch(context, request, idempotent).await?;
+
+        check_status(response, context).await
+    }
+}
+
+/// Content type assumed for cached bodies, which are only stored after parsing as JSON.
+const JSON: &str = "application/json";
+
+/// Fails on non-success HTTP statuses and ESRI error payloads.
+///
+/// `304 Not Modified` is passed through; it only answers the conditional
+/// requests sent to revalidate cache entries. Bodies read here are recorded
+/// on the context.
+async fn check_status(response: Response, context: &mut RequestContext) -> Result<Response> {
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED {
+        return Ok(response);
+    }
+    if !status.is_success() {
+        let error_text = match response.text().await {
+            Ok(text) => {
+                context.record_body(text.as_bytes());
+                text
+            }
+            Err(e) => format!("Failed to read error response: {}", e),
+        };
+        tracing::error!(status = %status, error = %error_text, operation = %context.operation(), "Request failed");
+        return Err(crate::Error::from(crate::ErrorKind::from_esri(
+            status.as_u16() as i32,
+            format!("HTTP {}: {}", status, error_text),
+            Vec::new(),
+        )));
+    }
+
+    if !has_text_body(&response) {
+        return Ok(response);
+    }
+
+    let (response, body) = buffer_body(response).await?;
+    context.record_body(&body);
+    check_esri_error(&String::from_utf8_lossy(&body), context.operation())?;
+
+    Ok(response)
+}
+
+/// Deserializes a JSON body, reporting HTML error pages clearly.
+///
+/// ArcGIS servers sometimes return HTTP 200 with HTML error pages instead of
+/// JSON; the message is extracted from the page rather than surfacing an
+/// opaque parse failure.
+async fn decode_json<T: DeserializeOwned>(
+    response: Response,
+    context: &mut RequestContext,
+) -> Result<T> {
+    let content_type = header_value(&response, CONTENT_TYPE).unwrap_or_default();
+    let text = response.text().await?;
+    context.record_body(text.as_bytes());
+    parse_json(&text, &content_type, context.operation())
+}
+
+/// Deserializes a JSON body that has already been read.
+fn parse_json<T: DeserializeOwned>(text: &str, content_type: &str, operation: &str) -> Result<T> {
+    if content_type.contains("text/html") {
+        let preview = text.chars().take(200).collect::<String>();
+        tracing::error!(
+            content_type = %content_type,
+            operation = %operation,
+            body_preview = %preview,
+            "Server returned HTML error page instead of JSON"
+        );
+
+        let error_msg = extract_html_error(text)
+            .unwrap_or_else(|| "Server returned HTML error page".to_string());
+
+        return Err(crate::Error::from(crate::ErrorKind::Api {
+            code: 400,
+            message: format!(
+                "{} operation not supported: {}. HTML response: {}",
+                operation, error_msg, preview
+            ),
+        }));
+    }
+
+    serde_json::from_str(text).map_err(|e| {
+        tracing::error!(
+            error = %e,
+            operation = %operation,
+            content_type = %content_type,
+            body_preview = %text.chars().take(200).collect::<String>(),
+            "Failed to parse response as JSON"
+        );
+        crate::Error::from(e)
+    })
+}
+
+/// Returns a response header as a string, if present and valid.
+fn header_value(response: &Response, name: HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Extracts a meaningful error message from an HTML error page.
+///
+/// Looks for common patterns in ArcGIS Server error pages.
+fn extract_html_error(html: &str) -> Option<String> {
+    let patterns = [
+        ("<p><b>Message:</b> <u>", "</u></p>"),
+        ("<p><b>Description:</b> <u>", "</u></p>"),
+        ("<h2>", "</h2>"),
+        ("<h1>", "</h1>"),
+    ];
+
+    for (start, end) in patterns {
+        if let Some(start_idx) = html.find(start) {
+            let content_start = start_idx + start.len();
+            if let Some(end_idx) = html[content_start..].find(end) {
+                let msg = &html[content_start..content_start + end_idx];
+                if !msg.trim().is_empty() {
+                    return Some(msg.trim().to_string());
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,166 @@
+//! Per-request metrics.
+//!
+//! Every logical request made through an [`ArcGISClient`](crate::ArcGISClient)
+//! produces one [`RequestMetrics`] record once its outcome is known. Records
+//! are handed to the client's [`MetricsRecorder`], which forwards them to a
+//! metrics backend (Prometheus, StatsD, OpenTelemetry, the `metrics` facade),
+//! so the health of ArcGIS dependencies can be charted without parsing logs.
+
+use super::RequestContext;
+use crate::{ErrorKind, ServiceKind};
+use derive_getters::Getters;
+use reqwest::Method;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Outcome of one logical request.
+///
+/// Retries and token replays belong to the same logical request; their count
+/// is reported as [`retries`](Self::retries). Responses served from the
+/// [`ResponseCache`](crate::ResponseCache) without contacting the server
+/// produce no record.
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct RequestMetrics {
+    /// REST operation name (e.g. `query`, `applyEdits`).
+    operation: String,
+    /// Kind of service targeted.
+    service_kind: ServiceKind,
+    /// HTTP method of the final attempt.
+    method: Method,
+    /// Request URL without query parameters.
+    url: String,
+    /// HTTP status of the final response, if one was received.
+    status: Option<u16>,
+    /// ESRI or HTTP error code, if the server reported an error.
+    esri_code: Option<i32>,
+    /// Low-cardinality error class (e.g. `invalid_token`, `timeout`), if the request failed.
+    error_type: Option<&'static str>,
+    /// Time from entering the executor until the outcome was known.
+    latency: Duration,
+    /// Size of the request body in bytes; `0` for bodiless and streamed requests.
+    request_bytes: u64,
+    /// Size of the response body in bytes, if known.
+    response_bytes: Option<u64>,
+    /// Number of times the request was sent again after the first attempt.
+    retries: u32,
+}
+
+impl RequestMetrics {
+    /// Builds the record for a finished request.
+    pub(crate) fn new(context: &RequestContext, error: Option<&crate::Error>) -> Self {
+        Self {
+            operation: context.operation().clone(),
+            service_kind: *context.service_kind(),
+            method: context.method().clone(),
+            url: context.url().clone(),
+            status: context.status().map(|status| status.as_u16()),
+            esri_code: error.and_then(|e| e.kind().code()),
+            error_type: error.map(|e| error_type(e.kind())),
+            latency: context.elapsed(),
+            request_bytes: *context.request_bytes(),
+            response_bytes: *context.response_bytes(),
+            retries: context.resend_count(),
+        }
+    }
+
+    /// Returns whether the request succeeded.
+    pub fn is_success(&self) -> bool {
+        self.error_type.is_none()
+    }
+}
+
+/// Receives a [`RequestMetrics`] record for every request.
+///
+/// Implemented for closures, so a recorder can forward to any metrics
+/// library. [`MemoryMetricsRecorder`] keeps records in memory.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, NoAuth, RequestMetrics};
+///
+/// let client = ArcGISClient::new(NoAuth).with_metrics(|metrics: &RequestMetrics| {
+///     // Forward to the metrics backend of your choice
+///     println!(
+///         "{} {} status={:?} latency={:?} retries={}",
+///         metrics.service_kind(),
+///         metrics.operation(),
+///         metrics.status(),
+///         metrics.latency(),
+///         metrics.retries(),
+///     );
+/// });
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Called once per request, after its outcome is known.
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<F> MetricsRecorder for F
+where
+    F: Fn(&RequestMetrics) + Send + Sync,
+{
+    fn record(&self, metrics: &RequestMetrics) {
+        self(metrics)
+    }
+}
+
+/// Recorder that keeps every record in memory, shared by clones.
+///
+/// Useful in tests and for simple in-process reporting.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryMetricsRecorder {
+    records: Arc<Mutex<Vec<RequestMetrics>>>,
+}
+
+impl MemoryMetricsRecorder {
+    /// Creates an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the records collected so far, oldest first.
+    pub fn records(&self) -> Vec<RequestMetrics> {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Removes all collected records.
+    pub fn clear(&self) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl MetricsRecorder for MemoryMetricsRecorder {
+    fn record(&self, metrics: &RequestMetrics) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(metrics.clone());
+    }
+}
+
+/// Classifies an error for the `error.type` span attribute and metric label.
+pub(crate) fn error_type(kind: &ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Http(e) if e.source().is_timeout() => "timeout",
+        ErrorKind::Http(e) if e.source().is_connect() => "connect",
+        ErrorKind::Http(_) => "http",
+        ErrorKind::Auth(_) | ErrorKind::OAuth(_) => "auth",
+        ErrorKind::Api { .. } => "api",
+        ErrorKind::InvalidToken { .. } => "invalid_token",
+        ErrorKind::PermissionDenied { .. } => "permission_denied",
+        ErrorKind::NotFound { .. } => "not_found",
+        ErrorKind::TransferLimitExceeded { .. } => "transfer_limit_exceeded",
+        ErrorKind::InvalidParameter { .. } => "invalid_parameter",
+        ErrorKind::EditFailed(_) => "edit_failed",
+        ErrorKind::CreditBudgetExceeded { .. } => "credit_budget_exceeded",
+        ErrorKind::Json(_) => "json",
+        _ => "_OTHER",
+    }
+}
@@ -0,0 +1,221 @@
+//! Request and response hooks for cross-cutting behavior.
+
+use crate::{Result, ServiceKind};
+use async_trait::async_trait;
+use derive_getters::Getters;
+use reqwest::{Method, Request, Response, StatusCode};
+use std::time::{Duration, Instant};
+use tracing::Span;
+use tracing::field::Empty;
+
+/// Information about a request passing through the executor.
+///
+/// The same context is handed to every hook of a request, so middleware can
+/// correlate [`Middleware::on_request`] with the matching response or error.
+#[derive(Debug, Clone, Getters)]
+pub struct RequestContext {
+    /// REST operation name (e.g. `query`, `applyEdits`).
+    operation: String,
+    /// Kind of service targeted.
+    service_kind: ServiceKind,
+    /// HTTP method.
+    method: Method,
+    /// Request URL without query parameters.
+    url: String,
+    /// Number of attempts made, including retries.
+    attempts: u32,
+    /// HTTP status of the latest response, if one was received.
+    status: Option<StatusCode>,
+    /// Size of the request body in bytes; `0` for bodiless and streamed requests.
+    request_bytes: u64,
+    /// Size of the latest response body in bytes, if known.
+    ///
+    /// Counts the bytes read when the client reads the body itself, and
+    /// otherwise reports the `Content-Length` header.
+    response_bytes: Option<u64>,
+    /// Number of times the request was handed to the transport, across
+    /// retries and token replays.
+    #[getter(skip)]
+    sends: u32,
+    /// Whether an attempt failed after the server may have received it.
+    #[getter(skip)]
+    unconfirmed: bool,
+    /// When the request entered the executor.
+    #[getter(skip)]
+    started: Instant,
+    /// Client span, following the OpenTelemetry HTTP semantic conventions.
+    #[getter(skip)]
+    span: Span,
+}
+
+impl RequestContext {
+    /// Creates a context for a request.
+    pub(crate) fn new(operation: impl Into<String>, method: Method, url: &str) -> Self {
+        let bare = url.split(['?', '#']).next().unwrap_or_default();
+        let operation = operation.into();
+        let service_kind = ServiceKind::from_url_str(bare);
+        let parsed = url::Url::parse(bare).ok();
+        let span = tracing::info_span!(
+            "arcgis.request",
+            otel.name = %method,
+            otel.kind = "client",
+            otel.status_code = Empty,
+            http.request.method = %method,
+            http.request.resend_count = Empty,
+            http.response.status_code = Empty,
+            url.full = %bare,
+            server.address = parsed.as_ref().and_then(|url| url.host_str()),
+            server.port = parsed.as_ref().and_then(|url| url.port_or_known_default()),
+            error.type = Empty,
+            arcgis.operation = %operation,
+            arcgis.service_kind = %service_kind,
+            arcgis.error_code = Empty,
+        );
+        Self {
+            operation,
+            service_kind,
+            method,
+            url: bare.to_string(),
+            attempts: 0,
+            status: None,
+            request_bytes: 0,
+            response_bytes: None,
+            sends: 0,
+            unconfirmed: false,
+            started: Instant::now(),
+            span,
+        }
+    }
+
+    /// Time elapsed since the request entered the executor.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Number of times the request was sent again after the first attempt,
+    /// including retries and replays with a refreshed token.
+    pub fn resend_count(&self) -> u32 {
+        self.sends.saturating_sub(1)
+    }
+
+    /// Span covering the request, with attributes named after the
+    /// [OpenTelemetry HTTP client semantic conventions](https://opentelemetry.io/docs/specs/semconv/http/http-spans/).
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    pub(crate) fn set_method(&mut self, method: Method) {
+        self.span
+            .record("http.request.method", tracing::field::display(&method));
+        self.span
+            .record("otel.name", tracing::field::display(&method));
+        self.method = method;
+    }
+
+    /// Records that the request is being handed to the transport.
+    pub(crate) fn record_send(&mut self, request: &Request) {
+        if self.sends == 0 {
+            self.request_bytes = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map_or(0, |bytes| bytes.len() as u64);
+        }
+        self.sends += 1;
+    }
+
+    /// Records the status and declared body size of a response.
+    pub(crate) fn record_response(&mut self, response: &Response) {
+        self.status = Some(response.status());
+        self.response_bytes = response.content_length();
+    }
+
+    /// Records a failed attempt, noting whether the server may have
+    /// received it.
+    pub(crate) fn record_transport_error(&mut self, error: &crate::Error) {
+        self.unconfirmed |= super::credits::is_unconfirmed(error);
+    }
+
+    /// Returns whether an attempt may have been processed by the server
+    /// although it failed, e.g. because it timed out.
+    pub(crate) fn is_unconfirmed(&self) -> bool {
+        self.unconfirmed
+    }
+
+    /// Records the size of a response body that was read.
+    pub(crate) fn record_body(&mut self, body: &[u8]) {
+        self.response_bytes = Some(body.len() as u64);
+    }
+
+    /// Records the outcome on the span once the request has finished.
+    pub(crate) fn record_outcome(&self, error: Option<&crate::Error>) {
+        if let Some(status) = self.status {
+            self.span
+                .record("http.response.status_code", status.as_u16());
+        }
+        if self.resend_count() > 0 {
+            self.span
+                .record("http.request.resend_count", self.resend_count());
+        }
+        if let Some(error) = error {
+            self.span.record("otel.status_code", "ERROR");
+            self.span
+                .record("error.type", super::metrics::error_type(error.kind()));
+            if let Some(code) = error.kind().code() {
+                self.span.record("arcgis.error_code", code);
+            }
+        }
+    }
+
+    pub(crate) fn set_attempts(&mut self, attempts: u32) {
+        self.attempts = attempts;
+    }
+}
+
+/// Hooks invoked around every request made by an [`ArcGISClient`](crate::ArcGISClient).
+///
+/// Middleware implements cross-cutting behavior (logging, header injection,
+/// metrics) once for all service clients. Hooks run in registration order.
+/// [`on_request`](Self::on_request) runs once per logical request, before
+/// rate limiting and retries; [`on_response`](Self::on_response) sees the
+/// final response after retries; [`on_error`](Self::on_error) sees transport
+/// failures, HTTP error statuses, and ESRI error payloads.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, Middleware, NoAuth, RequestContext};
+/// use async_trait::async_trait;
+///
+/// struct CorrelationId;
+///
+/// #[async_trait]
+/// impl Middleware for CorrelationId {
+///     async fn on_request(
+///         &self,
+///         _context: &RequestContext,
+///         request: &mut arcgis::reqwest::Request,
+///     ) -> arcgis::Result<()> {
+///         request
+///             .headers_mut()
+///             .insert("x-correlation-id", "batch-42".parse().expect("Valid header"));
+///         Ok(())
+///     }
+/// }
+///
+/// let client = ArcGISClient::new(NoAuth).with_middleware(CorrelationId);
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent. May modify the request.
+    ///
+    /// Returning an error aborts the request.
+    async fn on_request(&self, _context: &RequestContext, _request: &mut Request) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the final response, before its body is read.
+    async fn on_response(&self, _context: &RequestContext, _response: &Response) {}
+
+    /// Called when the request fails.
+    async fn on_error(&self, _context: &RequestContext, _error: &crate::Error) {}
+}
@@ -0,0 +1,606 @@
+//! Core HTTP client for ArcGIS services.
+
+mod builder;
+mod cache;
+mod cassette;
+mod credits;
+mod executor;
+mod metrics;
+mod middleware;
+mod rate_limit;
+mod request;
+mod retry;
+mod transport;
+
+pub use builder::{ArcGISClientBuilder, HttpConfig};
+pub use cache::{CacheStore, CachedResponse, DiskCacheStore, MemoryCacheStore, ResponseCache};
+pub use cassette::{CASSETTE_MODE_ENV, CassetteMode, CassetteTransport};
+pub use credits::{CreditMeter, CreditUsage};
+pub use metrics::{MemoryMetricsRecorder, MetricsRecorder, RequestMetrics};
+pub use middleware::{Middleware, RequestContext};
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use request::{ESRI_AUTHORIZATION_HEADER, ServiceRequest, TokenPlacement};
+pub use retry::{RetryPolicy, RetryPolicyBuilder};
+pub use transport::HttpTransport;
+
+use crate::{AuthProvider, Result};
+use derive_getters::Getters;
+use reqwest::{Client as ReqwestClient, Request, RequestBuilder, Response};
+use std::sync::Arc;
+use tracing::{Instrument, instrument};
+
+/// Default longest URL sent as GET.
+///
+/// Conservative enough for IIS and reverse proxies commonly placed in front
+/// of ArcGIS Server, which reject URLs well below the HTTP limit.
+pub(crate) const DEFAULT_MAX_URL_LENGTH: usize = 2048;
+
+/// The main client for interacting with ArcGIS services.
+///
+/// This client handles HTTP communication, authentication, and common
+/// request/response processing for all ArcGIS services.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ApiKeyAuth, ArcGISClient};
+///
+/// let auth = ApiKeyAuth::new("YOUR_API_KEY");
+/// let client = ArcGISClient::new(auth);
+/// ```
+///
+/// Cloning is cheap: the HTTP client, transport, authentication provider,
+/// rate limiter, and cache are shared, so clones reuse connections, tokens,
+/// and rate limits. Service clients hold their own clone and can be moved
+/// into spawned tasks or stored in long-lived state.
+#[derive(Clone, Getters)]
+pub struct ArcGISClient {
+    /// HTTP client used to build requests.
+    http: ReqwestClient,
+    /// Transport that sends requests; defaults to [`http`](Self::http).
+    #[getter(skip)]
+    transport: Arc<dyn HttpTransport>,
+    /// Authentication provider.
+    auth: Arc<dyn AuthProvider>,
+    /// Retry policy for transient failures.
+    retry_policy: RetryPolicy,
+    /// Client-side rate limiter shared by all service clients.
+    rate_limiter: Arc<RateLimiter>,
+    /// Request and response hooks, run in registration order.
+    middleware: Vec<Arc<dyn Middleware>>,
+    /// Receives per-request metrics, if configured.
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    /// Meter charged for billable requests, if configured.
+    credit_meter: Option<CreditMeter>,
+    /// Longest URL sent as GET; longer requests are sent as form-encoded POST.
+    max_url_length: usize,
+    /// Cache for metadata responses, if enabled.
+    cache: Option<ResponseCache>,
+    /// Where authentication tokens are sent.
+    token_placement: TokenPlacement,
+}
+
+impl ArcGISClient {
+    /// Creates a new ArcGIS client with the given authentication provider.
+    ///
+    /// # Using Environment Variables
+    ///
+    /// The SDK automatically loads `.env` files when using auth helpers.
+    /// Store your credentials in a `.env` file (add to `.gitignore`):
+    ///
+    /// ```text
+    /// # ArcGIS Online keys (tier-separated)
+    /// ARCGIS_CONTENT_KEY=your_content_key
+    /// ARCGIS_FEATURES_KEY=your_features_key
+    /// ARCGIS_LOCATION_KEY=your_location_key
+    ///
+    /// # ArcGIS Enterprise
+    /// ARCGIS_ENTERPRISE_KEY=your_enterprise_key
+    /// ARCGIS_ENTERPRISE_PORTAL=https://your-server.com/portal/sharing/rest
+    ///
+    /// # OAuth (optional)
+    /// ARCGIS_CLIENT_ID=your_client_id
+    /// ARCGIS_CLIENT_SECRET=your_client_secret
+    /// ```
+    ///
+    /// Then use the `agol()` or `enterprise()` helpers - no manual `dotenvy::dotenv()` call needed:
+    ///
+    /// ```no_run
+    /// use arcgis::{ApiKeyAuth, ApiKeyTier, ArcGISClient};
+    ///
+    /// # fn example() -> arcgis::Result<()> {
+    /// // ArcGIS Online (automatically loads .env and reads ARCGIS_CONTENT_KEY)
+    /// let auth = ApiKeyAuth::agol(ApiKeyTier::Content)?;
+    /// let client = ArcGISClient::new(auth);
+    ///
+    /// // ArcGIS Enterprise (reads ARCGIS_ENTERPRISE_KEY)
+    /// let auth = ApiKeyAuth::enterprise()?;
+    /// let client = ArcGISClient::new(auth);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(auth))]
+    pub fn new(auth: impl AuthProvider + 'static) -> Self {
+        tracing::debug!("Creating new ArcGIS client");
+        let http = ReqwestClient::new();
+        Self {
+            transport: Arc::new(http.clone()),
+            http,
+            auth: Arc::new(auth),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: Arc::new(RateLimiter::default()),
+            middleware: Vec::new(),
+            metrics: None,
+            credit_meter: None,
+            max_url_length: DEFAULT_MAX_URL_LENGTH,
+            cache: None,
+            token_placement: TokenPlacement::default(),
+        }
+    }
+
+    /// Creates a client from a named connection profile.
+    ///
+    /// Loads the profile with [`Profile::load`](crate::Profile::load), applying environment
+    /// overrides, and authenticates as it describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile cannot be loaded or its
+    /// authentication provider cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::ArcGISClient;
+    ///
+    /// # fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::from_profile("prod")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument]
+    pub fn from_profile(name: &str) -> Result<Self> {
+        crate::Profile::load(name)?.client()
+    }
+
+    /// Creates a builder for configuring the HTTP transport, authentication,
+    /// retry policy, and rate limiter.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, ClientCredentialsAuth};
+    /// use std::time::Duration;
+    ///
+    /// # fn example() -> arcgis::Result<()> {
+    /// let proxy = arcgis::reqwest::Proxy::all("http://proxy.internal:3128")?;
+    ///
+    /// // The proxy and timeout also apply to OAuth token requests
+    /// let client = ArcGISClient::builder()
+    ///     .auth(ClientCredentialsAuth::from_env()?)
+    ///     .proxy(proxy)
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> ArcGISClientBuilder {
+        ArcGISClientBuilder::new()
+    }
+
+    /// Sets the retry policy used for all requests made through this client.
+    ///
+    /// Defaults to [`RetryPolicy::default`] (3 attempts with exponential backoff).
+    /// Use [`RetryPolicy::none`] to disable retries.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, RetryPolicy};
+    ///
+    /// let client = ArcGISClient::new(NoAuth).with_retry_policy(RetryPolicy::none());
+    /// ```
+    #[instrument(skip(self))]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        tracing::debug!("Setting retry policy");
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets the rate limiter applied to all requests made through this client.
+    ///
+    /// Every service client created from this client (geocoding, routing,
+    /// feature queries, etc.) is paced automatically. By default no limits apply.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, RateLimit, RateLimiter, ServiceKind};
+    ///
+    /// let limiter = RateLimiter::new()
+    ///     .with_max_in_flight(8)
+    ///     .with_service_limit(ServiceKind::Geocode, RateLimit::per_second(5.0));
+    ///
+    /// let client = ArcGISClient::new(NoAuth).with_rate_limiter(limiter);
+    /// ```
+    #[instrument(skip(self, limiter))]
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        tracing::debug!("Setting rate limiter");
+        self.rate_limiter = Arc::new(limiter);
+        self
+    }
+
+    /// Adds a middleware that observes or modifies every request.
+    ///
+    /// Middleware runs in the order it was added. See [`Middleware`].
+    #[instrument(skip(self, middleware))]
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        tracing::debug!("Adding middleware");
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the recorder that receives metrics for every request.
+    ///
+    /// Each request reports its service kind, operation, HTTP status, ESRI
+    /// error code, latency, bytes transferred, and retries. See
+    /// [`MetricsRecorder`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, MemoryMetricsRecorder, NoAuth};
+    ///
+    /// let recorder = MemoryMetricsRecorder::new();
+    /// let client = ArcGISClient::new(NoAuth).with_metrics(recorder.clone());
+    /// ```
+    #[instrument(skip(self, recorder))]
+    pub fn with_metrics(mut self, recorder: impl MetricsRecorder + 'static) -> Self {
+        tracing::debug!("Setting metrics recorder");
+        self.metrics = Some(Arc::new(recorder));
+        self
+    }
+
+    /// Sets the meter that estimates and limits credit consumption.
+    ///
+    /// Billable geocoding, routing, elevation, and places requests are
+    /// charged to the meter; once its budget is spent they fail with
+    /// [`ErrorKind::CreditBudgetExceeded`](crate::ErrorKind::CreditBudgetExceeded).
+    /// See [`CreditMeter`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ApiKeyAuth, ArcGISClient, CreditMeter};
+    ///
+    /// let meter = CreditMeter::new().with_budget(10.0);
+    /// let client = ArcGISClient::new(ApiKeyAuth::new("YOUR_API_KEY")).with_credit_meter(meter);
+    /// ```
+    #[instrument(skip(self, meter))]
+    pub fn with_credit_meter(mut self, meter: CreditMeter) -> Self {
+        tracing::debug!(budget = ?meter.budget(), "Setting credit meter");
+        self.credit_meter = Some(meter);
+        self
+    }
+
+    /// Sets the transport that sends requests.
+    ///
+    /// Defaults to the client's `reqwest` client. See [`HttpTransport`].
+    #[instrument(skip(self, transport))]
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        tracing::debug!("Setting HTTP transport");
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Sets the longest URL sent as a GET request.
+    ///
+    /// GET requests whose URL, including the encoded query string, exceeds
+    /// this length are sent as form-encoded POST requests instead. This keeps
+    /// large `objectIds` lists and detailed spatial filters from failing with
+    /// HTTP 414 or being truncated. Defaults to 2048 characters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth};
+    ///
+    /// let client = ArcGISClient::new(NoAuth).with_max_url_length(4096);
+    /// ```
+    #[instrument(skip(self))]
+    pub fn with_max_url_length(mut self, max_url_length: usize) -> Self {
+        tracing::debug!("Setting max URL length");
+        self.max_url_length = max_url_length;
+        self
+    }
+
+    /// Enables caching of service metadata responses.
+    ///
+    /// Definitions, metadata, styles, and raster info are served from the
+    /// cache while fresh and revalidated with conditional requests once
+    /// stale. See [`ResponseCache`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, ResponseCache};
+    ///
+    /// let client = ArcGISClient::new(NoAuth).with_cache(ResponseCache::memory());
+    /// ```
+    #[instrument(skip(self, cache))]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        tracing::debug!(ttl_secs = cache.ttl().as_secs(), "Enabling response cache");
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets where authentication tokens are sent.
+    ///
+    /// Defaults to [`TokenPlacement::Parameter`]. With
+    /// [`TokenPlacement::Header`] tokens travel in the
+    /// `X-Esri-Authorization: Bearer` header and stay out of URLs, proxy
+    /// logs, and server access logs. Individual requests can still send the
+    /// parameter with [`ServiceRequest::token_in_query`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ApiKeyAuth, ArcGISClient, TokenPlacement};
+    ///
+    /// let client = ArcGISClient::new(ApiKeyAuth::new("YOUR_API_KEY"))
+    ///     .with_token_placement(TokenPlacement::Header);
+    /// ```
+    #[instrument(skip(self))]
+    pub fn with_token_placement(mut self, placement: TokenPlacement) -> Self {
+        tracing::debug!(?placement, "Setting token placement");
+        self.token_placement = placement;
+        self
+    }
+
+    /// Gets authentication token if required by the provider.
+    ///
+    /// Returns `Some(token)` if the auth provider requires token parameters
+    /// (e.g., ApiKeyAuth, ClientCredentials), or `None` for providers that
+    /// don't require tokens (e.g., NoAuth for public services).
+    ///
+    /// Without a URL, providers that pick tokens per server or service, such
+    /// as [`AuthRouter`](crate::AuthRouter), can only answer with their
+    /// default token. Use [`get_token_for_url`](Self::get_token_for_url)
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let token = client.get_token_if_required().await?;
+    /// assert!(token.is_none()); // NoAuth returns None
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[deprecated(note = "use `get_token_for_url`, which applies per-URL auth routing")]
+    #[instrument(skip(self))]
+    pub async fn get_token_if_required(&self) -> crate::Result<Option<String>> {
+        if self.auth.requires_token_param() {
+            tracing::debug!("Auth provider requires token, retrieving");
+            Ok(Some(self.auth.get_token().await?))
+        } else {
+            tracing::debug!("Auth provider does not require token");
+            Ok(None)
+        }
+    }
+
+    /// Gets the authentication token for a request to `url`, if one is required.
+    ///
+    /// Lets providers that issue tokens per server or service, such as
+    /// [`AuthRouter`](crate::AuthRouter) and
+    /// [`FederatedServerAuth`](crate::FederatedServerAuth), pick the right one.
+    /// Use it when downloading from URLs outside the service clients.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let url = "https://example.com/server/rest/services/Parcels/MapServer";
+    /// assert!(client.get_token_for_url(url).await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn get_token_for_url(&self, url: &str) -> crate::Result<Option<String>> {
+        self.auth.token_for_url(url).await
+    }
+}
+
+impl ArcGISClient {
+    /// Sends a prebuilt request through middleware, rate limiting, and retries.
+    ///
+    /// This is the low-level escape hatch for endpoints the SDK does not wrap.
+    /// Unlike [`execute`](Self::execute), it does not attach a token or check
+    /// the response status; non-success responses are returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be built, a middleware rejects
+    /// it, or the transport fails on the final attempt.
+    #[instrument(skip(self, request))]
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let request = request.build()?;
+        let operation = request
+            .url()
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .unwrap_or_default()
+            .to_string();
+        let mut context =
+            RequestContext::new(operation, request.method().clone(), request.url().as_str());
+
+        let span = context.span().clone();
+        let result = self
+            .dispatch(&mut context, request, false)
+            .instrument(span)
+            .await;
+        self.finish(&context, &result).await;
+        result
+    }
+
+    /// Runs middleware, then sends the request, retrying transient failures
+    /// according to the retry policy.
+    ///
+    /// Each attempt first waits for the client's [`RateLimiter`], so retries
+    /// are paced like any other request.
+    ///
+    /// A request is retried when the transport fails to connect. Requests the
+    /// retry policy allows to be replayed, either read-only or marked
+    /// `idempotent`, are also retried when the transport times out, when the
+    /// server responds with a retryable HTTP status, or when a successful
+    /// response carries a transient ESRI error payload. Requests whose body
+    /// cannot be cloned (e.g. streamed uploads) are sent once.
+    ///
+    /// Middleware error hooks are left to the caller, which knows whether the
+    /// final response is a failure.
+    pub(crate) async fn dispatch(
+        &self,
+        context: &mut RequestContext,
+        mut request: Request,
+        idempotent: bool,
+    ) -> Result<Response> {
+        for middleware in &self.middleware {
+            middleware.on_request(context, &mut request).await?;
+        }
+
+        let max_attempts = (*self.retry_policy.max_attempts()).max(1);
+        let replayable = self.retry_policy.allows_replay(&request, idempotent);
+        let method = request.method().clone();
+        let path = request.url().path().to_string();
+
+        let mut pending = request;
+        let mut attempt = 1;
+
+        let response = loop {
+            context.set_attempts(attempt);
+
+            // Keep a copy for the next attempt; unclonable bodies are sent once.
+            let next = if attempt < max_attempts {
+                pending.try_clone()
+            } else {
+                None
+            };
+
+            context.record_send(&pending);
+            let outcome = {
+                let _permit = self.rate_limiter.acquire(pending.url()).await;
+                self.transport.execute(pending).await
+            };
+            match &outcome {
+                Ok(response) => context.record_response(response),
+                Err(e) => context.record_transport_error(e),
+            }
+
+            let Some(next) = next else {
+                break outcome?;
+            };
+
+            let retry_after = match outcome {
+                Err(e) if retry::is_retryable_error(&e, replayable) => {
+                    tracing::warn!(attempt, error = %e, "Transient transport error");
+                    None
+                }
+                Err(e) => return Err(e),
+                Ok(response) if !replayable => break response,
+                Ok(response) if retry::is_retryable_status(response.status()) => {
+                    tracing::warn!(attempt, status = %response.status(), "Transient HTTP status");
+                    retry::retry_after(response.headers())
+                }
+                Ok(response) if has_text_body(&response) => {
+                    let (response, body) = buffer_body(response).await?;
+                    match retry::esri_error_code(&body) {
+                        Some(code) if retry::is_retryable_esri_code(code) => {
+                            tracing::warn!(attempt, esri_code = code, "Transient ESRI error");
+                            None
+                        }
+                        _ => break response,
+                    }
+                }
+                Ok(response) => break response,
+            };
+
+            let delay = self.retry_policy.delay(attempt, retry_after);
+            tracing::debug!(
+                method = %method,
+                path = %path,
+                attempt,
+                max_attempts,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying request"
+            );
+            tokio::time::sleep(delay).await;
+
+            pending = next;
+            attempt += 1;
+        };
+
+        context.record_response(&response);
+        for middleware in &self.middleware {
+            middleware.on_response(context, &response).await;
+        }
+
+        Ok(response)
+    }
+
+    /// Reports a finished request to the span, the metrics recorder, and, if
+    /// it failed, the error hooks of all middleware.
+    pub(crate) async fn finish<T>(&self, context: &RequestContext, result: &Result<T>) {
+        let error = result.as_ref().err();
+        context.record_outcome(error);
+        if let Some(recorder) = &self.metrics {
+            recorder.record(&RequestMetrics::new(context, error));
+        }
+        if let Some(e) = error {
+            self.notify_error(context, e).await;
+        }
+    }
+
+    /// Runs the error hooks of all middleware.
+    pub(crate) async fn notify_error(&self, context: &RequestContext, error: &crate::Error) {
+        for middleware in &self.middleware {
+            middleware.on_error(context, error).await;
+        }
+    }
+}
+
+/// Returns whether a response body may carry an ESRI JSON error payload.
+///
+/// ArcGIS Server serves `f=json` responses as either `application/json` or
+/// `text/plain`, so both are inspected. Binary payloads (PBF, tiles, images)
+/// are passed through untouched.
+pub(crate) fn has_text_body(response: &Response) -> bool {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("json") || value.starts_with("text/plain"))
+}
+
+/// Buffers a response body.
+///
+/// Returns a response rebuilt from the buffered body so callers can still
+/// read it normally, along with the body itself.
+pub(crate) async fn buffer_body(response: Response) -> Result<(Response, bytes::Bytes)> {
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?;
+
+    let mut rebuilt = http::Response::new(body.clone());
+    *rebuilt.status_mut() = status;
+    *rebuilt.version_mut() = version;
+    *rebuilt.headers_mut() = headers;
+
+    Ok((Response::from(rebuilt), body))
+}
@@ -0,0 +1,243 @@
+//! Client-side rate limiting and concurrency caps.
+//!
+//! ArcGIS Online throttles bursty clients with HTTP 429. [`RateLimiter`] keeps
+//! batch workloads under the service limits by pacing requests with token
+//! buckets (per host and/or per [`ServiceKind`]) and capping the number of
+//! requests in flight.
+
+use crate::ServiceKind;
+use derive_getters::Getters;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::instrument;
+use url::Url;
+
+/// Token bucket limit for a host or service kind.
+///
+/// Requests are admitted at [`requests_per_second`](Self::requests_per_second)
+/// on average, with up to [`burst`](Self::burst) requests allowed back-to-back
+/// after a quiet period. An optional [`max_in_flight`](Self::max_in_flight) caps
+/// concurrent requests to the same target.
+///
+/// # Example
+///
+/// ```
+/// use arcgis::RateLimit;
+///
+/// // 5 requests per second, bursts of up to 10, at most 4 concurrent
+/// let limit = RateLimit::per_second(5.0).with_burst(10).with_max_in_flight(4);
+/// assert_eq!(*limit.burst(), 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Getters)]
+pub struct RateLimit {
+    /// Sustained request rate.
+    requests_per_second: f64,
+    /// Maximum number of tokens the bucket can hold.
+    burst: u32,
+    /// Maximum concurrent requests, if capped.
+    max_in_flight: Option<usize>,
+}
+
+impl RateLimit {
+    /// Creates a limit of `requests_per_second`, with a burst of one second's worth.
+    pub fn per_second(requests_per_second: f64) -> Self {
+        let requests_per_second = requests_per_second.max(f64::MIN_POSITIVE);
+        Self {
+            requests_per_second,
+            burst: (requests_per_second.ceil() as u32).max(1),
+            max_in_flight: None,
+        }
+    }
+
+    /// Creates a limit of `requests_per_minute`.
+    pub fn per_minute(requests_per_minute: f64) -> Self {
+        Self::per_second(requests_per_minute / 60.0)
+    }
+
+    /// Sets the bucket capacity (maximum back-to-back requests).
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Caps concurrent requests to the limited target.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight.max(1));
+        self
+    }
+}
+
+/// Key identifying a rate-limited target.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum LimitKey {
+    Host(String),
+    Service(ServiceKind),
+}
+
+/// Runtime state for one rate-limited target.
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    state: Mutex<BucketState>,
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(limit.burst),
+                refilled_at: Instant::now(),
+            }),
+            in_flight: limit.max_in_flight.map(|n| Arc::new(Semaphore::new(n))),
+        }
+    }
+
+    /// Takes a token, returning how long to wait if none is available.
+    fn try_take(&self) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.limit.requests_per_second)
+            .min(f64::from(self.limit.burst));
+        state.refilled_at = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            None
+        } else {
+            let missing = 1.0 - state.tokens;
+            Some(Duration::from_secs_f64(
+                missing / self.limit.requests_per_second,
+            ))
+        }
+    }
+
+    async fn take(&self) {
+        while let Some(wait) = self.try_take() {
+            tracing::debug!(
+                wait_ms = wait.as_millis() as u64,
+                "Rate limit reached, waiting"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Permits held for the duration of a request.
+///
+/// Dropping the permit releases the in-flight slots.
+#[derive(Debug, Default)]
+pub(crate) struct RatePermit {
+    _permits: Vec<OwnedSemaphorePermit>,
+}
+
+/// Client-side rate limiter shared by all service clients of an [`ArcGISClient`](crate::ArcGISClient).
+///
+/// Limits can be configured per host (e.g. `geocode.arcgis.com`) and per
+/// [`ServiceKind`]; when both match a request, both are enforced. A global
+/// in-flight cap applies to every request. Without any configuration the
+/// limiter admits all requests immediately.
+///
+/// The in-flight slot is held until response headers arrive; reading the
+/// response body does not count against the cap.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, NoAuth, RateLimit, RateLimiter, ServiceKind};
+///
+/// let limiter = RateLimiter::new()
+///     .with_max_in_flight(16)
+///     .with_service_limit(ServiceKind::Geocode, RateLimit::per_second(10.0))
+///     .with_service_limit(ServiceKind::Routing, RateLimit::per_second(2.0).with_max_in_flight(2))
+///     .with_host_limit("services.arcgis.com", RateLimit::per_minute(600.0));
+///
+/// let client = ArcGISClient::new(NoAuth).with_rate_limiter(limiter);
+/// ```
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    max_in_flight: Option<Arc<Semaphore>>,
+    buckets: HashMap<LimitKey, Bucket>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter with no limits configured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Caps the total number of concurrent requests across all hosts.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(Arc::new(Semaphore::new(max_in_flight.max(1))));
+        self
+    }
+
+    /// Applies a limit to every request sent to `host`.
+    ///
+    /// The host is matched against the request URL's host name, ignoring case.
+    pub fn with_host_limit(mut self, host: impl Into<String>, limit: RateLimit) -> Self {
+        self.buckets.insert(
+            LimitKey::Host(host.into().to_ascii_lowercase()),
+            Bucket::new(limit),
+        );
+        self
+    }
+
+    /// Applies a limit to every request of the given service kind.
+    pub fn with_service_limit(mut self, kind: ServiceKind, limit: RateLimit) -> Self {
+        self.buckets
+            .insert(LimitKey::Service(kind), Bucket::new(limit));
+        self
+    }
+
+    /// Returns whether any limit is configured.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_in_flight.is_none() && self.buckets.is_empty()
+    }
+
+    /// Waits until a request to `url` may be sent.
+    #[instrument(skip(self, url), fields(host = url.host_str().unwrap_or_default()))]
+    pub(crate) async fn acquire(&self, url: &Url) -> RatePermit {
+        if self.is_unlimited() {
+            return RatePermit::default();
+        }
+
+        let host_key = LimitKey::Host(url.host_str().unwrap_or_default().to_ascii_lowercase());
+        let service_key = LimitKey::Service(ServiceKind::from_url(url));
+        let matched: Vec<&Bucket> = [&host_key, &service_key]
+            .into_iter()
+            .filter_map(|key| self.buckets.get(key))
+            .collect();
+
+        let mut permits = Vec::new();
+
+        // Acquire concurrency slots before tokens so queued requests don't burn rate budget.
+        let semaphores = self.max_in_flight.iter().chain(
+            matched
+                .iter()
+                .filter_map(|bucket| bucket.in_flight.as_ref()),
+        );
+        for semaphore in semaphores {
+            if let Ok(permit) = Arc::clone(semaphore).acquire_owned().await {
+                permits.push(permit);
+            }
+        }
+
+        for bucket in matched {
+            bucket.take().await;
+        }
+
+        RatePermit { _permits: permits }
+    }
+}
@@ -0,0 +1,424 @@
+//! Service request description used by the unified executor.
+
+use crate::Result;
+use reqwest::Method;
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::multipart::Form;
+use serde::Serialize;
+
+/// Header carrying the token when [`TokenPlacement::Header`] is used.
+pub const ESRI_AUTHORIZATION_HEADER: &str = "X-Esri-Authorization";
+
+/// Where the authentication token is sent.
+///
+/// ArcGIS REST endpoints accept the token either as a `token` parameter or
+/// in the `X-Esri-Authorization: Bearer <token>` header. Parameters end up in
+/// proxy and server access logs; the header keeps tokens out of URLs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenPlacement {
+    /// `token` query parameter, or form field for POST requests.
+    #[default]
+    Parameter,
+    /// `X-Esri-Authorization: Bearer <token>` header.
+    Header,
+}
+
+/// Body of a [`ServiceRequest`].
+#[derive(Debug, Default)]
+pub(crate) enum RequestBody {
+    /// No body; parameters travel in the query string.
+    #[default]
+    Empty,
+    /// URL-encoded form parameters.
+    Form(Vec<(String, String)>),
+    /// Multipart upload. Consumed by the first attempt.
+    Multipart(Form),
+}
+
+/// A request to an ArcGIS REST endpoint, executed by [`ArcGISClient`](crate::ArcGISClient).
+///
+/// Service clients describe *what* to send (method, URL, parameters, operation
+/// name) and the client's executor handles *how*: authentication, middleware,
+/// rate limiting, retries, HTTP status checks, and ESRI error detection.
+///
+/// Parameters are kept as key/value pairs rather than a prebuilt HTTP request,
+/// so the executor can place the token and rebuild the request when needed.
+/// GET requests whose encoded URL exceeds the client's
+/// [`max_url_length`](crate::ArcGISClient::max_url_length) are sent as a
+/// form-encoded POST instead, which ArcGIS REST endpoints accept equally.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, NoAuth, ServiceRequest};
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let client = ArcGISClient::new(NoAuth);
+///
+/// let request = ServiceRequest::get("https://example.com/arcgis/rest/services/Cities/MapServer")
+///     .operation("serviceInfo")
+///     .query(&[("f", "json")]);
+///
+/// let info: serde_json::Value = client.execute_json(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ServiceRequest {
+    method: Method,
+    url: String,
+    operation: Option<String>,
+    query: Vec<(String, String)>,
+    body: RequestBody,
+    authenticate: bool,
+    cacheable: bool,
+    idempotent: bool,
+    billable_units: Option<u32>,
+    token_placement: Option<TokenPlacement>,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    error: Option<crate::Error>,
+}
+
+impl ServiceRequest {
+    /// Creates a request with the given method and URL.
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            operation: None,
+            query: Vec::new(),
+            body: RequestBody::Empty,
+            authenticate: true,
+            cacheable: false,
+            idempotent: false,
+            billable_units: None,
+            token_placement: None,
+            headers: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Creates a GET request.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    /// Creates a POST request.
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::POST, url)
+    }
+
+    /// Names the REST operation for logging, middleware, and error messages.
+    ///
+    /// Defaults to the last segment of the URL path.
+    pub fn operation(mut self, operation: impl Into<String>) -> Self {
+        self.operation = Some(operation.into());
+        self
+    }
+
+    /// Appends query string parameters.
+    ///
+    /// Accepts anything `serde_urlencoded` can serialize: parameter structs,
+    /// slices of pairs, or maps. Serialization errors are reported when the
+    /// request is executed.
+    pub fn query<T: Serialize + ?Sized>(mut self, params: &T) -> Self {
+        match encode_pairs(params) {
+            Ok(pairs) => self.query.extend(pairs),
+            Err(e) => self.fail(e),
+        }
+        self
+    }
+
+    /// Appends URL-encoded form parameters to the request body.
+    pub fn form<T: Serialize + ?Sized>(mut self, params: &T) -> Self {
+        match encode_pairs(params) {
+            Ok(pairs) => match &mut self.body {
+                RequestBody::Form(form) => form.extend(pairs),
+                _ => self.body = RequestBody::Form(pairs),
+            },
+            Err(e) => self.fail(e),
+        }
+        self
+    }
+
+    /// Sends a multipart body. The authentication token is added as a text part.
+    pub fn multipart(mut self, form: Form) -> Self {
+        self.body = RequestBody::Multipart(form);
+        self
+    }
+
+    /// Sends the request without an authentication token.
+    ///
+    /// Useful for pre-signed URLs and public endpoints that reject tokens.
+    pub fn without_token(mut self) -> Self {
+        self.authenticate = false;
+        self
+    }
+
+    /// Sends the token as a parameter even if the client places tokens in
+    /// the `X-Esri-Authorization` header.
+    ///
+    /// For endpoints that only read the `token` parameter, such as some
+    /// reverse proxies and web adaptors in front of older ArcGIS Server sites.
+    pub fn token_in_query(mut self) -> Self {
+        self.token_placement = Some(TokenPlacement::Parameter);
+        self
+    }
+
+    /// Allows the response to be served from the client's
+    /// [`ResponseCache`](crate::ResponseCache).
+    ///
+    /// Intended for metadata that rarely changes, such as service and layer
+    /// definitions. Has no effect unless the client has a cache and the
+    /// request is executed with [`execute_json`](crate::ArcGISClient::execute_json).
+    pub fn cacheable(mut self) -> Self {
+        self.cacheable = true;
+        self
+    }
+
+    /// Marks the request as safe to replay after a timeout or transient
+    /// server error.
+    ///
+    /// GET requests and read-only POST operations such as `query` are
+    /// replayable by default. Other POST requests may change server state and
+    /// are only retried after connection failures, which happen before the
+    /// server sees the request, unless marked idempotent.
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    /// Marks the request as consuming credits for `units` billable records
+    /// or transactions, e.g. one per address in a batch geocode or one per
+    /// route solve.
+    ///
+    /// Billable requests are charged to the client's
+    /// [`CreditMeter`](crate::CreditMeter), if one is configured.
+    pub fn billable(mut self, units: u32) -> Self {
+        self.billable_units = Some(units);
+        self
+    }
+
+    /// Returns the HTTP method.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Returns the request URL, without query parameters.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the operation name.
+    pub fn operation_name(&self) -> &str {
+        match &self.operation {
+            Some(operation) => operation,
+            None => self
+                .url
+                .split(['?', '#'])
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns whether a token should be attached.
+    pub(crate) fn authenticate(&self) -> bool {
+        self.authenticate
+    }
+
+    /// Returns whether the response may be cached.
+    pub(crate) fn is_cacheable(&self) -> bool {
+        self.cacheable
+    }
+
+    /// Returns whether the request was marked idempotent.
+    ///
+    /// GETs converted to POST by [`convert_to_post`](Self::convert_to_post) are
+    /// marked, so they stay replayable.
+    pub(crate) fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    /// Returns the number of billable units, if the request consumes credits.
+    pub(crate) fn billable_units(&self) -> Option<u32> {
+        self.billable_units
+    }
+
+    /// Adds a request header.
+    ///
+    /// Values that are not valid header values are skipped.
+    pub(crate) fn header(mut self, name: HeaderName, value: &str) -> Self {
+        match HeaderValue::from_str(value) {
+            Ok(value) => self.headers.push((name, value)),
+            Err(_) => tracing::warn!(header = %name, "Skipping invalid header value"),
+        }
+        self
+    }
+
+    /// Returns the response cache key: method, URL, sorted parameters, and
+    /// a hash of `identity`, the caller the response was served to.
+    ///
+    /// The token itself is excluded, so entries keyed by a stable principal
+    /// survive token refreshes.
+    pub(crate) fn cache_key(&self, identity: Option<&str>) -> String {
+        let mut params: Vec<&(String, String)> = self.query.iter().collect();
+        if let RequestBody::Form(form) = &self.body {
+            params.extend(form);
+        }
+        params.retain(|(key, _)| key != "token");
+        params.sort();
+
+        let encoded = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+        match identity {
+            Some(identity) => format!(
+                "{} {}?{} as {}",
+                self.method,
+                self.url,
+                encoded,
+                identity_hash(identity)
+            ),
+            None => format!("{} {}?{}", self.method, self.url, encoded),
+        }
+    }
+
+    /// Takes the first parameter encoding error, if any.
+    pub(crate) fn take_error(&mut self) -> Option<crate::Error> {
+        self.error.take()
+    }
+
+    /// Attaches an authentication token.
+    ///
+    /// `placement` is the client default; [`token_in_query`](Self::token_in_query)
+    /// overrides it. With [`TokenPlacement::Header`] the token is sent as a
+    /// sensitive `X-Esri-Authorization` header. Otherwise it goes in the
+    /// conventional location: multipart part, form field for POST requests,
+    /// query parameter otherwise.
+    pub(crate) fn attach_token(&mut self, token: String, placement: TokenPlacement) {
+        if self.token_placement.unwrap_or(placement) == TokenPlacement::Header {
+            match HeaderValue::from_str(&format!("Bearer {}", token)) {
+                Ok(mut value) => {
+                    value.set_sensitive(true);
+                    self.headers
+                        .push((HeaderName::from_static("x-esri-authorization"), value));
+                    return;
+                }
+                Err(_) => {
+                    tracing::warn!("Token is not a valid header value, sending as parameter");
+                }
+            }
+        }
+
+        match &mut self.body {
+            RequestBody::Multipart(_) => {
+                if let RequestBody::Multipart(form) = std::mem::take(&mut self.body) {
+                    self.body = RequestBody::Multipart(form.text("token", token));
+                }
+            }
+            RequestBody::Form(form) => form.push(("token".to_string(), token)),
+            RequestBody::Empty if self.method == Method::POST => {
+                self.body = RequestBody::Form(vec![("token".to_string(), token)]);
+            }
+            RequestBody::Empty => self.query.push(("token".to_string(), token)),
+        }
+    }
+
+    /// Copies the request so it can be sent again.
+    ///
+    /// Returns `None` for multipart uploads, which can only be sent once.
+    /// Parameter encoding errors are not copied; take them first.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        let body = match &self.body {
+            RequestBody::Empty => RequestBody::Empty,
+            RequestBody::Form(form) => RequestBody::Form(form.clone()),
+            RequestBody::Multipart(_) => return None,
+        };
+        Some(Self {
+            method: self.method.clone(),
+            url: self.url.clone(),
+            operation: self.operation.clone(),
+            query: self.query.clone(),
+            body,
+            authenticate: self.authenticate,
+            cacheable: self.cacheable,
+            idempotent: self.idempotent,
+            billable_units: self.billable_units,
+            token_placement: self.token_placement,
+            headers: self.headers.clone(),
+            error: None,
+        })
+    }
+
+    /// Returns the length of the URL including the encoded query string.
+    pub(crate) fn url_len(&self) -> usize {
+        if self.query.is_empty() {
+            return self.url.len();
+        }
+        let encoded = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&self.query)
+            .finish();
+        self.url.len() + 1 + encoded.len()
+    }
+
+    /// Moves the query parameters of a bodiless GET into a form-encoded POST.
+    ///
+    /// Returns `false` and leaves the request unchanged if it is not a GET
+    /// or already has a body.
+    pub(crate) fn convert_to_post(&mut self) -> bool {
+        if self.method != Method::GET || !matches!(self.body, RequestBody::Empty) {
+            return false;
+        }
+        self.method = Method::POST;
+        self.body = RequestBody::Form(std::mem::take(&mut self.query));
+        // The original GET was safe to replay, and so is its POST form
+        self.idempotent = true;
+        true
+    }
+
+    /// Converts into a `reqwest` request builder.
+    pub(crate) fn into_builder(self, http: &reqwest::Client) -> reqwest::RequestBuilder {
+        let mut builder = http.request(self.method, &self.url);
+        if !self.query.is_empty() {
+            builder = builder.query(&self.query);
+        }
+        for (name, value) in self.headers {
+            builder = builder.header(name, value);
+        }
+        match self.body {
+            RequestBody::Empty => builder,
+            RequestBody::Form(form) => builder.form(&form),
+            RequestBody::Multipart(form) => builder.multipart(form),
+        }
+    }
+
+    fn fail(&mut self, error: crate::Error) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+}
+
+/// Hashes an identity for use in cache keys, which stores may persist in
+/// plain text.
+fn identity_hash(identity: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(identity.as_bytes())
+        .iter()
+        .take(16)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Serializes parameters into owned key/value pairs.
+fn encode_pairs<T: Serialize + ?Sized>(params: &T) -> Result<Vec<(String, String)>> {
+    let encoded = serde_urlencoded::to_string(params)?;
+    Ok(url::form_urlencoded::parse(encoded.as_bytes())
+        .into_owned()
+        .collect())
+}
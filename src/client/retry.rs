@@ -0,0 +1,267 @@
+//! Retry policy with ESRI-aware error classification.
+//!
+//! ArcGIS services fail transiently in two ways: at the HTTP layer (502/503/504
+//! from a load balancer, dropped connections, timeouts) and inside an HTTP 200
+//! response body (`{"error": {"code": 500, "message": "Unable to complete operation."}}`).
+//! [`RetryPolicy`] classifies both and retries with exponential backoff.
+
+use derive_getters::Getters;
+use reqwest::{Method, Request, StatusCode, header::HeaderMap};
+use std::time::{Duration, SystemTime};
+
+/// HTTP status codes that indicate a transient failure.
+const RETRYABLE_STATUS_CODES: &[u16] = &[408, 429, 500, 502, 503, 504];
+
+/// ESRI body error codes that indicate a transient server-side failure.
+const RETRYABLE_ESRI_CODES: &[i32] = &[429, 500, 502, 503, 504];
+
+/// Read-only operations (final URL path segment) that are safe to replay
+/// when sent as POST.
+///
+/// Any other POST may change server state, so it is only replayed after a
+/// failure the server may have seen if it is marked
+/// [`idempotent`](crate::ServiceRequest::idempotent) or
+/// [`RetryPolicy::retry_non_idempotent`] is set.
+const READ_ONLY_OPERATIONS: &[&str] = &[
+    "query",
+    "queryRelatedRecords",
+    "queryAttachments",
+    "queryTopFeatures",
+    "queryDomains",
+    "identify",
+    "find",
+    "export",
+    "exportImage",
+    "generateRenderer",
+    "generateKml",
+    "getSamples",
+    "computeHistograms",
+    "computeStatisticsHistograms",
+    "measure",
+    "findAddressCandidates",
+    "reverseGeocode",
+    "suggest",
+    "project",
+    "buffer",
+    "simplify",
+    "union",
+    "intersect",
+    "difference",
+    "areasAndLengths",
+    "lengths",
+    "distance",
+    "relation",
+    "densify",
+    "generalize",
+    "offset",
+    "convexHull",
+    "labelPoints",
+    "cut",
+    "reshape",
+    "trimExtend",
+    "autoComplete",
+    "fromGeoCoordinateString",
+    "toGeoCoordinateString",
+    "differences",
+    "conflicts",
+];
+
+/// Retry policy for transient ArcGIS failures.
+///
+/// Requests are retried on connection errors and, if they are safe to replay,
+/// on timeouts, retryable HTTP statuses (408, 429, 500, 502, 503, 504), and
+/// ESRI error payloads with a server-side code (e.g. `{"error": {"code": 500, ...}}`).
+/// Delays grow exponentially from [`initial_backoff`](Self::initial_backoff)
+/// up to [`max_backoff`](Self::max_backoff), optionally with jitter, and a
+/// `Retry-After` header takes precedence when present.
+///
+/// GET, HEAD, and OPTIONS requests and read-only POST operations such as
+/// `query` and `identify` are safe to replay; other requests must be marked
+/// [`idempotent`](crate::ServiceRequest::idempotent). Operations that may
+/// change server state, such as `applyEdits` or a geoprocessing `execute`,
+/// are only retried after failures the server cannot have seen unless
+/// [`retry_non_idempotent`](Self::retry_non_idempotent) is enabled.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, NoAuth, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::builder()
+///     .max_attempts(5u32)
+///     .initial_backoff(Duration::from_millis(250))
+///     .build()
+///     .expect("Valid retry policy");
+///
+/// let client = ArcGISClient::new(NoAuth).with_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone, PartialEq, derive_builder::Builder, Getters)]
+#[builder(setter(into), default)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first request.
+    ///
+    /// A value of `1` disables retries.
+    max_attempts: u32,
+
+    /// Delay before the first retry.
+    initial_backoff: Duration,
+
+    /// Upper bound on any single delay, including `Retry-After` values.
+    max_backoff: Duration,
+
+    /// Factor applied to the delay after each retry.
+    multiplier: f64,
+
+    /// Whether to randomize each delay to avoid synchronized retries.
+    jitter: bool,
+
+    /// Whether to honor the server's `Retry-After` header.
+    respect_retry_after: bool,
+
+    /// Whether to replay operations that may change server state, such as
+    /// `applyEdits`, after timeouts and transient server errors.
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            respect_retry_after: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new builder for RetryPolicy.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// Returns a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether a request may be replayed after a failure that can
+    /// occur once the server has received it.
+    ///
+    /// `marked` is set for requests the caller declared idempotent.
+    pub(crate) fn allows_replay(&self, request: &Request, marked: bool) -> bool {
+        self.retry_non_idempotent || marked || is_idempotent(request)
+    }
+
+    /// Computes the delay before the next attempt.
+    ///
+    /// `retry` is the 1-based retry number. A `Retry-After` value from the
+    /// server, when honored, replaces the computed exponential delay.
+    pub(crate) fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after.filter(|_| self.respect_retry_after) {
+            return retry_after.min(self.max_backoff);
+        }
+
+        let exponent = retry.saturating_sub(1).min(31) as i32;
+        let base = self.initial_backoff.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        let capped = base.min(self.max_backoff.as_secs_f64());
+
+        let seconds = if self.jitter {
+            // Equal jitter: keep half the delay, randomize the other half.
+            capped / 2.0 + fastrand::f64() * capped / 2.0
+        } else {
+            capped
+        };
+
+        Duration::from_secs_f64(seconds)
+    }
+}
+
+/// Returns whether a request can be safely replayed.
+fn is_idempotent(request: &Request) -> bool {
+    if matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) {
+        return true;
+    }
+
+    let operation = request
+        .url()
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .unwrap_or_default();
+
+    READ_ONLY_OPERATIONS.contains(&operation)
+}
+
+/// Returns whether an HTTP status indicates a transient failure.
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    RETRYABLE_STATUS_CODES.contains(&status.as_u16())
+}
+
+/// Returns whether a transport error indicates a transient failure.
+///
+/// Connection failures happen before the server sees the request and are
+/// always retryable. A timeout may fire after the server has received and
+/// processed the request, so it is only retryable if `replayable`.
+pub(crate) fn is_retryable_error(error: &crate::Error, replayable: bool) -> bool {
+    match error.kind() {
+        crate::ErrorKind::Http(e) => {
+            let e = e.source();
+            e.is_connect() || (replayable && e.is_timeout())
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether an ESRI body error code indicates a transient failure.
+pub(crate) fn is_retryable_esri_code(code: i32) -> bool {
+    RETRYABLE_ESRI_CODES.contains(&code)
+}
+
+/// Extracts the ESRI error code from a response body, if present.
+pub(crate) fn esri_error_code(body: &[u8]) -> Option<i32> {
+    #[derive(serde::Deserialize)]
+    struct ErrorResponse {
+        error: ErrorDetail,
+    }
+    #[derive(serde::Deserialize)]
+    struct ErrorDetail {
+        code: i32,
+    }
+
+    if !body.windows(7).any(|window| window == b"\"error\"") {
+        return None;
+    }
+
+    serde_json::from_slice::<ErrorResponse>(body)
+        .ok()
+        .map(|err| err.error.code)
+}
+
+/// Parses a `Retry-After` header given as seconds or an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
@@ -0,0 +1,63 @@
+//! Pluggable HTTP transport.
+
+use crate::Result;
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+
+/// Sends HTTP requests on behalf of an [`ArcGISClient`](crate::ArcGISClient).
+///
+/// The default transport is a `reqwest::Client`. Supplying another
+/// implementation lets tests answer requests from memory, so any service
+/// client method can run offline against canned ESRI JSON or PBF payloads.
+/// Middleware, rate limiting, retries, and ESRI error detection all run on
+/// top of the transport.
+///
+/// Responses can be built from an `http::Response` with
+/// `reqwest::Response::from`.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, FeatureServiceClient, HttpTransport, LayerId};
+/// use arcgis::reqwest::{Request, Response};
+/// use async_trait::async_trait;
+///
+/// struct EmptyFeatureSet;
+///
+/// #[async_trait]
+/// impl HttpTransport for EmptyFeatureSet {
+///     async fn execute(&self, _request: Request) -> arcgis::Result<Response> {
+///         let response = http::Response::builder()
+///             .header("content-type", "application/json")
+///             .body(r#"{"features": []}"#)
+///             .expect("Valid response");
+///         Ok(Response::from(response))
+///     }
+/// }
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let client = ArcGISClient::builder().transport(EmptyFeatureSet).build()?;
+/// let service = FeatureServiceClient::new("https://example.com/FeatureServer", &client);
+/// let features = service.query(LayerId::new(0)).execute().await?;
+/// assert!(features.features().is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    /// Sends a request and returns the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent. Connection and timeout
+    /// errors from `reqwest` are retried according to the client's
+    /// [`RetryPolicy`](crate::RetryPolicy).
+    async fn execute(&self, request: Request) -> Result<Response>;
+}
+
+#[async_trait]
+impl HttpTransport for reqwest::Client {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        Ok(reqwest::Client::execute(self, request).await?)
+    }
+}
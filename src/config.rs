@@ -14,6 +14,9 @@
 //! - `ARCGIS_ENTERPRISE_PORTAL` - URL for ArcGIS Enterprise portal (e.g., `https://your-server.com/portal/sharing/rest`)
 //! - `ARCGIS_ENTERPRISE_KEY` - API key for ArcGIS Enterprise portal operations
 //! - `ARCGIS_FEATURE_URL` - Base URL for a feature service (e.g., `https://your-server.com/arcgis/rest/services/Assets/FeatureServer`)
+//! - `ARCGIS_USERNAME` - Portal username for `generateToken` authentication
+//! - `ARCGIS_PASSWORD` - Portal password for `generateToken` authentication
+//! - `ARCGIS_PROFILE_FILE` - Path of the connection profiles file (see [`Profile`](crate::Profile))
 //!
 //! # Example
 //!
@@ -96,6 +99,21 @@ pub struct EnvConfig {
     /// Used for version management examples to construct VersionManagementServer URL.
     /// The VersionManagementServer URL is derived by replacing `FeatureServer` with `VersionManagementServer`.
     pub arcgis_feature_url: Option<String>,
+
+    /// Portal username for `generateToken` authentication.
+    ///
+    /// Overrides the username of a [`Profile`](crate::Profile).
+    pub arcgis_username: Option<String>,
+
+    /// Portal password for `generateToken` authentication.
+    ///
+    /// Overrides the password of a [`Profile`](crate::Profile).
+    pub arcgis_password: Option<SecretString>,
+
+    /// Path of the connection profiles file.
+    ///
+    /// Defaults to `~/.config/arcgis/profiles.toml` when unset.
+    pub arcgis_profile_file: Option<String>,
 }
 
 impl EnvConfig {
@@ -152,6 +170,16 @@ impl EnvConfig {
             arcgis_feature_url: std::env::var("ARCGIS_FEATURE_URL").ok().inspect(|_| {
                 tracing::debug!("ARCGIS_FEATURE_URL loaded from environment");
             }),
+            arcgis_username: std::env::var("ARCGIS_USERNAME").ok().inspect(|_| {
+                tracing::debug!("ARCGIS_USERNAME loaded from environment");
+            }),
+            arcgis_password: std::env::var("ARCGIS_PASSWORD").ok().map(|s| {
+                tracing::debug!("ARCGIS_PASSWORD loaded from environment");
+                SecretString::new(s.into())
+            }),
+            arcgis_profile_file: std::env::var("ARCGIS_PROFILE_FILE").ok().inspect(|_| {
+                tracing::debug!("ARCGIS_PROFILE_FILE loaded from environment");
+            }),
         };
 
         tracing::debug!("Environment configuration loaded");
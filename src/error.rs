@@ -1,5 +1,8 @@
 //! Error types for the ArcGIS SDK.
 
+use crate::{EditFailure, redact_tokens};
+use std::borrow::Cow;
+
 /// HTTP request error wrapper.
 #[derive(Debug, derive_more::Display, derive_more::Error, derive_getters::Getters)]
 #[display("HTTP request failed: {}", source)]
@@ -245,6 +248,84 @@ pub enum ErrorKind {
         message: String,
     },
 
+    /// Invalid or expired token (ESRI code 498) or missing token (499).
+    #[display("Invalid or missing token ({}): {}", code, message)]
+    InvalidToken {
+        /// Error code (498 or 499).
+        code: i32,
+        /// Error message from the API.
+        message: String,
+    },
+
+    /// The caller lacks permission for the resource or operation (403).
+    #[display("Permission denied: {}{}", message, Details(details))]
+    PermissionDenied {
+        /// Error message from the API.
+        message: String,
+        /// Additional details from the API.
+        details: Vec<String>,
+    },
+
+    /// The service, layer, item, or resource does not exist (404).
+    #[display("Not found: {}{}", message, Details(details))]
+    NotFound {
+        /// Error message from the API.
+        message: String,
+        /// Additional details from the API.
+        details: Vec<String>,
+    },
+
+    /// The request exceeded the server's transfer or size limit.
+    #[display("Transfer limit exceeded ({}): {}{}", code, message, Details(details))]
+    TransferLimitExceeded {
+        /// Error code from the API.
+        code: i32,
+        /// Error message from the API.
+        message: String,
+        /// Additional details from the API.
+        details: Vec<String>,
+    },
+
+    /// Invalid or missing input parameters (400).
+    #[display("Invalid parameter: {}{}", message, Details(details))]
+    InvalidParameter {
+        /// Error message from the API.
+        message: String,
+        /// Additional details from the API, usually naming the parameter.
+        details: Vec<String>,
+    },
+
+    /// One or more items of an edit operation were rejected.
+    ///
+    /// Created by [`EditResult::error_for_failures`](crate::EditResult::error_for_failures).
+    #[display("{} edit(s) failed: {}", _0.len(), EditFailures(_0))]
+    EditFailed(Vec<EditFailure>),
+
+    /// A billable request would exceed the client's credit budget.
+    ///
+    /// Raised by the [`CreditMeter`](crate::CreditMeter) before the request is sent.
+    #[display(
+        "Credit budget exceeded: {:.3} of {:.3} credits spent, request needs {:.3}",
+        spent,
+        budget,
+        estimate
+    )]
+    CreditBudgetExceeded {
+        /// Budget in credits.
+        budget: f64,
+        /// Estimated credits spent before the request.
+        spent: f64,
+        /// Estimated credits the request would consume.
+        estimate: f64,
+    },
+
+    /// The service reports that it does not support a requested operation.
+    ///
+    /// Raised before the request is sent, from capabilities loaded with
+    /// [`FeatureServiceClient::capabilities`](crate::FeatureServiceClient::capabilities).
+    #[display("Not supported by service: {}", _0)]
+    Unsupported(String),
+
     /// JSON serialization/deserialization error.
     #[display("{}", _0)]
     #[from]
@@ -279,6 +360,10 @@ pub enum ErrorKind {
     #[display("OAuth error: {}", _0)]
     OAuth(String),
 
+    /// Connection profile missing, unreadable, or incomplete.
+    #[display("Profile error: {}", _0)]
+    Profile(String),
+
     /// Geometry conversion error.
     #[display("Geometry conversion error: {}", _0)]
     Geometry(String),
@@ -292,6 +377,173 @@ pub enum ErrorKind {
     Other(String),
 }
 
+impl ErrorKind {
+    /// Classifies an ESRI error by code, message, and details.
+    ///
+    /// Used for both `{"error": {...}}` payloads and HTTP error statuses.
+    /// Codes and messages without a dedicated variant become
+    /// [`Api`](Self::Api), with any details appended to the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arcgis::ErrorKind;
+    ///
+    /// let kind = ErrorKind::from_esri(498, "Invalid token.", Vec::new());
+    /// assert!(matches!(kind, ErrorKind::InvalidToken { code: 498, .. }));
+    ///
+    /// let kind = ErrorKind::from_esri(
+    ///     400,
+    ///     "Unable to complete operation.",
+    ///     vec!["'where' parameter is invalid".to_string()],
+    /// );
+    /// assert!(matches!(kind, ErrorKind::InvalidParameter { .. }));
+    /// ```
+    pub fn from_esri(code: i32, message: impl Into<String>, details: Vec<String>) -> Self {
+        let message = message.into();
+        let text = std::iter::once(&message)
+            .chain(&details)
+            .map(|s| s.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if matches!(code, 498 | 499)
+            || text.starts_with("invalid token")
+            || text.starts_with("token required")
+        {
+            return Self::InvalidToken { code, message };
+        }
+        match code {
+            403 => return Self::PermissionDenied { message, details },
+            404 => return Self::NotFound { message, details },
+            _ => {}
+        }
+        if text.contains("transfer limit") || text.contains("size limit") {
+            return Self::TransferLimitExceeded {
+                code,
+                message,
+                details,
+            };
+        }
+        if code == 400
+            && ["invalid", "missing", "parameter"]
+                .iter()
+                .any(|word| text.contains(word))
+        {
+            return Self::InvalidParameter { message, details };
+        }
+
+        let message = if details.is_empty() {
+            message
+        } else {
+            format!("{}{}", message, Details(&details))
+        };
+        Self::Api { code, message }
+    }
+
+    /// Returns the ESRI or HTTP error code, if the error came from the server.
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Self::Api { code, .. }
+            | Self::InvalidToken { code, .. }
+            | Self::TransferLimitExceeded { code, .. } => Some(*code),
+            Self::PermissionDenied { .. } => Some(403),
+            Self::NotFound { .. } => Some(404),
+            Self::InvalidParameter { .. } => Some(400),
+            _ => None,
+        }
+    }
+
+    /// Replaces credential values in messages and request URLs with `[REDACTED]`.
+    ///
+    /// Applied to every error on creation, so tokens never reach logs or
+    /// callers through error messages.
+    fn redacted(self) -> Self {
+        match self {
+            Self::Http(mut e) => {
+                if let Some(url) = e.source.url_mut() {
+                    if let Some(query) = url.query() {
+                        if let Cow::Owned(redacted) = redact_tokens(query) {
+                            url.set_query(Some(&redacted));
+                        }
+                    }
+                }
+                Self::Http(e)
+            }
+            Self::Auth(message) => Self::Auth(redact_owned(message)),
+            Self::Api { code, message } => Self::Api {
+                code,
+                message: redact_owned(message),
+            },
+            Self::InvalidToken { code, message } => Self::InvalidToken {
+                code,
+                message: redact_owned(message),
+            },
+            Self::PermissionDenied { message, details } => Self::PermissionDenied {
+                message: redact_owned(message),
+                details: details.into_iter().map(redact_owned).collect(),
+            },
+            Self::NotFound { message, details } => Self::NotFound {
+                message: redact_owned(message),
+                details: details.into_iter().map(redact_owned).collect(),
+            },
+            Self::TransferLimitExceeded {
+                code,
+                message,
+                details,
+            } => Self::TransferLimitExceeded {
+                code,
+                message: redact_owned(message),
+                details: details.into_iter().map(redact_owned).collect(),
+            },
+            Self::InvalidParameter { message, details } => Self::InvalidParameter {
+                message: redact_owned(message),
+                details: details.into_iter().map(redact_owned).collect(),
+            },
+            Self::OAuth(message) => Self::OAuth(redact_owned(message)),
+            Self::Validation(message) => Self::Validation(redact_owned(message)),
+            Self::Other(message) => Self::Other(redact_owned(message)),
+            other => other,
+        }
+    }
+}
+
+/// Formats error details as ` (details: a; b)`, or nothing when empty.
+struct Details<'a>(&'a [String]);
+
+impl std::fmt::Display for Details<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            write!(f, " (details: {})", self.0.join("; "))
+        }
+    }
+}
+
+/// Formats edit failures as a `; `-separated list.
+struct EditFailures<'a>(&'a [EditFailure]);
+
+impl std::fmt::Display for EditFailures<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, failure) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", failure)?;
+        }
+        Ok(())
+    }
+}
+
+/// Redacts credentials in an owned message, reusing it when nothing changes.
+fn redact_owned(message: String) -> String {
+    match redact_tokens(&message) {
+        Cow::Borrowed(_) => message,
+        Cow::Owned(redacted) => redacted,
+    }
+}
+
 /// Macro to generate bridge From implementations for external errors.
 ///
 /// This creates the conversion chain: ExternalError → WrapperError → ErrorKind → Error
@@ -367,7 +619,7 @@ impl Error {
 /// // impl From<reqwest::Error> for Error {
 /// //     #[track_caller]
 /// //     fn from(err: reqwest::Error) -> Self {
-/// //         let kind = ErrorKind::from(err);
+/// //         let kind = ErrorKind::from(err).redacted();
 /// //         tracing::error!(error_kind = %kind, "Error created");
 /// //         Self(Box::new(kind))
 /// //     }
@@ -378,7 +630,7 @@ macro_rules! error_from {
         impl From<$source> for Error {
             #[track_caller]
             fn from(err: $source) -> Self {
-                let kind = ErrorKind::from(err);
+                let kind = ErrorKind::from(err).redacted();
                 tracing::error!(error_kind = %kind, "Error created");
                 Self(Box::new(kind))
             }
@@ -390,6 +642,7 @@ macro_rules! error_from {
 impl From<ErrorKind> for Error {
     #[track_caller]
     fn from(kind: ErrorKind) -> Self {
+        let kind = kind.redacted();
         tracing::error!(error_kind = %kind, "Error created");
         Self(Box::new(kind))
     }
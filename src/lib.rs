@@ -51,6 +51,7 @@
 // Re-export major dependencies for user convenience
 pub use geo_types;
 pub use geojson;
+pub use reqwest;
 
 // Core modules
 mod auth;
@@ -58,16 +59,33 @@ mod client;
 mod config;
 mod error;
 mod geometry;
+mod profile;
 mod services;
 mod types;
 mod util;
 
+// Blocking (synchronous) clients
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 // Example tracking (for testing/verification)
 pub mod example_tracker;
 
 // Re-exports
-pub use auth::{ApiKeyAuth, ApiKeyTier, AuthProvider, ClientCredentialsAuth, NoAuth};
-pub use client::ArcGISClient;
+pub use auth::{
+    ApiKeyAuth, ApiKeyTier, AuthProvider, AuthRouter, AuthRouterBuilder, AuthorizationCodeAuth,
+    AuthorizationCodeAuthBuilder, AuthorizeUrlHandler, ClientCredentialsAuth,
+    ClientCredentialsAuthBuilder, FederatedServerAuth, FileTokenStore, MemoryTokenStore, NoAuth,
+    PersistedToken, TokenClient, TokenKey, TokenStore, UsernamePasswordAuth,
+    UsernamePasswordAuthBuilder,
+};
+pub use client::{
+    ArcGISClient, ArcGISClientBuilder, CASSETTE_MODE_ENV, CacheStore, CachedResponse, CassetteMode,
+    CassetteTransport, CreditMeter, CreditUsage, DiskCacheStore, ESRI_AUTHORIZATION_HEADER,
+    HttpConfig, HttpTransport, MemoryCacheStore, MemoryMetricsRecorder, MetricsRecorder,
+    Middleware, RateLimit, RateLimiter, RequestContext, RequestMetrics, ResponseCache, RetryPolicy,
+    RetryPolicyBuilder, ServiceRequest, TokenPlacement,
+};
 pub use config::EnvConfig;
 pub use error::{
     BuilderError, EnvError, Error, ErrorKind, HttpError, IoError, JsonError, UrlEncodedError,
@@ -80,24 +98,27 @@ pub use geometry::{
     SpatialReference, SpatialRel, StatePlanePoint, WashingtonNorth, WashingtonNorthMeters,
     WebMercatorPoint, Wgs84Point,
 };
+pub use profile::{Profile, ProfileAuth, ProfileKeys, Profiles};
 pub use services::{
     AddAttachmentResult, AddItemParams, AddItemResult, AddToDefinitionParams,
     AddToDefinitionResult, AddedLayerInfo, AddressCandidate, AlterResponse, AlterVersionParams,
     AreaUnit, AreasAndLengthsParameters, AreasAndLengthsParametersBuilder, AreasAndLengthsResult,
-    AttachmentInfo, AttachmentInfosResponse, AttachmentSource, BarrierType, BatchGeocodeRecord,
-    BatchGeocodeResponse, BatchLocation, BufferParameters, BufferParametersBuilder, BufferResult,
-    CalculateResult, CalculationType, CategoriesResult, Category, CategoryInfo, ClassBreakInfo,
-    ClosestFacilityParameters, ClosestFacilityParametersBuilder, ClosestFacilityResult, CodedValue,
-    CodedValueCode, CodedValueDomain, CodedValueDomainBuilder, ConflictDetection, ConflictEntry,
-    ConflictFeature, ConflictsResponse, CreateGroupParams, CreateServiceParams,
-    CreateServiceResult, CreateVersionParams, CreateVersionResponse, CurbApproach, DayHours,
-    DeleteAttachmentResult, DeleteAttachmentsResponse, DeleteForwardEditsResponse,
-    DeleteItemResult, DeleteResponse, DeleteServiceResult, DemResolution, DifferenceFeature,
-    DifferenceResultType, DifferencesResponse, DirectionsLength, DirectionsStyle,
-    DirectionsTimeAttribute, DistanceParameters, DistanceParametersBuilder, DistanceResult, Domain,
-    DomainCodedValue, DownloadResult, DownloadTarget, DrawingTool, EditError, EditFieldsInfo,
-    EditFieldsInfoBuilder, EditOptions, EditResult, EditResultItem, EditSessionError,
-    EditorTrackingInfo, ElevationClient, ElevationPoint, ExportExtent, ExportImageParameters,
+    AttachmentInfo, AttachmentInfosResponse, AttachmentSource, AuthInfo, BarrierType,
+    BatchGeocodeRecord, BatchGeocodeResponse, BatchLocation, BufferParameters,
+    BufferParametersBuilder, BufferResult, CalculateResult, CalculationType, CategoriesResult,
+    Category, CategoryInfo, ClassBreakInfo, ClosestFacilityParameters,
+    ClosestFacilityParametersBuilder, ClosestFacilityResult, CodedValue, CodedValueCode,
+    CodedValueDomain, CodedValueDomainBuilder, ConflictDetection, ConflictEntry, ConflictFeature,
+    ConflictsResponse, CreateGroupParams, CreateServiceParams, CreateServiceResult,
+    CreateVersionParams, CreateVersionResponse, CurbApproach, DayHours, DeleteAttachmentResult,
+    DeleteAttachmentsResponse, DeleteForwardEditsResponse, DeleteItemResult, DeleteResponse,
+    DeleteServiceResult, DemResolution, DifferenceFeature, DifferenceResultType,
+    DifferencesResponse, DirectionsLength, DirectionsStyle, DirectionsTimeAttribute,
+    DirectoryFolder, DirectoryLayer, DirectoryListing, DirectoryService, DistanceParameters,
+    DistanceParametersBuilder, DistanceResult, Domain, DomainCodedValue, DownloadResult,
+    DownloadTarget, DrawingTool, EditError, EditFailure, EditFieldsInfo, EditFieldsInfoBuilder,
+    EditOperation, EditOptions, EditResult, EditResultItem, EditSessionError, EditorTrackingInfo,
+    ElevationClient, ElevationPoint, ExportExtent, ExportImageParameters,
     ExportImageParametersBuilder, ExportImageResult, ExportMapBuilder, ExportMapParams,
     ExportMapParamsBuilder, ExportMapResponse, ExportResult, ExportTarget, Extent, Feature,
     FeatureQueryParams, FeatureQueryParamsBuilder, FeatureServiceClient, FeatureSet,
@@ -132,10 +153,11 @@ pub use services::{
     RelationshipsResponse, RendererResponse, RenderingRule, ResponseFormat, RestoreRowsLayer,
     RestoreRowsResponse, RestrictionAttribute, ReverseGeocodeResponse, RouteParameters,
     RouteParametersBuilder, RouteResult, RouteShape, RoutingServiceClient, SampleParameters,
-    SampleParametersBuilder, SampleResult, SearchParameters, SearchResult, ServiceAreaParameters,
-    ServiceAreaParametersBuilder, ServiceAreaResult, ServiceDefinition, ServiceDefinitionBuilder,
-    ServiceDefinitionValidationError, ServiceLayer, SessionId, ShareItemResult, SharingParameters,
-    SimplifyParameters, SimplifyParametersBuilder, SimplifyResult, SortOrder,
+    SampleParametersBuilder, SampleResult, SearchParameters, SearchResult, ServerInfo,
+    ServiceAreaParameters, ServiceAreaParametersBuilder, ServiceAreaResult, ServiceCapabilities,
+    ServiceClient, ServiceDefinition, ServiceDefinitionBuilder, ServiceDefinitionValidationError,
+    ServiceEntry, ServiceLayer, ServiceType, ServicesDirectoryClient, SessionId, ShareItemResult,
+    SharingParameters, SimplifyParameters, SimplifyParametersBuilder, SimplifyResult, SortOrder,
     SpatialReferenceDefinition, SplitPolicy, StartEditingResponse, StartReadingResponse,
     StatisticDefinition, StatisticType, StopEditingResponse, StopReadingResponse, Subtype,
     SuggestResponse, Suggestion, SummarizeElevationParameters, SummarizeElevationParametersBuilder,
@@ -149,8 +171,8 @@ pub use services::{
     VersionInfo, VersionInfosResponse, VersionManagementClient, VersionPermission, VersioningType,
     ViewshedParameters, ViewshedParametersBuilder, ViewshedResult,
 };
-pub use types::{AttachmentId, LayerId, ObjectId};
-pub use util::check_esri_error;
+pub use types::{AttachmentId, LayerId, ObjectId, ServiceKind};
+pub use util::{check_esri_error, redact_tokens};
 
 /// Result type alias using this crate's [`Error`] type.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,415 @@
+//! Named connection profiles.
+//!
+//! [`EnvConfig`] reads a fixed set of environment variables, which makes it
+//! awkward to switch between portals. Profiles describe each connection
+//! (portal, authentication, API keys, and service URLs) in a TOML file, one
+//! table per profile:
+//!
+//! ```toml
+//! [dev]
+//! auth = "api_key"
+//! api_key = "AAPK..."
+//!
+//! [prod]
+//! portal_url = "https://gis.example.com/portal"
+//! auth = "client_credentials"
+//! client_id = "abc123"
+//! client_secret = "..."
+//!
+//! [prod.keys]
+//! location = "AAPK..."
+//!
+//! [prod.services]
+//! feature = "https://gis.example.com/server/rest/services/Assets/FeatureServer"
+//! ```
+//!
+//! The file is read from `ARCGIS_PROFILE_FILE`, or from
+//! `~/.config/arcgis/profiles.toml` by default. Environment variables read by
+//! [`EnvConfig`] override the values of the selected profile, so secrets can
+//! stay out of the file.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::{ArcGISClient, FeatureServiceClient, Profile};
+//!
+//! # fn example() -> arcgis::Result<()> {
+//! let client = ArcGISClient::from_profile("prod")?;
+//!
+//! // Service URLs are available from the profile itself
+//! let profile = Profile::load("prod")?;
+//! if let Some(url) = profile.service_url("feature") {
+//!     let service = FeatureServiceClient::new(url, &client);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    ApiKeyAuth, ApiKeyTier, ArcGISClient, AuthProvider, AuthRouter, AuthorizationCodeAuth,
+    ClientCredentialsAuth, EnvConfig, NoAuth, Result, UsernamePasswordAuth,
+};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracing::instrument;
+
+/// Authentication method of a [`Profile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileAuth {
+    /// No authentication ([`NoAuth`]).
+    #[default]
+    None,
+    /// API key from `api_key` ([`ApiKeyAuth`]).
+    ApiKey,
+    /// OAuth client credentials from `client_id` and `client_secret`
+    /// ([`ClientCredentialsAuth`]).
+    ClientCredentials,
+    /// Portal user from `username` and `password` ([`UsernamePasswordAuth`]).
+    UsernamePassword,
+    /// Interactive sign-in for `client_id` ([`AuthorizationCodeAuth`]).
+    AuthorizationCode,
+}
+
+/// API keys of a [`Profile`], per [`ApiKeyTier`].
+///
+/// Requests to services in a tier with a key use that key, regardless of
+/// the profile's authentication method; see [`ApiKeyTier::for_service`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProfileKeys {
+    /// Key for public services.
+    pub public: Option<SecretString>,
+    /// Key for location services.
+    pub location: Option<SecretString>,
+    /// Key for content management.
+    pub content: Option<SecretString>,
+    /// Key for feature editing.
+    pub features: Option<SecretString>,
+}
+
+impl ProfileKeys {
+    /// Returns the key for `tier`, if set.
+    pub fn get(&self, tier: ApiKeyTier) -> Option<&SecretString> {
+        match tier {
+            ApiKeyTier::Public => self.public.as_ref(),
+            ApiKeyTier::Location => self.location.as_ref(),
+            ApiKeyTier::Content => self.content.as_ref(),
+            ApiKeyTier::Features => self.features.as_ref(),
+        }
+    }
+}
+
+/// A named connection: portal, authentication, API keys, and service URLs.
+///
+/// Load one with [`Profile::load`], or create a client directly with
+/// [`ArcGISClient::from_profile`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    /// Name of the profile in the profiles file.
+    #[serde(skip)]
+    pub name: String,
+    /// Portal URL, e.g. `https://gis.example.com/portal`. Defaults to ArcGIS Online.
+    pub portal_url: Option<String>,
+    /// Authentication method.
+    pub auth: ProfileAuth,
+    /// API key for [`ProfileAuth::ApiKey`].
+    pub api_key: Option<SecretString>,
+    /// OAuth client ID for [`ProfileAuth::ClientCredentials`] and
+    /// [`ProfileAuth::AuthorizationCode`].
+    pub client_id: Option<String>,
+    /// OAuth client secret for [`ProfileAuth::ClientCredentials`].
+    pub client_secret: Option<SecretString>,
+    /// Portal username for [`ProfileAuth::UsernamePassword`].
+    pub username: Option<String>,
+    /// Portal password for [`ProfileAuth::UsernamePassword`].
+    pub password: Option<SecretString>,
+    /// Loopback port for [`ProfileAuth::AuthorizationCode`] redirects.
+    pub redirect_port: Option<u16>,
+    /// API keys per tier.
+    pub keys: ProfileKeys,
+    /// Service URLs by name, e.g. `feature`.
+    pub services: BTreeMap<String, String>,
+}
+
+impl Profile {
+    /// Loads a profile from the default profiles file, with environment
+    /// overrides applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or has no
+    /// profile called `name`.
+    #[instrument]
+    pub fn load(name: &str) -> Result<Self> {
+        let profile = Profiles::load()?.get(name)?;
+        Ok(profile.with_env_overrides(EnvConfig::global()))
+    }
+
+    /// Overrides profile values with those set in `env`.
+    ///
+    /// - `ARCGIS_ENTERPRISE_PORTAL` overrides `portal_url`
+    /// - `ARCGIS_API_KEY` overrides `api_key`
+    /// - `ARCGIS_CLIENT_ID` and `ARCGIS_CLIENT_SECRET` override the OAuth client
+    /// - `ARCGIS_USERNAME` and `ARCGIS_PASSWORD` override the portal user
+    /// - `ARCGIS_<TIER>_KEY` overrides the key of each tier
+    /// - `ARCGIS_FEATURE_URL` overrides the `feature` service URL
+    pub fn with_env_overrides(mut self, env: &EnvConfig) -> Self {
+        fn set<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                target.clone_from(value);
+            }
+        }
+
+        set(&mut self.portal_url, &env.arcgis_enterprise_portal);
+        set(&mut self.api_key, &env.arcgis_api_key);
+        if let Some(client_id) = &env.arcgis_client_id {
+            self.client_id = Some(client_id.expose_secret().to_string());
+        }
+        set(&mut self.client_secret, &env.arcgis_client_secret);
+        set(&mut self.username, &env.arcgis_username);
+        set(&mut self.password, &env.arcgis_password);
+        set(&mut self.keys.public, &env.arcgis_public_key);
+        set(&mut self.keys.location, &env.arcgis_location_key);
+        set(&mut self.keys.content, &env.arcgis_content_key);
+        set(&mut self.keys.features, &env.arcgis_features_key);
+        if let Some(url) = &env.arcgis_feature_url {
+            self.services.insert("feature".to_string(), url.clone());
+        }
+        self
+    }
+
+    /// Returns the service URL registered under `name`, if any.
+    pub fn service_url(&self, name: &str) -> Option<&str> {
+        self.services.get(name).map(String::as_str)
+    }
+
+    /// Creates the authentication provider the profile describes.
+    ///
+    /// When tier keys are set, requests to services in those tiers use the
+    /// tier key and all other requests use the profile's method, via an
+    /// [`AuthRouter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value the authentication method needs is
+    /// missing, or the provider cannot be created.
+    #[instrument(skip(self), fields(profile = %self.name, auth = ?self.auth))]
+    pub fn auth_provider(&self) -> Result<Box<dyn AuthProvider>> {
+        let base: Box<dyn AuthProvider> = match self.auth {
+            ProfileAuth::None => Box::new(NoAuth),
+            ProfileAuth::ApiKey => {
+                let api_key = self.require(&self.api_key, "api_key")?;
+                Box::new(ApiKeyAuth::new(api_key.expose_secret()))
+            }
+            ProfileAuth::ClientCredentials => {
+                let client_id = self.require(&self.client_id, "client_id")?;
+                let client_secret = self.require(&self.client_secret, "client_secret")?;
+                let mut builder =
+                    ClientCredentialsAuth::builder(client_id, client_secret.expose_secret());
+                if let Some(portal_url) = &self.portal_url {
+                    builder = builder.portal_url(portal_url);
+                }
+                Box::new(builder.build()?)
+            }
+            ProfileAuth::UsernamePassword => {
+                let username = self.require(&self.username, "username")?;
+                let password = self.require(&self.password, "password")?;
+                let mut builder = UsernamePasswordAuth::builder(username, password.expose_secret());
+                if let Some(portal_url) = &self.portal_url {
+                    builder = builder.portal_url(portal_url);
+                }
+                Box::new(builder.build()?)
+            }
+            ProfileAuth::AuthorizationCode => {
+                let client_id = self.require(&self.client_id, "client_id")?;
+                let mut builder = AuthorizationCodeAuth::builder(client_id);
+                if let Some(portal_url) = &self.portal_url {
+                    builder = builder.portal_url(portal_url);
+                }
+                if let Some(port) = self.redirect_port {
+                    builder = builder.redirect_port(port);
+                }
+                Box::new(builder.build()?)
+            }
+        };
+
+        let tiers = [
+            ApiKeyTier::Public,
+            ApiKeyTier::Location,
+            ApiKeyTier::Content,
+            ApiKeyTier::Features,
+        ];
+        let keyed: Vec<_> = tiers
+            .into_iter()
+            .filter_map(|tier| self.keys.get(tier).map(|key| (tier, key)))
+            .collect();
+        if keyed.is_empty() {
+            return Ok(base);
+        }
+
+        tracing::debug!(tiers = keyed.len(), "Routing tier keys");
+        let router = keyed
+            .into_iter()
+            .fold(AuthRouter::builder(base), |router, (tier, key)| {
+                router.api_key_tier(tier, ApiKeyAuth::new(key.expose_secret()))
+            });
+        Ok(Box::new(router.build()))
+    }
+
+    /// Creates a client authenticated as the profile describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the authentication provider cannot be created.
+    pub fn client(&self) -> Result<ArcGISClient> {
+        Ok(ArcGISClient::new(self.auth_provider()?))
+    }
+
+    /// Returns a required value, or an error naming the missing field.
+    fn require<'a, T>(&self, value: &'a Option<T>, field: &str) -> Result<&'a T> {
+        value.as_ref().ok_or_else(|| {
+            tracing::error!(profile = %self.name, field, "Profile is missing a required value");
+            crate::ErrorKind::Profile(format!(
+                "profile '{}' uses {:?} authentication but does not set {}",
+                self.name, self.auth, field
+            ))
+            .into()
+        })
+    }
+}
+
+/// The profiles of a profiles file, by name.
+///
+/// # Example
+///
+/// ```
+/// use arcgis::{ProfileAuth, Profiles};
+///
+/// let profiles: Profiles = r#"
+///     [dev]
+///     auth = "api_key"
+///     api_key = "dev-key"
+///
+///     [prod]
+///     portal_url = "https://gis.example.com/portal"
+/// "#
+/// .parse()?;
+///
+/// assert_eq!(profiles.names().collect::<Vec<_>>(), ["dev", "prod"]);
+/// assert_eq!(profiles.get("dev")?.auth, ProfileAuth::ApiKey);
+/// # Ok::<(), arcgis::Error>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Profiles {
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl Profiles {
+    /// Returns the path of the profiles file.
+    ///
+    /// Uses `ARCGIS_PROFILE_FILE` if set, otherwise
+    /// `$XDG_CONFIG_HOME/arcgis/profiles.toml`, `~/.config/arcgis/profiles.toml`,
+    /// or `%APPDATA%\arcgis\profiles.toml` on Windows.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = &EnvConfig::global().arcgis_profile_file {
+            return Some(PathBuf::from(path));
+        }
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+            .map(|dir| dir.join("arcgis").join("profiles.toml"))
+    }
+
+    /// Loads the profiles file at [`default_path`](Self::default_path).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no location is configured, or the file cannot be
+    /// read or parsed.
+    #[instrument]
+    pub fn load() -> Result<Self> {
+        let path = Self::default_path().ok_or_else(|| {
+            crate::Error::from(crate::ErrorKind::Profile(
+                "no profiles file location; set ARCGIS_PROFILE_FILE".to_string(),
+            ))
+        })?;
+        Self::from_path(path)
+    }
+
+    /// Loads a profiles file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    #[instrument(skip(path), fields(path = %path.as_ref().display()))]
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        tracing::debug!("Loading connection profiles");
+
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            tracing::error!(error = %e, "Failed to read profiles file");
+            crate::Error::from(crate::ErrorKind::Profile(format!(
+                "cannot read {}: {}",
+                path.display(),
+                e
+            )))
+        })?;
+        Self::parse(&contents).map_err(|message| {
+            tracing::error!(error = %message, "Failed to parse profiles file");
+            crate::ErrorKind::Profile(format!("{}: {}", path.display(), message)).into()
+        })
+    }
+
+    /// Returns the profile called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such profile.
+    pub fn get(&self, name: &str) -> Result<Profile> {
+        self.profiles.get(name).cloned().ok_or_else(|| {
+            let available = self.names().collect::<Vec<_>>().join(", ");
+            crate::ErrorKind::Profile(format!(
+                "no profile named '{}' (available: {})",
+                name, available
+            ))
+            .into()
+        })
+    }
+
+    /// Returns the profile names, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    /// Parses profiles from TOML.
+    ///
+    /// Errors report the message and line only, never the offending line
+    /// itself, so secrets in the file are not echoed.
+    fn parse(s: &str) -> std::result::Result<Self, String> {
+        let mut profiles: BTreeMap<String, Profile> = toml::from_str(s).map_err(|e| {
+            let location = e
+                .span()
+                .map(|span| format!(" at line {}", s[..span.start].matches('\n').count() + 1))
+                .unwrap_or_default();
+            format!("invalid profiles{}: {}", location, e.message())
+        })?;
+        for (name, profile) in &mut profiles {
+            profile.name.clone_from(name);
+        }
+        Ok(Self { profiles })
+    }
+}
+
+impl std::str::FromStr for Profiles {
+    type Err = crate::Error;
+
+    /// Parses profiles from TOML.
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s).map_err(|message| crate::ErrorKind::Profile(message).into())
+    }
+}
@@ -0,0 +1,212 @@
+//! Services directory client implementation.
+
+use super::types::{
+    DirectoryFolder, DirectoryListing, DirectoryService, ServiceEntry, ServiceRoot,
+};
+use crate::{ArcGISClient, Result, ServiceRequest};
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+use tracing::instrument;
+
+/// Number of service roots fetched concurrently while crawling.
+const CONCURRENT_REQUESTS: usize = 8;
+
+/// Client for the ArcGIS Server REST services directory.
+///
+/// Walks `/rest/services`, its folders, and each service's root JSON to
+/// discover what a server hosts. The result is a tree of
+/// [`DirectoryFolder`]s and [`DirectoryService`]s with their layers; each
+/// service can hand out the matching service client.
+///
+/// # Example
+///
+/// ```no_run
+/// use arcgis::{ArcGISClient, NoAuth, ServiceType, ServicesDirectoryClient};
+///
+/// # async fn example() -> arcgis::Result<()> {
+/// let client = ArcGISClient::new(NoAuth);
+/// let directory = ServicesDirectoryClient::new(
+///     "https://sampleserver6.arcgisonline.com/arcgis/rest/services",
+///     &client,
+/// );
+///
+/// let root = directory.crawl().await?;
+/// for service in root.all_services() {
+///     println!("{} ({}): {} layers", service.name(), service.service_type(), service.layers().len());
+/// }
+///
+/// if let Some(service) = root.find_service("Wildfire", &ServiceType::FeatureServer) {
+///     let features = service.feature_client(&client);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ServicesDirectoryClient {
+    /// Services root URL (e.g. `https://host/arcgis/rest/services`).
+    url: String,
+
+    /// Shared ArcGIS client.
+    client: ArcGISClient,
+}
+
+impl ServicesDirectoryClient {
+    /// Creates a new services directory client.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Services root URL (e.g., `https://host/arcgis/rest/services`)
+    /// * `client` - Reference to an [`ArcGISClient`] for making requests
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
+        let url: String = url.into();
+        ServicesDirectoryClient {
+            url: url.trim_end_matches('/').to_string(),
+            client: client.clone(),
+        }
+    }
+
+    /// Returns the services root URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the URL of a folder, or of the services root for `None`.
+    fn folder_url(&self, folder: Option<&str>) -> String {
+        match folder {
+            Some(folder) if !folder.is_empty() => format!("{}/{}", self.url, folder),
+            _ => self.url.clone(),
+        }
+    }
+
+    /// Lists the folders and services at one level of the directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder` - Folder name, or `None` for the services root
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the folder does not exist.
+    #[instrument(skip(self), fields(url = %self.url))]
+    pub async fn list(&self, folder: Option<&str>) -> Result<DirectoryListing> {
+        tracing::debug!("Listing services directory");
+
+        let url = self.folder_url(folder);
+        let request = ServiceRequest::get(&url)
+            .operation("listServices")
+            .query(&[("f", "json")])
+            .cacheable();
+
+        let listing: DirectoryListing = self.client.execute_json(request).await?;
+
+        tracing::debug!(
+            folders = listing.folders().len(),
+            services = listing.services().len(),
+            "Services directory listed"
+        );
+
+        Ok(listing)
+    }
+
+    /// Reads a service's root JSON for its description, layers, tables, and tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service root cannot be read, e.g. because the
+    /// service is stopped or requires a token.
+    #[instrument(skip(self, entry), fields(name = %entry.name(), service_type = %entry.service_type()))]
+    pub async fn service(&self, entry: &ServiceEntry) -> Result<DirectoryService> {
+        tracing::debug!("Reading service root");
+
+        let service = DirectoryService::new(&self.url, entry.clone());
+        let request = ServiceRequest::get(service.url())
+            .operation("serviceInfo")
+            .query(&[("f", "json")])
+            .cacheable();
+
+        let root: ServiceRoot = self.client.execute_json(request).await?;
+        Ok(service.with_root(root))
+    }
+
+    /// Walks the whole directory: every folder and every service's root JSON.
+    ///
+    /// Services whose root cannot be read and folders that cannot be listed
+    /// are kept in the tree with their [`error`](DirectoryService::error)
+    /// set, so one secured or stopped service does not hide the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the services root itself cannot be listed.
+    #[instrument(skip(self), fields(url = %self.url))]
+    pub async fn crawl(&self) -> Result<DirectoryFolder> {
+        tracing::debug!("Crawling services directory");
+
+        let listing = self.list(None).await?;
+        let root = self.crawl_listing(String::new(), listing).await;
+
+        tracing::info!(
+            services = root.all_services().len(),
+            folders = root.folders().len(),
+            "Services directory crawled"
+        );
+
+        Ok(root)
+    }
+
+    /// Walks one folder and its subfolders.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the folder itself cannot be listed.
+    #[instrument(skip(self), fields(url = %self.url))]
+    pub async fn crawl_folder(&self, folder: &str) -> Result<DirectoryFolder> {
+        tracing::debug!("Crawling services folder");
+
+        let listing = self.list(Some(folder)).await?;
+        Ok(self.crawl_listing(folder.to_string(), listing).await)
+    }
+
+    /// Builds the folder for a listing, reading service roots and recursing
+    /// into subfolders.
+    fn crawl_listing(
+        &self,
+        name: String,
+        listing: DirectoryListing,
+    ) -> BoxFuture<'_, DirectoryFolder> {
+        async move {
+            let url = self.folder_url(Some(&name));
+            let current_version = *listing.current_version();
+
+            let services = futures::stream::iter(listing.services().iter().cloned())
+                .map(|entry| async move {
+                    match self.service(&entry).await {
+                        Ok(service) => service,
+                        Err(e) => {
+                            tracing::warn!(name = %entry.name(), error = %e, "Failed to read service root");
+                            DirectoryService::new(&self.url, entry).with_error(&e)
+                        }
+                    }
+                })
+                .buffered(CONCURRENT_REQUESTS)
+                .collect::<Vec<_>>()
+                .await;
+
+            let mut folders = Vec::new();
+            for folder in listing.folders() {
+                let folder_name = folder.clone();
+                let child = match self.list(Some(&folder_name)).await {
+                    Ok(child) => self.crawl_listing(folder_name, child).await,
+                    Err(e) => {
+                        tracing::warn!(folder = %folder_name, error = %e, "Failed to list folder");
+                        let url = self.folder_url(Some(&folder_name));
+                        DirectoryFolder::new(folder_name, url).with_error(&e)
+                    }
+                };
+                folders.push(child);
+            }
+
+            DirectoryFolder::new(name, url).with_contents(current_version, folders, services)
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,46 @@
+//! Services Directory.
+//!
+//! The ArcGIS Server REST services directory (`/rest/services`) lists the
+//! folders and services a server hosts. This module crawls it into a typed
+//! tree and hands out the matching service client for each service.
+//!
+//! # Operations
+//!
+//! - **List**: Read one level of the directory (services root or a folder)
+//! - **Service**: Read a service's root JSON (description, layers, tables, tasks)
+//! - **Crawl**: Walk every folder and service into a [`DirectoryFolder`] tree
+//!
+//! # Example
+//!
+//! ```no_run
+//! use arcgis::{ArcGISClient, NoAuth, ServiceClient, ServicesDirectoryClient};
+//!
+//! # async fn example() -> arcgis::Result<()> {
+//! let client = ArcGISClient::new(NoAuth);
+//! let directory = ServicesDirectoryClient::new(
+//!     "https://sampleserver6.arcgisonline.com/arcgis/rest/services",
+//!     &client,
+//! );
+//!
+//! let root = directory.crawl().await?;
+//! for service in root.all_services() {
+//!     match service.client(&client) {
+//!         Some(ServiceClient::Feature(_)) => {
+//!             tracing::info!(name = %service.name(), layers = service.layers().len(), "Feature service");
+//!         }
+//!         Some(_) => tracing::info!(name = %service.name(), "Other service"),
+//!         None => tracing::info!(name = %service.name(), "No client available"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod client;
+mod types;
+
+pub use client::ServicesDirectoryClient;
+pub use types::{
+    DirectoryFolder, DirectoryLayer, DirectoryListing, DirectoryService, ServiceClient,
+    ServiceEntry, ServiceType,
+};
@@ -0,0 +1,417 @@
+//! Services directory types.
+
+use crate::{
+    ArcGISClient, FeatureServiceClient, GeocodeServiceClient, GeometryServiceClient,
+    GeoprocessingServiceClient, ImageServiceClient, LayerId, MapServiceClient,
+    RoutingServiceClient, VectorTileServiceClient,
+};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Type of an ArcGIS Server service, as named in the services directory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ServiceType {
+    /// Feature service (`FeatureServer`).
+    FeatureServer,
+    /// Map service (`MapServer`).
+    MapServer,
+    /// Image service (`ImageServer`).
+    ImageServer,
+    /// Geoprocessing service (`GPServer`).
+    GPServer,
+    /// Geocoding service (`GeocodeServer`).
+    GeocodeServer,
+    /// Geometry service (`GeometryServer`).
+    GeometryServer,
+    /// Network analysis (routing) service (`NAServer`).
+    NAServer,
+    /// Vector tile service (`VectorTileServer`).
+    VectorTileServer,
+    /// Scene service (`SceneServer`).
+    SceneServer,
+    /// Any other service type, e.g. `GlobeServer` or `StreamServer`.
+    Other(String),
+}
+
+impl ServiceType {
+    /// Returns the type as it appears in service URLs.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::FeatureServer => "FeatureServer",
+            Self::MapServer => "MapServer",
+            Self::ImageServer => "ImageServer",
+            Self::GPServer => "GPServer",
+            Self::GeocodeServer => "GeocodeServer",
+            Self::GeometryServer => "GeometryServer",
+            Self::NAServer => "NAServer",
+            Self::VectorTileServer => "VectorTileServer",
+            Self::SceneServer => "SceneServer",
+            Self::Other(other) => other,
+        }
+    }
+}
+
+impl From<String> for ServiceType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "FeatureServer" => Self::FeatureServer,
+            "MapServer" => Self::MapServer,
+            "ImageServer" => Self::ImageServer,
+            "GPServer" => Self::GPServer,
+            "GeocodeServer" => Self::GeocodeServer,
+            "GeometryServer" => Self::GeometryServer,
+            "NAServer" => Self::NAServer,
+            "VectorTileServer" => Self::VectorTileServer,
+            "SceneServer" => Self::SceneServer,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<ServiceType> for String {
+    fn from(value: ServiceType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One level of the services directory: `/rest/services` or a folder.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryListing {
+    /// ArcGIS Server version.
+    #[serde(default)]
+    current_version: Option<f64>,
+
+    /// Subfolder names, relative to the services root.
+    #[serde(default)]
+    folders: Vec<String>,
+
+    /// Services in this folder.
+    #[serde(default)]
+    services: Vec<ServiceEntry>,
+}
+
+/// A service as listed in the services directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct ServiceEntry {
+    /// Service name, prefixed with its folder (e.g. `Utilities/Geometry`).
+    name: String,
+
+    /// Service type.
+    #[serde(rename = "type")]
+    service_type: ServiceType,
+}
+
+/// Service root JSON, reduced to the fields the crawler reads.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ServiceRoot {
+    #[serde(default)]
+    pub(super) service_description: Option<String>,
+    #[serde(default)]
+    pub(super) description: Option<String>,
+    #[serde(default)]
+    pub(super) layers: Vec<DirectoryLayer>,
+    #[serde(default)]
+    pub(super) tables: Vec<DirectoryLayer>,
+    #[serde(default)]
+    pub(super) tasks: Vec<String>,
+}
+
+/// A layer or table of a service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryLayer {
+    /// Layer ID within the service.
+    id: LayerId,
+
+    /// Layer name.
+    name: String,
+
+    /// Layer type (e.g. `Feature Layer`, `Group Layer`), if reported.
+    #[serde(default, rename = "type")]
+    layer_type: Option<String>,
+
+    /// Geometry type (e.g. `esriGeometryPolygon`), if reported.
+    #[serde(default)]
+    geometry_type: Option<String>,
+
+    /// ID of the group layer containing this layer; `-1` or absent for top-level layers.
+    #[serde(default)]
+    parent_layer_id: Option<i64>,
+
+    /// IDs of the layers in this group layer.
+    #[serde(default)]
+    sub_layer_ids: Option<Vec<LayerId>>,
+}
+
+/// A service in the directory tree, with its layers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+pub struct DirectoryService {
+    /// Service name, prefixed with its folder (e.g. `Utilities/Geometry`).
+    name: String,
+
+    /// Service type.
+    service_type: ServiceType,
+
+    /// Service URL (e.g. `https://host/arcgis/rest/services/Utilities/Geometry/GeometryServer`).
+    url: String,
+
+    /// Service description, if the service root reports one.
+    description: Option<String>,
+
+    /// Layers of feature, map, and scene services.
+    layers: Vec<DirectoryLayer>,
+
+    /// Tables of feature and map services.
+    tables: Vec<DirectoryLayer>,
+
+    /// Task names of geoprocessing services.
+    tasks: Vec<String>,
+
+    /// Why the service root could not be read, if it failed (e.g. a
+    /// stopped or secured service). Layers, tables, and tasks are empty then.
+    error: Option<String>,
+}
+
+impl DirectoryService {
+    /// Creates a service entry under the services root `root_url`, without details.
+    pub(super) fn new(root_url: &str, entry: ServiceEntry) -> Self {
+        let url = format!("{}/{}/{}", root_url, entry.name, entry.service_type);
+        Self {
+            name: entry.name,
+            service_type: entry.service_type,
+            url,
+            description: None,
+            layers: Vec::new(),
+            tables: Vec::new(),
+            tasks: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Fills in details from the service root JSON.
+    pub(super) fn with_root(mut self, root: ServiceRoot) -> Self {
+        self.description = [root.service_description, root.description]
+            .into_iter()
+            .flatten()
+            .find(|description| !description.trim().is_empty());
+        self.layers = root.layers;
+        self.tables = root.tables;
+        self.tasks = root.tasks;
+        self
+    }
+
+    /// Records that the service root could not be read.
+    pub(super) fn with_error(mut self, error: &crate::Error) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    /// Returns the service name without its folder.
+    pub fn short_name(&self) -> &str {
+        self.name.rsplit('/').next().unwrap_or(&self.name)
+    }
+
+    /// Returns the client matching this service's type, or `None` for
+    /// types this crate has no client for.
+    ///
+    /// Geoprocessing clients address a single task rather than the service,
+    /// so geoprocessing services return `None` here; use
+    /// [`geoprocessing_client`](Self::geoprocessing_client) instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, ServiceClient, ServicesDirectoryClient};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let directory = ServicesDirectoryClient::new(
+    ///     "https://sampleserver6.arcgisonline.com/arcgis/rest/services",
+    ///     &client,
+    /// );
+    ///
+    /// for service in directory.crawl().await?.all_services() {
+    ///     if let Some(ServiceClient::Map(map)) = service.client(&client) {
+    ///         let legend = map.get_legend().await?;
+    ///         println!("{}: {} legend layers", service.name(), legend.layers().len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client(&self, client: &ArcGISClient) -> Option<ServiceClient> {
+        let url = self.url.as_str();
+        let service = match self.service_type {
+            ServiceType::FeatureServer => {
+                ServiceClient::Feature(FeatureServiceClient::new(url, client))
+            }
+            ServiceType::MapServer => ServiceClient::Map(MapServiceClient::new(url, client)),
+            ServiceType::ImageServer => ServiceClient::Image(ImageServiceClient::new(url, client)),
+            ServiceType::GeocodeServer => {
+                ServiceClient::Geocode(GeocodeServiceClient::new(url, client))
+            }
+            ServiceType::GeometryServer => {
+                ServiceClient::Geometry(GeometryServiceClient::new(url, client))
+            }
+            ServiceType::NAServer => ServiceClient::Routing(RoutingServiceClient::new(url, client)),
+            ServiceType::VectorTileServer => {
+                ServiceClient::VectorTile(VectorTileServiceClient::new(url, client))
+            }
+            ServiceType::GPServer | ServiceType::SceneServer | ServiceType::Other(_) => {
+                return None;
+            }
+        };
+        Some(service)
+    }
+
+    /// Returns a [`FeatureServiceClient`] if this is a feature service.
+    pub fn feature_client(&self, client: &ArcGISClient) -> Option<FeatureServiceClient> {
+        match self.client(client)? {
+            ServiceClient::Feature(service) => Some(service),
+            _ => None,
+        }
+    }
+
+    /// Returns a [`MapServiceClient`] if this is a map service.
+    pub fn map_client(&self, client: &ArcGISClient) -> Option<MapServiceClient> {
+        match self.client(client)? {
+            ServiceClient::Map(service) => Some(service),
+            _ => None,
+        }
+    }
+
+    /// Returns an [`ImageServiceClient`] if this is an image service.
+    pub fn image_client(&self, client: &ArcGISClient) -> Option<ImageServiceClient> {
+        match self.client(client)? {
+            ServiceClient::Image(service) => Some(service),
+            _ => None,
+        }
+    }
+
+    /// Returns a [`GeoprocessingServiceClient`] for `task`, one of
+    /// [`tasks`](Self::tasks), if this is a geoprocessing service.
+    pub fn geoprocessing_client(
+        &self,
+        task: &str,
+        client: &ArcGISClient,
+    ) -> Option<GeoprocessingServiceClient> {
+        (self.service_type == ServiceType::GPServer)
+            .then(|| GeoprocessingServiceClient::new(format!("{}/{}", self.url, task), client))
+    }
+
+    /// Returns a [`VectorTileServiceClient`] if this is a vector tile service.
+    pub fn vector_tile_client(&self, client: &ArcGISClient) -> Option<VectorTileServiceClient> {
+        match self.client(client)? {
+            ServiceClient::VectorTile(service) => Some(service),
+            _ => None,
+        }
+    }
+}
+
+/// Client for a service found in the services directory.
+///
+/// Returned by [`DirectoryService::client`].
+#[derive(Clone)]
+pub enum ServiceClient {
+    /// Feature service client.
+    Feature(FeatureServiceClient),
+    /// Map service client.
+    Map(MapServiceClient),
+    /// Image service client.
+    Image(ImageServiceClient),
+    /// Geocoding service client.
+    Geocode(GeocodeServiceClient),
+    /// Geometry service client.
+    Geometry(GeometryServiceClient),
+    /// Routing service client.
+    Routing(RoutingServiceClient),
+    /// Vector tile service client.
+    VectorTile(VectorTileServiceClient),
+}
+
+/// A folder in the directory tree, with its services and subfolders.
+///
+/// The services root is a folder with an empty name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+pub struct DirectoryFolder {
+    /// Folder name, relative to the services root; empty for the root.
+    name: String,
+
+    /// Folder URL.
+    url: String,
+
+    /// ArcGIS Server version, as reported by this folder.
+    current_version: Option<f64>,
+
+    /// Subfolders.
+    folders: Vec<DirectoryFolder>,
+
+    /// Services in this folder.
+    services: Vec<DirectoryService>,
+
+    /// Why the folder could not be listed, if it failed (e.g. a secured
+    /// folder). Services and subfolders are empty then.
+    error: Option<String>,
+}
+
+impl DirectoryFolder {
+    /// Creates an empty folder.
+    pub(super) fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            current_version: None,
+            folders: Vec::new(),
+            services: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Fills in the folder contents.
+    pub(super) fn with_contents(
+        mut self,
+        current_version: Option<f64>,
+        folders: Vec<DirectoryFolder>,
+        services: Vec<DirectoryService>,
+    ) -> Self {
+        self.current_version = current_version;
+        self.folders = folders;
+        self.services = services;
+        self
+    }
+
+    /// Records that the folder could not be listed.
+    pub(super) fn with_error(mut self, error: &crate::Error) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    /// Returns every service in this folder and its subfolders, depth first.
+    pub fn all_services(&self) -> Vec<&DirectoryService> {
+        let mut services: Vec<&DirectoryService> = self.services.iter().collect();
+        for folder in &self.folders {
+            services.extend(folder.all_services());
+        }
+        services
+    }
+
+    /// Finds a service by its full name (e.g. `Utilities/Geometry`) and type.
+    pub fn find_service(
+        &self,
+        name: &str,
+        service_type: &ServiceType,
+    ) -> Option<&DirectoryService> {
+        self.all_services()
+            .into_iter()
+            .find(|service| service.name == name && service.service_type == *service_type)
+    }
+}
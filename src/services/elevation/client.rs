@@ -1,6 +1,6 @@
 //! Elevation service client implementation.
 
-use crate::{ArcGISClient, ErrorKind, FeatureSet, GPExecuteResult, Result};
+use crate::{ArcGISClient, ErrorKind, FeatureSet, GPExecuteResult, Result, ServiceRequest};
 use tracing::instrument;
 
 use super::types::{
@@ -36,15 +36,15 @@ use super::types::{
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct ElevationClient<'a> {
+pub struct ElevationClient {
     /// Base URL of the elevation service.
     url: String,
 
-    /// Reference to the ArcGIS client.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client.
+    client: ArcGISClient,
 }
 
-impl<'a> ElevationClient<'a> {
+impl ElevationClient {
     /// Creates a new elevation service client.
     ///
     /// # Arguments
@@ -62,11 +62,11 @@ impl<'a> ElevationClient<'a> {
     /// let client = ArcGISClient::new(auth);
     /// let elevation = ElevationClient::new(&client);
     /// ```
-    pub fn new(client: &'a ArcGISClient) -> Self {
+    pub fn new(client: &ArcGISClient) -> Self {
         ElevationClient {
             url: "https://elevation.arcgis.com/arcgis/rest/services/Tools/ElevationSync/GPServer"
                 .to_string(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -89,10 +89,10 @@ impl<'a> ElevationClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn with_url(url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn with_url(url: impl Into<String>, client: &ArcGISClient) -> Self {
         ElevationClient {
             url: url.into(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -141,29 +141,14 @@ impl<'a> ElevationClient<'a> {
 
         let profile_url = format!("{}/Profile/execute", self.url);
 
-        let mut request = self
-            .client
-            .http()
-            .get(&profile_url)
+        let request = ServiceRequest::get(&profile_url)
             .query(&[("f", "json")])
-            .query(&params);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+            .query(&params)
+            .billable(1);
 
         tracing::debug!(url = %profile_url, "Sending profile request");
 
-        let response = request.send().await?;
-        let response_body = response.text().await?;
-
-        tracing::debug!(
-            response_length = response_body.len(),
-            response_body = %response_body,
-            "Received profile response"
-        );
-
-        let gp_result: GPExecuteResult = serde_json::from_str(&response_body)?;
+        let gp_result: GPExecuteResult = self.client.execute_json(request).await?;
 
         tracing::debug!(
             result_count = gp_result.results().len(),
@@ -256,14 +241,14 @@ impl<'a> ElevationClient<'a> {
         // Create GP service client for async Elevation service
         let gp_service = crate::GeoprocessingServiceClient::new(
             "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/SummarizeElevation",
-            self.client,
+            &self.client,
         );
 
         // Convert params to HashMap
         let param_map = self.params_to_hashmap(&params)?;
 
         // Submit job
-        let job = gp_service.submit_job(param_map).await?;
+        let job = gp_service.submit_billable_job(param_map, 1).await?;
 
         tracing::info!(
             job_id = %job.job_id(),
@@ -316,7 +301,7 @@ impl<'a> ElevationClient<'a> {
 
         let gp_service = crate::GeoprocessingServiceClient::new(
             "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/SummarizeElevation",
-            self.client,
+            &self.client,
         );
 
         // Poll until complete
@@ -389,7 +374,7 @@ impl<'a> ElevationClient<'a> {
             // Create GP client to fetch the result
             let gp_service = crate::GeoprocessingServiceClient::new(
                 "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/SummarizeElevation",
-                self.client,
+                &self.client,
             );
 
             // Fetch the result data
@@ -481,14 +466,14 @@ impl<'a> ElevationClient<'a> {
         // Create GP service client for async Elevation service
         let gp_service = crate::GeoprocessingServiceClient::new(
             "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/Viewshed",
-            self.client,
+            &self.client,
         );
 
         // Convert params to HashMap
         let param_map = self.params_to_hashmap(&params)?;
 
         // Submit job
-        let job = gp_service.submit_job(param_map).await?;
+        let job = gp_service.submit_billable_job(param_map, 1).await?;
 
         tracing::info!(
             job_id = %job.job_id(),
@@ -542,7 +527,7 @@ impl<'a> ElevationClient<'a> {
 
         let gp_service = crate::GeoprocessingServiceClient::new(
             "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/Viewshed",
-            self.client,
+            &self.client,
         );
 
         // Poll until complete
@@ -588,7 +573,7 @@ impl<'a> ElevationClient<'a> {
             // Create GP client to fetch the result
             let gp_service = crate::GeoprocessingServiceClient::new(
                 "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/Viewshed",
-                self.client,
+                &self.client,
             );
 
             // Fetch the result data
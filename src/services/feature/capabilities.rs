@@ -0,0 +1,225 @@
+//! Server information and service capabilities.
+//!
+//! [`ServerInfo`] is read from a server's `rest/info` endpoint and
+//! [`ServiceCapabilities`] from a feature service root. Once a
+//! [`FeatureServiceClient`](crate::FeatureServiceClient) has loaded its
+//! capabilities, queries and edits are checked against them before any
+//! request is sent.
+
+use crate::ResponseFormat;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Server version and security settings from `rest/info`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    /// REST API version (e.g., `11.1`).
+    current_version: Option<f64>,
+
+    /// Full server version (e.g., `11.1.0`).
+    full_version: Option<String>,
+
+    /// Portal the server is federated with, if any.
+    owning_system_url: Option<String>,
+
+    /// Authentication settings.
+    auth_info: Option<AuthInfo>,
+}
+
+impl ServerInfo {
+    /// Returns the URL of the server's token service, if token security is used.
+    pub fn token_services_url(&self) -> Option<&str> {
+        self.auth_info
+            .as_ref()
+            .and_then(|info| info.token_services_url.as_deref())
+    }
+}
+
+/// Authentication settings reported in `rest/info`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthInfo {
+    /// Whether the server uses token-based security.
+    #[serde(default)]
+    is_token_based_security: bool,
+
+    /// URL of the token service (`generateToken`).
+    token_services_url: Option<String>,
+
+    /// Validity of short-lived tokens, in minutes.
+    short_lived_token_validity: Option<u32>,
+}
+
+/// Capabilities a feature service advertises at its root or for a layer.
+///
+/// Flags the service does not report are `None`; checks only reject an
+/// operation when the service explicitly reports it as unsupported.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(from = "RawCapabilities", into = "RawCapabilities")]
+pub struct ServiceCapabilities {
+    /// REST API version of the service.
+    current_version: Option<f64>,
+
+    /// Maximum number of records returned by a single query.
+    max_record_count: Option<u32>,
+
+    /// Whether queries accept `resultOffset` and `resultRecordCount`.
+    supports_pagination: Option<bool>,
+
+    /// Query formats the service accepts (e.g., `JSON`, `geoJSON`, `PBF`).
+    supported_query_formats: Vec<String>,
+
+    /// Whether `applyEdits` accepts `useGlobalIds`.
+    supports_apply_edits_with_global_ids: Option<bool>,
+
+    /// Whether layers can be truncated.
+    supports_truncate: Option<bool>,
+
+    /// Enabled operations (e.g., `Query`, `Create`, `Update`, `Delete`).
+    capabilities: Vec<String>,
+}
+
+impl ServiceCapabilities {
+    /// Returns whether the service accepts query responses in `format`.
+    ///
+    /// Services that do not list their formats are assumed to accept JSON only.
+    pub fn supports_format(&self, format: ResponseFormat) -> bool {
+        let name = match format {
+            ResponseFormat::Json => "json",
+            ResponseFormat::GeoJson => "geojson",
+            ResponseFormat::Pbf => "pbf",
+        };
+        if self.supported_query_formats.is_empty() {
+            return format == ResponseFormat::Json;
+        }
+        self.supported_query_formats
+            .iter()
+            .any(|supported| supported.eq_ignore_ascii_case(name))
+    }
+
+    /// Fills in the values a layer does not report from the service root.
+    pub(crate) fn or_root(self, root: &ServiceCapabilities) -> Self {
+        fn or_list(layer: Vec<String>, root: &[String]) -> Vec<String> {
+            if layer.is_empty() {
+                root.to_vec()
+            } else {
+                layer
+            }
+        }
+
+        Self {
+            current_version: self.current_version.or(root.current_version),
+            max_record_count: self.max_record_count.or(root.max_record_count),
+            supports_pagination: self.supports_pagination.or(root.supports_pagination),
+            supported_query_formats: or_list(
+                self.supported_query_formats,
+                &root.supported_query_formats,
+            ),
+            supports_apply_edits_with_global_ids: self
+                .supports_apply_edits_with_global_ids
+                .or(root.supports_apply_edits_with_global_ids),
+            supports_truncate: self.supports_truncate.or(root.supports_truncate),
+            capabilities: or_list(self.capabilities, &root.capabilities),
+        }
+    }
+
+    /// Returns whether an operation (e.g., `Update`) is enabled.
+    ///
+    /// Services that do not list their capabilities are assumed to allow it.
+    pub fn has_capability(&self, capability: &str) -> bool {
+        self.capabilities.is_empty()
+            || self
+                .capabilities
+                .iter()
+                .any(|enabled| enabled.eq_ignore_ascii_case(capability))
+    }
+}
+
+/// Query capabilities nested in the service root.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AdvancedQueryCapabilities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supports_pagination: Option<bool>,
+}
+
+/// Service root fields as the server reports them.
+///
+/// Formats and capabilities are comma-separated strings, and pagination
+/// support is reported under `advancedQueryCapabilities` by newer servers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawCapabilities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_version: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_record_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supports_pagination: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    advanced_query_capabilities: Option<AdvancedQueryCapabilities>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supported_query_formats: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supports_apply_edits_with_global_ids: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supports_truncate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capabilities: Option<String>,
+}
+
+/// Splits a comma-separated list, dropping empty entries.
+fn split_list(list: Option<String>) -> Vec<String> {
+    list.map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(String::from)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Joins a list into the comma-separated form, or `None` if empty.
+fn join_list(list: Vec<String>) -> Option<String> {
+    (!list.is_empty()).then(|| list.join(","))
+}
+
+impl From<RawCapabilities> for ServiceCapabilities {
+    fn from(raw: RawCapabilities) -> Self {
+        let supports_pagination = raw
+            .advanced_query_capabilities
+            .and_then(|advanced| advanced.supports_pagination)
+            .or(raw.supports_pagination);
+
+        Self {
+            current_version: raw.current_version,
+            max_record_count: raw.max_record_count,
+            supports_pagination,
+            supported_query_formats: split_list(raw.supported_query_formats),
+            supports_apply_edits_with_global_ids: raw.supports_apply_edits_with_global_ids,
+            supports_truncate: raw.supports_truncate,
+            capabilities: split_list(raw.capabilities),
+        }
+    }
+}
+
+impl From<ServiceCapabilities> for RawCapabilities {
+    fn from(capabilities: ServiceCapabilities) -> Self {
+        Self {
+            current_version: capabilities.current_version,
+            max_record_count: capabilities.max_record_count,
+            supports_pagination: None,
+            advanced_query_capabilities: capabilities.supports_pagination.map(|supported| {
+                AdvancedQueryCapabilities {
+                    supports_pagination: Some(supported),
+                }
+            }),
+            supported_query_formats: join_list(capabilities.supported_query_formats),
+            supports_apply_edits_with_global_ids: capabilities.supports_apply_edits_with_global_ids,
+            supports_truncate: capabilities.supports_truncate,
+            capabilities: join_list(capabilities.capabilities),
+        }
+    }
+}
@@ -1,15 +1,19 @@
 //! Administrative operations for the Feature Service client.
 
 use super::FeatureServiceClient;
-use crate::{LayerId, Result};
+use crate::{LayerId, Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> FeatureServiceClient<'a> {
+impl FeatureServiceClient {
     /// Deletes all features from a layer.
     ///
     /// This operation removes all features from the specified layer while preserving
     /// the layer structure and schema. Use with caution as this operation cannot be undone.
     ///
+    /// If the service's [capabilities](Self::capabilities) are loaded and the
+    /// layer reports `supportsTruncate: false`, fails with [`ErrorKind::Unsupported`](crate::ErrorKind::Unsupported)
+    /// without sending a request.
+    ///
     /// # Arguments
     ///
     /// * `layer_id` - The layer to truncate
@@ -33,38 +37,17 @@ impl<'a> FeatureServiceClient<'a> {
     #[instrument(skip(self), fields(layer_id = %layer_id))]
     pub async fn truncate(&self, layer_id: LayerId) -> Result<crate::TruncateResult> {
         tracing::debug!("Truncating layer");
+        self.load_layer_checks(layer_id).await;
+        self.ensure_truncate(layer_id)?;
 
         let url = format!("{}/{}/truncate", self.base_url, layer_id);
-        // Build form data
-
-        let mut form = vec![("f", "json")];
-
-        // Add token if required by auth provider
 
-        let token_value;
+        let form = vec![("f", "json")];
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            token_value = token;
-
-            form.push(("token", token_value.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "truncate request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: crate::TruncateResult = response.json().await?;
+        let result: crate::TruncateResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         tracing::info!(success = result.success(), "Truncate completed");
 
@@ -111,34 +94,14 @@ impl<'a> FeatureServiceClient<'a> {
 
         let mut form = vec![("f", "json")];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
         if !layers_str.is_empty() {
             form.push(("layers", &layers_str));
         }
 
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "queryDomains request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: crate::QueryDomainsResponse = response.json().await?;
+        let result: crate::QueryDomainsResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         tracing::info!(
             layer_count = result.layers().len(),
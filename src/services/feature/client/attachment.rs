@@ -5,12 +5,12 @@ use super::super::{
     DeleteAttachmentsResponse, DownloadResult, DownloadTarget,
 };
 use super::FeatureServiceClient;
-use crate::{AttachmentId, LayerId, ObjectId, Result};
+use crate::{AttachmentId, LayerId, ObjectId, Result, ServiceRequest};
 use futures::StreamExt;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tracing::instrument;
 
-impl<'a> FeatureServiceClient<'a> {
+impl FeatureServiceClient {
     /// Queries attachments for a specific feature.
     ///
     /// Returns metadata about all attachments associated with the feature.
@@ -53,26 +53,8 @@ impl<'a> FeatureServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending queryAttachments request");
 
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "queryAttachments failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: AttachmentInfosResponse = response.json().await?;
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
+        let result: AttachmentInfosResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             attachment_count = result.attachment_infos.len(),
@@ -140,35 +122,15 @@ impl<'a> FeatureServiceClient<'a> {
             "Sending deleteAttachments request"
         );
 
-        let mut form = vec![
+        let form = vec![
             ("attachmentIds", attachment_ids_str.as_str()),
             ("f", "json"),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "deleteAttachments failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: DeleteAttachmentsResponse = response.json().await?;
+        let result: DeleteAttachmentsResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         let success_count = result
             .delete_attachment_results
@@ -334,34 +296,12 @@ impl<'a> FeatureServiceClient<'a> {
         // Add standard parameters
         form = form.text("f", "json");
 
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
         tracing::debug!(url = %url, "Sending addAttachment request");
 
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "addAttachment failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get raw response text for debugging
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "addAttachment raw response");
-
-        let response_wrapper: crate::services::feature::attachment::AddAttachmentResponse =
-            serde_json::from_str(&response_text)?;
+        let response_wrapper: crate::services::feature::attachment::AddAttachmentResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
         let result = response_wrapper.add_attachment_result;
 
         tracing::info!(
@@ -515,33 +455,12 @@ impl<'a> FeatureServiceClient<'a> {
         // Add standard parameters
         form = form.text("f", "json");
 
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
         tracing::debug!(url = %url, "Sending updateAttachment request");
 
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "updateAttachment failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "updateAttachment raw response");
-
-        let response_wrapper: crate::services::feature::attachment::UpdateAttachmentResponse =
-            serde_json::from_str(&response_text)?;
+        let response_wrapper: crate::services::feature::attachment::UpdateAttachmentResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
         let result = response_wrapper.update_attachment_result;
 
         tracing::info!(
@@ -623,24 +542,8 @@ impl<'a> FeatureServiceClient<'a> {
         );
         tracing::debug!(url = %url, "Sending download request");
 
-        let mut request = self.client.http().get(&url);
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "download attachment failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url);
+        let response = self.client.execute(request).await?;
 
         // Stream the response based on target
         match target {
@@ -0,0 +1,295 @@
+//! Server information and capability negotiation for the Feature Service client.
+
+use super::FeatureServiceClient;
+use crate::{
+    ErrorKind, LayerId, ResponseFormat, Result, ServerInfo, ServiceCapabilities, ServiceRequest,
+};
+use std::sync::PoisonError;
+use tracing::instrument;
+
+impl FeatureServiceClient {
+    /// Retrieves version and security information for the hosting server.
+    ///
+    /// Fetches `rest/info` of the server the feature service belongs to,
+    /// reporting the REST API version, the token service URL, and the portal
+    /// the server is federated with.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, FeatureServiceClient};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let service = FeatureServiceClient::new(
+    ///     "https://host/server/rest/services/Parcels/FeatureServer",
+    ///     &client,
+    /// );
+    ///
+    /// let info = service.server_info().await?;
+    /// println!("Version: {:?}", info.current_version());
+    /// println!("Token service: {:?}", info.token_services_url());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), fields(base_url = %self.base_url))]
+    pub async fn server_info(&self) -> Result<ServerInfo> {
+        tracing::debug!("Fetching server info");
+
+        let url = crate::auth::rest_info_url(&self.base_url);
+        let request = ServiceRequest::get(&url)
+            .operation("serverInfo")
+            .query(&[("f", "json")])
+            .cacheable();
+
+        let info: ServerInfo = self.client.execute_json(request).await?;
+
+        tracing::debug!(
+            current_version = ?info.current_version(),
+            token_services_url = ?info.token_services_url(),
+            "Server info retrieved"
+        );
+
+        Ok(info)
+    }
+
+    /// Loads the capabilities the service advertises and enables checks against them.
+    ///
+    /// Reads the service root for its version, `maxRecordCount`, supported
+    /// query formats, pagination, global ID edits, truncate, and enabled
+    /// operations. The result is kept by this client and its clones; from then
+    /// on edits and truncates the service does not support fail with
+    /// [`ErrorKind::Unsupported`] before a request is sent, query builders
+    /// fall back to a supported response format and page size, and paginated
+    /// queries of services without pagination fail if the result is truncated.
+    ///
+    /// Pagination, truncate, `maxRecordCount`, and query formats are reported
+    /// per layer. Once root capabilities are loaded, the first query or
+    /// truncate of a layer also loads its
+    /// [`layer_capabilities`](Self::layer_capabilities), whose values take
+    /// precedence over the root's.
+    ///
+    /// Until capabilities are loaded, requests are sent unchecked.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, FeatureServiceClient, LayerId};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let service = FeatureServiceClient::new("https://example.com/FeatureServer", &client);
+    ///
+    /// let capabilities = service.capabilities().await?;
+    /// println!("Max records: {:?}", capabilities.max_record_count());
+    ///
+    /// // Falls back to JSON if the service does not support PBF
+    /// let features = service.query(LayerId::new(0)).pbf().execute_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), fields(base_url = %self.base_url))]
+    pub async fn capabilities(&self) -> Result<ServiceCapabilities> {
+        tracing::debug!("Fetching service capabilities");
+
+        let request = ServiceRequest::get(&self.base_url)
+            .operation("serviceCapabilities")
+            .query(&[("f", "json")])
+            .cacheable();
+
+        let capabilities: ServiceCapabilities = self.client.execute_json(request).await?;
+
+        tracing::debug!(
+            current_version = ?capabilities.current_version(),
+            max_record_count = ?capabilities.max_record_count(),
+            supports_pagination = ?capabilities.supports_pagination(),
+            formats = ?capabilities.supported_query_formats(),
+            "Service capabilities retrieved"
+        );
+
+        *self
+            .capabilities
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(capabilities.clone());
+
+        Ok(capabilities)
+    }
+
+    /// Returns the capabilities loaded by [`capabilities`](Self::capabilities), if any.
+    pub fn cached_capabilities(&self) -> Option<ServiceCapabilities> {
+        self.capabilities
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Loads the capabilities a layer advertises and enables checks against them.
+    ///
+    /// Reads the layer resource (`FeatureServer/{layerId}`). Values the layer
+    /// does not report fall back to the service root's, if loaded by
+    /// [`capabilities`](Self::capabilities). The result is kept by this
+    /// client and its clones.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, NoAuth, FeatureServiceClient, LayerId};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let service = FeatureServiceClient::new("https://example.com/FeatureServer", &client);
+    ///
+    /// let capabilities = service.layer_capabilities(LayerId::new(0)).await?;
+    /// println!("Pagination: {:?}", capabilities.supports_pagination());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), fields(base_url = %self.base_url))]
+    pub async fn layer_capabilities(&self, layer_id: LayerId) -> Result<ServiceCapabilities> {
+        tracing::debug!("Fetching layer capabilities");
+
+        let url = format!("{}/{}", self.base_url, layer_id);
+        let request = ServiceRequest::get(&url)
+            .operation("layerCapabilities")
+            .query(&[("f", "json")])
+            .cacheable();
+
+        let capabilities: ServiceCapabilities = self.client.execute_json(request).await?;
+
+        tracing::debug!(
+            max_record_count = ?capabilities.max_record_count(),
+            supports_pagination = ?capabilities.supports_pagination(),
+            supports_truncate = ?capabilities.supports_truncate(),
+            "Layer capabilities retrieved"
+        );
+
+        let effective = match self.cached_capabilities() {
+            Some(root) => capabilities.clone().or_root(&root),
+            None => capabilities.clone(),
+        };
+        self.layer_capabilities
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(layer_id, capabilities);
+
+        Ok(effective)
+    }
+
+    /// Returns the capabilities checks use for a layer: those loaded by
+    /// [`layer_capabilities`](Self::layer_capabilities) completed with the
+    /// root's, or the root's alone, if any are loaded.
+    pub fn cached_layer_capabilities(&self, layer_id: LayerId) -> Option<ServiceCapabilities> {
+        let layer = self
+            .layer_capabilities
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&layer_id)
+            .cloned();
+        match (layer, self.cached_capabilities()) {
+            (Some(layer), Some(root)) => Some(layer.or_root(&root)),
+            (layer, root) => layer.or(root),
+        }
+    }
+
+    /// Loads a layer's capabilities if root capabilities are loaded and the
+    /// layer's are not yet.
+    ///
+    /// Failures are logged and leave the root values in effect.
+    pub(crate) async fn load_layer_checks(&self, layer_id: LayerId) {
+        let loaded = self
+            .layer_capabilities
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(&layer_id);
+        if loaded || self.cached_capabilities().is_none() {
+            return;
+        }
+        if let Err(e) = self.layer_capabilities(layer_id).await {
+            tracing::debug!(layer_id = %layer_id, error = %e, "Layer capabilities unavailable, using root");
+        }
+    }
+
+    /// Builds the error for an operation the service does not support.
+    fn unsupported(&self, what: &str) -> crate::Error {
+        tracing::warn!(base_url = %self.base_url, what = %what, "Operation not supported by service");
+        crate::Error::from(ErrorKind::Unsupported(format!(
+            "{} does not support {}",
+            self.base_url, what
+        )))
+    }
+
+    /// Fails if the layer reports that it cannot be truncated.
+    pub(super) fn ensure_truncate(&self, layer_id: LayerId) -> Result<()> {
+        match self.cached_layer_capabilities(layer_id) {
+            Some(capabilities) if *capabilities.supports_truncate() == Some(false) => {
+                Err(self.unsupported("truncate"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails if the service lacks an edit capability or global ID edits.
+    ///
+    /// `Editing` without any of `Create`, `Update`, or `Delete` allows all three.
+    pub(super) fn ensure_edits(
+        &self,
+        adds: bool,
+        updates: bool,
+        deletes: bool,
+        global_ids: bool,
+    ) -> Result<()> {
+        let Some(capabilities) = self.cached_capabilities() else {
+            return Ok(());
+        };
+
+        let operations = [(adds, "Create"), (updates, "Update"), (deletes, "Delete")];
+        let editing_only = capabilities.has_capability("Editing")
+            && !operations
+                .iter()
+                .any(|(_, capability)| capabilities.has_capability(capability));
+        for (requested, capability) in operations {
+            if requested && !editing_only && !capabilities.has_capability(capability) {
+                return Err(self.unsupported(&format!("{} edits", capability)));
+            }
+        }
+        if global_ids && *capabilities.supports_apply_edits_with_global_ids() == Some(false) {
+            return Err(self.unsupported("applyEdits with global IDs"));
+        }
+        Ok(())
+    }
+
+    /// Fails if the layer reports that queries cannot be paginated.
+    pub(crate) fn ensure_pagination(&self, layer_id: LayerId) -> Result<()> {
+        match self.cached_layer_capabilities(layer_id) {
+            Some(capabilities) if *capabilities.supports_pagination() == Some(false) => {
+                Err(self.unsupported("query pagination"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns `format` if the layer supports it, otherwise JSON.
+    pub(crate) fn negotiate_format(
+        &self,
+        layer_id: LayerId,
+        format: ResponseFormat,
+    ) -> ResponseFormat {
+        match self.cached_layer_capabilities(layer_id) {
+            Some(capabilities) if !capabilities.supports_format(format) => {
+                tracing::debug!(
+                    requested = ?format,
+                    supported = ?capabilities.supported_query_formats(),
+                    "Query format not supported, falling back to JSON"
+                );
+                ResponseFormat::Json
+            }
+            _ => format,
+        }
+    }
+
+    /// Returns the layer's `maxRecordCount`, if capabilities are loaded.
+    pub(crate) fn max_record_count(&self, layer_id: LayerId) -> Option<u32> {
+        self.cached_layer_capabilities(layer_id)
+            .and_then(|capabilities| *capabilities.max_record_count())
+    }
+}
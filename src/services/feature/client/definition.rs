@@ -1,12 +1,10 @@
 //! Service definition retrieval operations for the Feature Service client.
 
 use super::FeatureServiceClient;
-use crate::{
-    LayerDefinition, LayerId, Result, ServiceDefinition, TableDefinition, check_esri_error,
-};
+use crate::{LayerDefinition, LayerId, Result, ServiceDefinition, ServiceRequest, TableDefinition};
 use tracing::instrument;
 
-impl<'a> FeatureServiceClient<'a> {
+impl FeatureServiceClient {
     /// Retrieves the service-level definition from an existing Feature Service.
     ///
     /// Fetches the service root endpoint (`GET {serviceUrl}?f=json`) and deserializes
@@ -50,30 +48,12 @@ impl<'a> FeatureServiceClient<'a> {
         let url = &self.base_url;
         tracing::debug!(url = %url, "Sending service definition request");
 
-        let mut request = self.client.http().get(url).query(&[("f", "json")]);
+        let request = ServiceRequest::get(url).query(&[("f", "json")]);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "get_definition request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "get_definition")?;
-        let definition: ServiceDefinition = serde_json::from_str(&response_text)?;
+        let definition: ServiceDefinition = self
+            .client
+            .execute_json(request.operation("get_definition").cacheable())
+            .await?;
 
         tracing::info!(
             layer_count = definition.layers().len(),
@@ -122,30 +102,12 @@ impl<'a> FeatureServiceClient<'a> {
         let url = format!("{}/{}", self.base_url, layer_id);
         tracing::debug!(url = %url, "Sending layer definition request");
 
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
 
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "get_layer_definition request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "get_layer_definition")?;
-        let layer: LayerDefinition = serde_json::from_str(&response_text)?;
+        let layer: LayerDefinition = self
+            .client
+            .execute_json(request.operation("get_layer_definition").cacheable())
+            .await?;
 
         tracing::info!(
             name = %layer.name(),
@@ -188,30 +150,12 @@ impl<'a> FeatureServiceClient<'a> {
         let url = format!("{}/{}", self.base_url, table_id);
         tracing::debug!(url = %url, "Sending table definition request");
 
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "get_table_definition request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "get_table_definition")?;
-        let table: TableDefinition = serde_json::from_str(&response_text)?;
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
+
+        let table: TableDefinition = self
+            .client
+            .execute_json(request.operation("get_table_definition"))
+            .await?;
 
         tracing::info!(
             name = %table.name(),
@@ -2,10 +2,10 @@
 
 use super::super::{EditOptions, EditResult, Feature};
 use super::FeatureServiceClient;
-use crate::{LayerId, ObjectId, Result, check_esri_error};
+use crate::{LayerId, ObjectId, Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> FeatureServiceClient<'a> {
+impl FeatureServiceClient {
     /// Adds new features to a layer.
     ///
     /// # Arguments
@@ -50,6 +50,7 @@ impl<'a> FeatureServiceClient<'a> {
         options: EditOptions,
     ) -> Result<EditResult> {
         tracing::debug!("Adding features to layer");
+        self.ensure_edits(true, false, false, false)?;
 
         let url = format!("{}/{}/addFeatures", self.base_url, layer_id);
 
@@ -80,32 +81,14 @@ impl<'a> FeatureServiceClient<'a> {
             ));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "addFeatures request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "addFeatures")?;
-        let result: EditResult = serde_json::from_str(&response_text)?;
+        let result: EditResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("addFeatures")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             success_count = result.success_count(),
@@ -160,6 +143,7 @@ impl<'a> FeatureServiceClient<'a> {
         options: EditOptions,
     ) -> Result<EditResult> {
         tracing::debug!("Updating features in layer");
+        self.ensure_edits(false, true, false, false)?;
 
         let url = format!("{}/{}/updateFeatures", self.base_url, layer_id);
 
@@ -190,32 +174,14 @@ impl<'a> FeatureServiceClient<'a> {
             ));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "updateFeatures request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "updateFeatures")?;
-        let result: EditResult = serde_json::from_str(&response_text)?;
+        let result: EditResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("updateFeatures")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             success_count = result.success_count(),
@@ -262,6 +228,7 @@ impl<'a> FeatureServiceClient<'a> {
         options: EditOptions,
     ) -> Result<EditResult> {
         tracing::debug!("Deleting features from layer");
+        self.ensure_edits(false, false, true, false)?;
 
         let url = format!("{}/{}/deleteFeatures", self.base_url, layer_id);
 
@@ -291,32 +258,14 @@ impl<'a> FeatureServiceClient<'a> {
             ));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "deleteFeatures request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "deleteFeatures")?;
-        let result: EditResult = serde_json::from_str(&response_text)?;
+        let result: EditResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("deleteFeatures")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             success_count = result.success_count(),
@@ -332,6 +281,11 @@ impl<'a> FeatureServiceClient<'a> {
     /// This is the most efficient way to perform multiple edit operations,
     /// as it allows adding, updating, and deleting features in a single request.
     ///
+    /// If the service's [capabilities](Self::capabilities) are loaded, edits it
+    /// does not allow (e.g. updates without the `Update` capability, or
+    /// `useGlobalIds` without `supportsApplyEditsWithGlobalIds`) fail with
+    /// [`ErrorKind::Unsupported`](crate::ErrorKind::Unsupported) before a request is sent.
+    ///
     /// # Arguments
     ///
     /// * `layer_id` - The layer to edit
@@ -406,6 +360,12 @@ impl<'a> FeatureServiceClient<'a> {
         options: EditOptions,
     ) -> Result<EditResult> {
         tracing::debug!("Applying batch edits to layer");
+        self.ensure_edits(
+            adds.is_some(),
+            updates.is_some(),
+            deletes.is_some(),
+            options.use_global_ids == Some(true),
+        )?;
 
         let url = format!("{}/{}/applyEdits", self.base_url, layer_id);
 
@@ -455,32 +415,14 @@ impl<'a> FeatureServiceClient<'a> {
             ));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "applyEdits request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "applyEdits")?;
-        let result: EditResult = serde_json::from_str(&response_text)?;
+        let result: EditResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("applyEdits")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             success_count = result.success_count(),
@@ -576,45 +518,14 @@ impl<'a> FeatureServiceClient<'a> {
             form.push(("rollbackOnFailure", if rollback { "true" } else { "false" }));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "calculate request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        tracing::debug!(
-            response_length = response_text.len(),
-            "Received calculate response"
-        );
-
-        check_esri_error(&response_text, "calculate")?;
-
-        let result: crate::CalculateResult = serde_json::from_str(&response_text).map_err(|e| {
-            tracing::error!(
-                error = %e,
-                response_preview = %&response_text[..response_text.len().min(500)],
-                "Failed to deserialize CalculateResult"
-            );
-            e
-        })?;
+        let result: crate::CalculateResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("calculate")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             success = result.success(),
@@ -632,6 +543,10 @@ impl<'a> FeatureServiceClient<'a> {
     /// for identifying features. Global IDs are stable across replicas and are useful
     /// in disconnected editing scenarios.
     ///
+    /// Fails with [`ErrorKind::Unsupported`](crate::ErrorKind::Unsupported) without
+    /// sending a request if loaded [capabilities](Self::capabilities) report
+    /// `supportsApplyEditsWithGlobalIds: false`.
+    ///
     /// # Arguments
     ///
     /// * `layer_id` - The layer to apply edits to
@@ -693,6 +608,7 @@ impl<'a> FeatureServiceClient<'a> {
         options: EditOptions,
     ) -> Result<EditResult> {
         tracing::debug!("Applying batch edits to layer using global IDs");
+        self.ensure_edits(adds.is_some(), updates.is_some(), deletes.is_some(), true)?;
 
         let url = format!("{}/{}/applyEdits", self.base_url, layer_id);
 
@@ -728,32 +644,14 @@ impl<'a> FeatureServiceClient<'a> {
             form.push(("rollbackOnFailure", if rollback { "true" } else { "false" }));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "applyEditsWithGlobalIds request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        check_esri_error(&response_text, "applyEditsWithGlobalIds")?;
-        let result: EditResult = serde_json::from_str(&response_text)?;
+        let result: EditResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("applyEditsWithGlobalIds")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             success_count = result.success_count(),
@@ -2,11 +2,14 @@
 
 mod admin;
 mod attachment;
+mod capabilities;
 mod definition;
 mod edit;
 mod query;
 
-use crate::{ArcGISClient, LayerId, QueryBuilder};
+use crate::{ArcGISClient, LayerId, QueryBuilder, ServiceCapabilities};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use tracing::instrument;
 
 /// Client for interacting with an ArcGIS Feature Service.
@@ -26,14 +29,19 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-pub struct FeatureServiceClient<'a> {
+#[derive(Clone)]
+pub struct FeatureServiceClient {
     /// Base URL of the feature service.
     pub(super) base_url: String,
-    /// Reference to the ArcGIS client for HTTP operations.
-    pub(super) client: &'a ArcGISClient,
+    /// Shared ArcGIS client for HTTP operations.
+    pub(super) client: ArcGISClient,
+    /// Capabilities loaded by [`capabilities`](Self::capabilities), shared by clones.
+    pub(super) capabilities: Arc<RwLock<Option<ServiceCapabilities>>>,
+    /// Layer capabilities as each layer reports them, shared by clones.
+    pub(super) layer_capabilities: Arc<RwLock<HashMap<LayerId, ServiceCapabilities>>>,
 }
 
-impl<'a> FeatureServiceClient<'a> {
+impl FeatureServiceClient {
     /// Creates a new Feature Service client.
     ///
     /// # Arguments
@@ -54,10 +62,15 @@ impl<'a> FeatureServiceClient<'a> {
     /// );
     /// ```
     #[instrument(skip(base_url, client))]
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating FeatureServiceClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+            capabilities: Arc::default(),
+            layer_capabilities: Arc::default(),
+        }
     }
 
     /// Creates a fluent query builder for the specified layer.
@@ -90,8 +103,8 @@ impl<'a> FeatureServiceClient<'a> {
     /// # }
     /// ```
     #[instrument(skip(self), fields(layer_id = %layer_id, base_url = %self.base_url))]
-    pub fn query(&'a self, layer_id: LayerId) -> QueryBuilder<'a> {
+    pub fn query(&self, layer_id: LayerId) -> QueryBuilder {
         tracing::debug!(layer_id = %layer_id, "Creating query builder");
-        QueryBuilder::new(self, layer_id)
+        QueryBuilder::new(self.clone(), layer_id)
     }
 }
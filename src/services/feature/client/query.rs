@@ -2,10 +2,10 @@
 
 use super::super::{FeatureQueryParams, FeatureSet};
 use super::FeatureServiceClient;
-use crate::{LayerId, Result};
+use crate::{LayerId, Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> FeatureServiceClient<'a> {
+impl FeatureServiceClient {
     /// Queries features from a specific layer with pre-built parameters.
     ///
     /// This is a lower-level method. For most use cases, prefer the
@@ -48,40 +48,20 @@ impl<'a> FeatureServiceClient<'a> {
         tracing::debug!(url = %url, "Sending query request");
 
         // Build request with query parameters
-        let mut request = self.client.http().get(&url).query(&params);
-
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "Query request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url).query(&params);
 
         // Parse the response based on the requested format
         let feature_set = match params.format() {
             crate::ResponseFormat::Pbf => {
                 // PBF format - decode binary protocol buffer
-                let bytes = response.bytes().await?;
+                let bytes = self.client.execute_bytes(request).await?;
                 tracing::debug!(bytes_len = bytes.len(), "Received PBF response");
                 super::super::pbf::decode_feature_collection(&bytes)?
             }
             crate::ResponseFormat::GeoJson => {
                 // GeoJSON format - convert from GeoJSON to FeatureSet
-                let geojson_fc: geojson::FeatureCollection = response.json().await?;
+                let geojson_fc: geojson::FeatureCollection =
+                    self.client.execute_json(request).await?;
                 tracing::debug!(
                     feature_count = geojson_fc.features.len(),
                     "Received GeoJSON response"
@@ -90,7 +70,7 @@ impl<'a> FeatureServiceClient<'a> {
             }
             crate::ResponseFormat::Json => {
                 // Standard ArcGIS JSON format
-                response.json().await?
+                self.client.execute_json(request).await?
             }
         };
 
@@ -157,31 +137,9 @@ impl<'a> FeatureServiceClient<'a> {
         tracing::debug!(url = %url, "Sending query related records request");
 
         // Build request with query parameters
-        let mut request = self.client.http().get(&url).query(&params);
-
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+        let request = ServiceRequest::get(&url).query(&params);
 
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "Query related records request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse the response
-        let result: crate::RelatedRecordsResponse = response.json().await?;
+        let result: crate::RelatedRecordsResponse = self.client.execute_json(request).await?;
 
         tracing::debug!(
             groups_count = result.related_record_groups().len(),
@@ -248,43 +206,17 @@ impl<'a> FeatureServiceClient<'a> {
         tracing::debug!(url = %url, "Sending query top features request");
 
         // Build request with query parameters
-        let mut request = self.client.http().get(&url).query(&params);
-
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "Query top features request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url).query(&params);
 
         // Parse the response based on the requested format
-        let result = if let Some(format_str) = params.f() {
-            if format_str == "pbf" {
-                // PBF format - decode binary protocol buffer
-                let bytes = response.bytes().await?;
-                tracing::debug!(bytes_len = bytes.len(), "Received PBF response");
-                super::super::pbf::decode_feature_collection(&bytes)?
-            } else {
-                // JSON or GeoJSON format - use standard JSON parsing
-                response.json().await?
-            }
+        let result = if params.f().as_deref() == Some("pbf") {
+            // PBF format - decode binary protocol buffer
+            let bytes = self.client.execute_bytes(request).await?;
+            tracing::debug!(bytes_len = bytes.len(), "Received PBF response");
+            super::super::pbf::decode_feature_collection(&bytes)?
         } else {
-            // Default to JSON parsing
-            response.json().await?
+            // JSON or GeoJSON format - use standard JSON parsing
+            self.client.execute_json(request).await?
         };
 
         tracing::debug!(
@@ -92,6 +92,118 @@ impl EditResult {
             + self.update_results.iter().filter(|r| !r.success).count()
             + self.delete_results.iter().filter(|r| !r.success).count()
     }
+
+    /// Returns the failed items, tagged with the operation they belong to.
+    pub fn failures(&self) -> Vec<EditFailure> {
+        [
+            (EditOperation::Add, &self.add_results),
+            (EditOperation::Update, &self.update_results),
+            (EditOperation::Delete, &self.delete_results),
+        ]
+        .into_iter()
+        .flat_map(|(operation, items)| {
+            items
+                .iter()
+                .filter(|item| !item.success)
+                .map(move |item| EditFailure {
+                    operation,
+                    object_id: item.object_id,
+                    global_id: item.global_id.clone(),
+                    error: item.error.clone(),
+                })
+        })
+        .collect()
+    }
+
+    /// Turns per-item failures into an error.
+    ///
+    /// An applyEdits request succeeds at the HTTP and ESRI level even when
+    /// individual features are rejected. Use this to treat any rejected item
+    /// as a failure of the whole call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorKind::EditFailed`](crate::ErrorKind::EditFailed) with
+    /// every failed item if any edit did not succeed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arcgis::{ArcGISClient, EditOptions, ErrorKind, FeatureServiceClient, LayerId, NoAuth, ObjectId};
+    ///
+    /// # async fn example() -> arcgis::Result<()> {
+    /// let client = ArcGISClient::new(NoAuth);
+    /// let service = FeatureServiceClient::new("https://example.com/FeatureServer", &client);
+    ///
+    /// let result = service
+    ///     .delete_features(LayerId::new(0), vec![ObjectId::new(7)], EditOptions::default())
+    ///     .await?
+    ///     .error_for_failures();
+    ///
+    /// if let Err(e) = result {
+    ///     if let ErrorKind::EditFailed(failures) = e.kind() {
+    ///         for failure in failures {
+    ///             eprintln!("{}", failure);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn error_for_failures(self) -> crate::Result<Self> {
+        let failures = self.failures();
+        if failures.is_empty() {
+            Ok(self)
+        } else {
+            Err(crate::Error::from(crate::ErrorKind::EditFailed(failures)))
+        }
+    }
+}
+
+/// Kind of edit within an edit operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum EditOperation {
+    /// Feature addition.
+    #[display("add")]
+    Add,
+    /// Feature update.
+    #[display("update")]
+    Update,
+    /// Feature deletion.
+    #[display("delete")]
+    Delete,
+}
+
+/// A rejected item of an edit operation.
+///
+/// Carried by [`ErrorKind::EditFailed`](crate::ErrorKind::EditFailed); see
+/// [`EditResult::error_for_failures`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct EditFailure {
+    /// Which edit the item belonged to.
+    operation: EditOperation,
+    /// ObjectID of the feature, if reported.
+    object_id: Option<ObjectId>,
+    /// GlobalID of the feature, if reported.
+    global_id: Option<String>,
+    /// Error reported for the item. Absent when the service returns only a
+    /// success flag (e.g. `returnEditResults=false`).
+    error: Option<EditError>,
+}
+
+impl std::fmt::Display for EditFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} failed", self.operation)?;
+        if let Some(object_id) = self.object_id {
+            write!(f, " for ObjectID {}", object_id)?;
+        } else if let Some(global_id) = &self.global_id {
+            write!(f, " for GlobalID {}", global_id)?;
+        }
+        match &self.error {
+            Some(error) => write!(f, ": {}", error),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Individual result for a single feature edit.
@@ -4,6 +4,7 @@
 //! and edit features with full CRUD support, including attachment management.
 
 mod attachment;
+mod capabilities;
 mod client;
 mod edit;
 mod geojson;
@@ -16,8 +17,11 @@ pub use attachment::{
     DeleteAttachmentResult, DeleteAttachmentsResponse, DownloadResult, DownloadTarget,
     UpdateAttachmentResult,
 };
+pub use capabilities::{AuthInfo, ServerInfo, ServiceCapabilities};
 pub use client::FeatureServiceClient;
-pub use edit::{CalculateResult, EditError, EditOptions, EditResult, EditResultItem};
+pub use edit::{
+    CalculateResult, EditError, EditFailure, EditOperation, EditOptions, EditResult, EditResultItem,
+};
 pub use query::QueryBuilder;
 pub use types::{
     CodedValue, Domain, Feature, FeatureQueryParams, FeatureQueryParamsBuilder, FeatureSet,
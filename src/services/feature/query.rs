@@ -1,11 +1,20 @@
 //! Query builder for Feature Service queries.
 
 use crate::{
-    ArcGISGeometry, FeatureQueryParams, FeatureServiceClient, FeatureSet, GeometryType, LayerId,
-    ObjectId, ResponseFormat, Result, SpatialRel,
+    ArcGISGeometry, Feature, FeatureQueryParams, FeatureServiceClient, FeatureSet, GeometryType,
+    LayerId, ObjectId, ResponseFormat, Result, SpatialRel,
 };
+use futures::future::BoxFuture;
+use futures::stream::{FuturesOrdered, Stream};
+use futures::{FutureExt, StreamExt, TryStreamExt};
 use tracing::instrument;
 
+/// Page size used when neither the query nor the service sets one.
+const DEFAULT_PAGE_SIZE: u32 = 1000;
+
+/// Pages fetched ahead of the consumer by [`QueryBuilder::stream`] by default.
+const DEFAULT_PREFETCH: usize = 2;
+
 /// A fluent builder for constructing and executing feature queries.
 ///
 /// This provides an ergonomic API for building complex queries without
@@ -38,23 +47,25 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-pub struct QueryBuilder<'a> {
-    client: &'a FeatureServiceClient<'a>,
+pub struct QueryBuilder {
+    client: FeatureServiceClient,
     layer_id: LayerId,
     params: FeatureQueryParams,
+    prefetch: usize,
 }
 
-impl<'a> QueryBuilder<'a> {
+impl QueryBuilder {
     /// Creates a new query builder.
     ///
     /// Typically you don't call this directly - use [`FeatureServiceClient::query`] instead.
     #[instrument(skip(client))]
-    pub(crate) fn new(client: &'a FeatureServiceClient<'a>, layer_id: LayerId) -> Self {
+    pub(crate) fn new(client: FeatureServiceClient, layer_id: LayerId) -> Self {
         tracing::debug!(layer_id = %layer_id, "Creating QueryBuilder");
         Self {
             client,
             layer_id,
             params: FeatureQueryParams::default(),
+            prefetch: DEFAULT_PREFETCH,
         }
     }
 
@@ -63,7 +74,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let features = service
     ///     .query(LayerId::new(0))
     ///     .where_clause("POPULATION > 1000000")
@@ -84,7 +95,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let features = service
     ///     .query(LayerId::new(0))
     ///     .out_fields(&["NAME", "POPULATION", "CITY_ID"])
@@ -119,11 +130,13 @@ impl<'a> QueryBuilder<'a> {
     ///
     /// PBF is a binary format that's more efficient than JSON for large datasets.
     /// Supported by ArcGIS Enterprise 10.7+ and ArcGIS Online.
+    /// If the service's [capabilities](FeatureServiceClient::capabilities) are
+    /// loaded and do not list PBF, the query falls back to JSON.
     ///
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// // Get large dataset efficiently with PBF
     /// let features = service
     ///     .query(LayerId::new(0))
@@ -157,7 +170,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId, ArcGISPoint, ArcGISGeometry, GeometryType, SpatialRel};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let point = ArcGISPoint::new(-118.0, 34.0);
     /// let features = service
     ///     .query(LayerId::new(0))
@@ -200,7 +213,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId, ObjectId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let features = service
     ///     .query(LayerId::new(0))
     ///     .object_ids(&[ObjectId::new(1), ObjectId::new(2), ObjectId::new(3)])
@@ -237,7 +250,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let features = service
     ///     .query(LayerId::new(0))
     ///     .order_by(&["POPULATION DESC", "NAME ASC"])
@@ -257,7 +270,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId, StatisticDefinition, StatisticType};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let stats = service
     ///     .query(LayerId::new(0))
     ///     .statistics(vec![
@@ -283,7 +296,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId, StatisticDefinition, StatisticType};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let stats = service
     ///     .query(LayerId::new(0))
     ///     .statistics(vec![
@@ -310,7 +323,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId, StatisticDefinition, StatisticType};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let stats = service
     ///     .query(LayerId::new(0))
     ///     .statistics(vec![
@@ -333,7 +346,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let features = service
     ///     .query(LayerId::new(0))
     ///     .out_sr(4326)  // WGS84
@@ -347,6 +360,16 @@ impl<'a> QueryBuilder<'a> {
         self
     }
 
+    /// Sets how many pages [`stream`](Self::stream) and [`pages`](Self::pages)
+    /// request concurrently.
+    ///
+    /// Default is 2. Values below 1 are treated as 1, which fetches one page
+    /// at a time.
+    pub fn prefetch(mut self, pages: usize) -> Self {
+        self.prefetch = pages.max(1);
+        self
+    }
+
     /// Executes the query and returns a single page of results.
     ///
     /// This method sends a single request to the server and returns
@@ -358,7 +381,7 @@ impl<'a> QueryBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// let features = service
     ///     .query(LayerId::new(0))
     ///     .where_clause("STATE = 'CA'")
@@ -369,8 +392,10 @@ impl<'a> QueryBuilder<'a> {
     /// # }
     /// ```
     #[instrument(skip(self), fields(layer_id = %self.layer_id))]
-    pub async fn execute(self) -> Result<FeatureSet> {
+    pub async fn execute(mut self) -> Result<FeatureSet> {
         tracing::debug!("Executing single-page query");
+        self.client.load_layer_checks(self.layer_id).await;
+        self.negotiate_format();
         self.client
             .query_with_params(self.layer_id, self.params)
             .await
@@ -387,10 +412,18 @@ impl<'a> QueryBuilder<'a> {
     /// This method may make many requests for large result sets. Consider using
     /// [`execute`](Self::execute) with manual pagination for very large queries.
     ///
+    /// # Capabilities
+    ///
+    /// If the service's [capabilities](FeatureServiceClient::capabilities) are
+    /// loaded, pages are capped at its `maxRecordCount`. Services that do not
+    /// support pagination are queried once without paging parameters; if
+    /// that result is truncated (`exceededTransferLimit`), the query fails
+    /// with [`ErrorKind::Unsupported`](crate::ErrorKind::Unsupported).
+    ///
     /// # Example
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
-    /// # async fn example(service: &FeatureServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
     /// // Automatically fetches all matching features across multiple requests
     /// let all_features = service
     ///     .query(LayerId::new(0))
@@ -405,18 +438,23 @@ impl<'a> QueryBuilder<'a> {
     #[instrument(skip(self), fields(layer_id = %self.layer_id))]
     pub async fn execute_all(mut self) -> Result<FeatureSet> {
         tracing::debug!("Executing auto-paginated query");
+        self.client.load_layer_checks(self.layer_id).await;
+        let paginate = self.client.ensure_pagination(self.layer_id).is_ok();
+        self.negotiate_format();
 
         let mut all_features = Vec::new();
         let mut offset = 0u32;
-        let page_size = (*self.params.result_record_count()).unwrap_or(1000);
+        let page_size = self.page_size();
 
         // Store geometry type from first response
         let mut geometry_type = None;
 
         loop {
             // Set pagination parameters
-            self.params.set_result_offset(Some(offset));
-            self.params.set_result_record_count(Some(page_size));
+            if paginate {
+                self.params.set_result_offset(Some(offset));
+                self.params.set_result_record_count(Some(page_size));
+            }
 
             tracing::debug!(
                 offset = offset,
@@ -442,11 +480,16 @@ impl<'a> QueryBuilder<'a> {
                 "Page retrieved"
             );
 
+            // A truncated result cannot be completed without pagination
+            if !paginate && *page.exceeded_transfer_limit() {
+                self.client.ensure_pagination(self.layer_id)?;
+            }
+
             // Add features to our collection
             all_features.append(page.features_mut());
 
             // Check if we're done
-            if feature_count == 0 || !*page.exceeded_transfer_limit() {
+            if !paginate || feature_count == 0 || !*page.exceeded_transfer_limit() {
                 tracing::debug!(
                     total_features = all_features.len(),
                     "Auto-pagination complete"
@@ -454,10 +497,240 @@ impl<'a> QueryBuilder<'a> {
                 break;
             }
 
-            // Move to next page
-            offset += page_size;
+            // Move past the features returned; the server may cap pages
+            // below the requested size
+            offset = offset.saturating_add(u32::try_from(feature_count).unwrap_or(u32::MAX));
         }
 
         Ok(FeatureSet::new(geometry_type, all_features, None, false))
     }
+
+    /// Streams the query results page by page.
+    ///
+    /// Pages are requested by offset, like [`execute_all`](Self::execute_all),
+    /// but are yielded as they arrive instead of being collected. Up to
+    /// [`prefetch`](Self::prefetch) pages are requested concurrently, and only
+    /// while the stream is polled: a consumer that stops pulling stops the
+    /// requests, so memory stays bounded by `prefetch` pages however large
+    /// the layer is.
+    ///
+    /// The first page is fetched alone. If the server returns fewer features
+    /// than requested while reporting `exceededTransferLimit`, its
+    /// `maxRecordCount` is lower than the page size: pages already requested
+    /// past that point are discarded, and paging resumes right after the
+    /// features actually returned, so no features are skipped.
+    ///
+    /// Services whose loaded capabilities rule out pagination are queried
+    /// once without paging parameters, and a truncated result ends the stream
+    /// with [`ErrorKind::Unsupported`](crate::ErrorKind::Unsupported).
+    ///
+    /// The stream ends after the last page or the first error.
+    #[instrument(skip(self), fields(layer_id = %self.layer_id, prefetch = self.prefetch))]
+    pub fn pages(self) -> impl Stream<Item = Result<FeatureSet>> + Send + 'static {
+        tracing::debug!("Streaming paginated query");
+        futures::stream::once(self.into_pager()).flat_map(|pager| {
+            futures::stream::unfold(pager, |mut pager| async move {
+                loop {
+                    pager.fill();
+                    let (offset, page) = pager.in_flight.next().await?;
+                    match pager.advance(offset, page) {
+                        Some(page) => return Some((page, pager)),
+                        None => continue,
+                    }
+                }
+            })
+        })
+    }
+
+    /// Prepares the pagination state for [`pages`](Self::pages), loading
+    /// the layer's capabilities first if checks are enabled.
+    async fn into_pager(mut self) -> Pager {
+        self.client.load_layer_checks(self.layer_id).await;
+        let paginate = self.client.ensure_pagination(self.layer_id).is_ok();
+        self.negotiate_format();
+
+        Pager {
+            page_size: self.page_size(),
+            client: self.client,
+            layer_id: self.layer_id,
+            params: self.params,
+            prefetch: self.prefetch,
+            paginate,
+            next_offset: 0,
+            first_page_seen: false,
+            done: false,
+            in_flight: FuturesOrdered::new(),
+        }
+    }
+
+    /// Streams the query results feature by feature.
+    ///
+    /// Flattens [`pages`](Self::pages), with the same bounded prefetch and
+    /// backpressure. Use it to pipe large layers into a file or database
+    /// without holding every feature in memory.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use arcgis::{ArcGISClient, ApiKeyAuth, FeatureServiceClient, LayerId};
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn example(service: &FeatureServiceClient) -> arcgis::Result<()> {
+    /// let mut features = std::pin::pin!(service
+    ///     .query(LayerId::new(0))
+    ///     .where_clause("1=1")
+    ///     .limit(2000)
+    ///     .prefetch(4)
+    ///     .stream());
+    ///
+    /// while let Some(feature) = features.try_next().await? {
+    ///     println!("{:?}", feature.attributes().get("OBJECTID"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Feature>> + Send + 'static {
+        self.pages()
+            .map_ok(|mut page| {
+                let features = std::mem::take(page.features_mut());
+                futures::stream::iter(features.into_iter().map(Ok))
+            })
+            .try_flatten()
+    }
+
+    /// Returns the page size for paginated queries: the requested limit,
+    /// capped at the layer's `maxRecordCount` if capabilities are loaded.
+    fn page_size(&self) -> u32 {
+        match (
+            *self.params.result_record_count(),
+            self.client.max_record_count(self.layer_id),
+        ) {
+            (Some(requested), Some(max)) => requested.min(max),
+            (Some(requested), None) => requested,
+            (None, Some(max)) => max,
+            (None, None) => DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Replaces the requested format with JSON if the service's loaded
+    /// capabilities do not list it.
+    fn negotiate_format(&mut self) {
+        let format = self
+            .client
+            .negotiate_format(self.layer_id, *self.params.format());
+        self.params.set_format(format);
+    }
+}
+
+/// Offset pagination state behind [`QueryBuilder::pages`].
+struct Pager {
+    client: FeatureServiceClient,
+    layer_id: LayerId,
+    params: FeatureQueryParams,
+    page_size: u32,
+    prefetch: usize,
+    /// Whether paging parameters are sent; `false` if the service does not
+    /// support pagination, in which case a single request is made.
+    paginate: bool,
+    next_offset: u32,
+    /// Whether the first page has arrived, after which pages are prefetched.
+    first_page_seen: bool,
+    /// Whether the last page or an error has been seen.
+    done: bool,
+    /// Requested pages with their offsets, in offset order.
+    in_flight: FuturesOrdered<BoxFuture<'static, (u32, Result<FeatureSet>)>>,
+}
+
+impl Pager {
+    /// Requests pages until `prefetch` are in flight, or one until the
+    /// first page has arrived.
+    fn fill(&mut self) {
+        let limit = if self.first_page_seen {
+            self.prefetch
+        } else {
+            1
+        };
+        while !self.done && self.in_flight.len() < limit {
+            let offset = self.next_offset;
+            let Some(next_offset) = offset.checked_add(self.page_size) else {
+                self.done = true;
+                break;
+            };
+            self.next_offset = next_offset;
+
+            let mut params = self.params.clone();
+            if self.paginate {
+                params.set_result_offset(Some(offset));
+                params.set_result_record_count(Some(self.page_size));
+            } else {
+                self.done = true;
+            }
+            tracing::debug!(
+                offset = offset,
+                page_size = self.page_size,
+                "Fetching page of results"
+            );
+
+            let client = self.client.clone();
+            let layer_id = self.layer_id;
+            self.in_flight.push_back(
+                async move { (offset, client.query_with_params(layer_id, params).await) }.boxed(),
+            );
+        }
+    }
+
+    /// Records an arrived page, returning it unless it is an empty last page.
+    ///
+    /// A page shorter than requested while `exceededTransferLimit` is set
+    /// means the server caps pages below the page size. Pages requested after
+    /// it would leave gaps, so they are dropped and paging resumes right
+    /// after the features actually returned, with the smaller page size.
+    fn advance(&mut self, offset: u32, page: Result<FeatureSet>) -> Option<Result<FeatureSet>> {
+        let page = match page {
+            Ok(page) => page,
+            Err(e) => {
+                self.finish();
+                return Some(Err(e));
+            }
+        };
+
+        let count = page.features().len();
+        let exceeded = *page.exceeded_transfer_limit();
+        tracing::debug!(
+            feature_count = count,
+            exceeded_limit = exceeded,
+            "Page retrieved"
+        );
+
+        if !self.paginate && exceeded {
+            self.finish();
+            return Some(self.client.ensure_pagination(self.layer_id).map(|()| page));
+        }
+
+        self.first_page_seen = true;
+        let returned = u32::try_from(count).unwrap_or(u32::MAX);
+        if exceeded && returned > 0 && returned < self.page_size {
+            tracing::debug!(
+                offset = offset,
+                requested = self.page_size,
+                returned = returned,
+                dropped = self.in_flight.len(),
+                "Server page size is smaller than requested, adjusting"
+            );
+            self.page_size = returned;
+            self.next_offset = offset.saturating_add(returned);
+            self.in_flight = FuturesOrdered::new();
+        }
+
+        if count == 0 || !exceeded {
+            tracing::debug!("Streaming pagination complete");
+            self.finish();
+        }
+        (count > 0).then_some(Ok(page))
+    }
+
+    /// Stops paging and drops any pages requested past the end.
+    fn finish(&mut self) {
+        self.done = true;
+        self.in_flight = FuturesOrdered::new();
+    }
 }
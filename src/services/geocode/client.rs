@@ -1,7 +1,8 @@
 //! Geocoding service client.
 
 use crate::{
-    ArcGISClient, GeocodeResponse, LocationType, Result, ReverseGeocodeResponse, SuggestResponse,
+    ArcGISClient, GeocodeResponse, LocationType, Result, ReverseGeocodeResponse, ServiceRequest,
+    SuggestResponse,
 };
 use tracing::instrument;
 
@@ -41,14 +42,15 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-pub struct GeocodeServiceClient<'a> {
+#[derive(Clone)]
+pub struct GeocodeServiceClient {
     /// Base URL of the geocoding service
     base_url: String,
-    /// Reference to the ArcGIS client for HTTP operations
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client for HTTP operations
+    client: ArcGISClient,
 }
 
-impl<'a> GeocodeServiceClient<'a> {
+impl GeocodeServiceClient {
     /// Creates a new Geocoding Service client.
     ///
     /// # Arguments
@@ -71,10 +73,13 @@ impl<'a> GeocodeServiceClient<'a> {
     /// );
     /// ```
     #[instrument(skip(base_url, client))]
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating GeocodeServiceClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+        }
     }
 
     /// Finds address candidates for a given address string.
@@ -119,33 +124,16 @@ impl<'a> GeocodeServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending findAddressCandidates request");
 
-        let mut request = self.client.http().get(&url).query(&[
-            ("SingleLine", address.as_str()),
-            ("f", "json"),
-            ("outFields", "*"),
-            ("maxLocations", "50"),
-        ]);
+        let request = ServiceRequest::get(&url)
+            .query(&[
+                ("SingleLine", address.as_str()),
+                ("f", "json"),
+                ("outFields", "*"),
+                ("maxLocations", "50"),
+            ])
+            .billable(1);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "findAddressCandidates failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let geocode_response: GeocodeResponse = response.json().await?;
+        let geocode_response: GeocodeResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             candidate_count = geocode_response.candidates().len(),
@@ -213,28 +201,9 @@ impl<'a> GeocodeServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending findAddressCandidates request");
 
-        let mut request = self.client.http().get(&url).query(&params);
+        let request = ServiceRequest::get(&url).query(&params).billable(1);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "findAddressCandidates failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let geocode_response: GeocodeResponse = response.json().await?;
+        let geocode_response: GeocodeResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             candidate_count = geocode_response.candidates().len(),
@@ -289,34 +258,17 @@ impl<'a> GeocodeServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending findAddressCandidates request");
 
-        let mut request = self.client.http().get(&url).query(&[
-            ("SingleLine", address.as_str()),
-            ("outSR", out_sr.to_string().as_str()),
-            ("f", "json"),
-            ("outFields", "*"),
-            ("maxLocations", "50"),
-        ]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "findAddressCandidates with SR failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url)
+            .query(&[
+                ("SingleLine", address.as_str()),
+                ("outSR", out_sr.to_string().as_str()),
+                ("f", "json"),
+                ("outFields", "*"),
+                ("maxLocations", "50"),
+            ])
+            .billable(1);
 
-        let geocode_response: GeocodeResponse = response.json().await?;
+        let geocode_response: GeocodeResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             candidate_count = geocode_response.candidates().len(),
@@ -427,32 +379,15 @@ impl<'a> GeocodeServiceClient<'a> {
         // Use JSON format to support any input spatial reference
         let location_param = location.to_location_json();
 
-        let mut request = self.client.http().get(&url).query(&[
-            ("location", location_param.as_str()),
-            ("outSR", Out::WKID.to_string().as_str()),
-            ("f", "json"),
-        ]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "reverseGeocode failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url)
+            .query(&[
+                ("location", location_param.as_str()),
+                ("outSR", Out::WKID.to_string().as_str()),
+                ("f", "json"),
+            ])
+            .billable(1);
 
-        let reverse_response: ReverseGeocodeResponse = response.json().await?;
+        let reverse_response: ReverseGeocodeResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             address = ?reverse_response.address().match_addr(),
@@ -500,32 +435,9 @@ impl<'a> GeocodeServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending suggest request");
 
-        let mut request = self
-            .client
-            .http()
-            .get(&url)
-            .query(&[("text", text.as_str()), ("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "suggest failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url).query(&[("text", text.as_str()), ("f", "json")]);
 
-        let suggest_response: SuggestResponse = response.json().await?;
+        let suggest_response: SuggestResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             suggestion_count = suggest_response.suggestions().len(),
@@ -580,32 +492,13 @@ impl<'a> GeocodeServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending suggest request with category filter");
 
-        let mut request = self.client.http().get(&url).query(&[
+        let request = ServiceRequest::get(&url).query(&[
             ("text", text.as_str()),
             ("category", category.as_str()),
             ("f", "json"),
         ]);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "suggest with category failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let suggest_response: SuggestResponse = response.json().await?;
+        let suggest_response: SuggestResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             suggestion_count = suggest_response.suggestions().len(),
@@ -652,6 +545,13 @@ impl<'a> GeocodeServiceClient<'a> {
 
         let url = format!("{}/geocodeAddresses", self.base_url);
 
+        let units = u32::try_from(addresses.len()).map_err(|_| {
+            crate::Error::from(crate::ErrorKind::Validation(format!(
+                "Too many addresses in one batch: {}",
+                addresses.len()
+            )))
+        })?;
+
         // Format records with attributes wrapper as required by API
         let records: Vec<serde_json::Value> = addresses
             .iter()
@@ -669,32 +569,12 @@ impl<'a> GeocodeServiceClient<'a> {
         tracing::debug!(url = %url, count = addresses.len(), "Sending geocodeAddresses request");
 
         let addresses_str = addresses_json.to_string();
-        let mut form = vec![("addresses", addresses_str.as_str()), ("f", "json")];
-
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
+        let form = vec![("addresses", addresses_str.as_str()), ("f", "json")];
 
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "geocodeAddresses failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let batch_response: crate::BatchGeocodeResponse = response.json().await?;
+        let batch_response: crate::BatchGeocodeResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form).billable(units))
+            .await?;
 
         tracing::info!(
             location_count = batch_response.locations().len(),
@@ -1,6 +1,6 @@
 //! Geometry Service client for geometric operations.
 
-use crate::{ArcGISClient, ArcGISEnvelope, ArcGISGeometry, Result};
+use crate::{ArcGISClient, ArcGISEnvelope, ArcGISGeometry, Result, ServiceRequest};
 use serde::Deserialize;
 use tracing::instrument;
 
@@ -29,14 +29,15 @@ use super::{
 /// # Ok(())
 /// # }
 /// ```
-pub struct GeometryServiceClient<'a> {
+#[derive(Clone)]
+pub struct GeometryServiceClient {
     /// Base URL of the geometry service.
     base_url: String,
-    /// Reference to the ArcGIS client for HTTP operations.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client for HTTP operations.
+    client: ArcGISClient,
 }
 
-impl<'a> GeometryServiceClient<'a> {
+impl GeometryServiceClient {
     /// Creates a new Geometry Service client.
     ///
     /// # Arguments
@@ -55,10 +56,13 @@ impl<'a> GeometryServiceClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating GeometryServiceClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+        }
     }
 
     /// Projects geometries from one spatial reference to another.
@@ -191,36 +195,17 @@ impl<'a> GeometryServiceClient<'a> {
         let out_sr_str = params.out_sr().to_string();
 
         // Use GET request with query parameters
-        let mut request = self.client.http().get(&url).query(&[
+        let request = ServiceRequest::get(&url).query(&[
             ("geometries", geometries_json.as_str()),
             ("inSR", in_sr_str.as_str()),
             ("outSR", out_sr_str.as_str()),
             ("f", "json"),
         ]);
 
-        // Add token as query parameter if required
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token.as_str())]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "project request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        crate::check_esri_error(&response_text, "project")?;
-        let result: ProjectResult = serde_json::from_str(&response_text)?;
+        let result: ProjectResult = self
+            .client
+            .execute_json(request.operation("project"))
+            .await?;
 
         tracing::info!(
             result_count = result.geometries().len(),
@@ -344,7 +329,7 @@ impl<'a> GeometryServiceClient<'a> {
         // Build base query parameters
         // Note: When using geodesic=true, the unit parameter should NOT be sent
         // The bufferSR determines the distance units
-        let mut request = self.client.http().get(&url).query(&[
+        let mut request = ServiceRequest::get(&url).query(&[
             ("geometries", geometries_param.as_str()),
             ("inSR", in_sr_str.as_str()),
             ("bufferSR", buffer_sr_str.as_str()),
@@ -366,11 +351,6 @@ impl<'a> GeometryServiceClient<'a> {
             request = request.query(&[("outSR", out_sr_str.as_str())]);
         }
 
-        // Add token as query parameter if required
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token.as_str())]);
-        }
-
         // Log all request parameters for debugging
         tracing::debug!(
             geometries = %geometries_param,
@@ -383,25 +363,10 @@ impl<'a> GeometryServiceClient<'a> {
             "Buffer request parameters"
         );
 
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "buffer request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "Buffer raw response");
-        crate::check_esri_error(&response_text, "buffer")?;
-        let result: BufferResult = serde_json::from_str(&response_text)?;
+        let result: BufferResult = self
+            .client
+            .execute_json(request.operation("buffer"))
+            .await?;
 
         tracing::info!(result_count = result.geometries().len(), "buffer completed");
 
@@ -458,45 +423,11 @@ impl<'a> GeometryServiceClient<'a> {
             ("f", "json".to_string()),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str));
-        }
-
         if let Some(extent) = extent_of_interest {
             let extent_json = serde_json::to_string(&extent)?;
             form.push(("extentOfInterest", extent_json));
         }
 
-        let response = self
-            .client
-            .http()
-            .get(&url)
-            .query(
-                &form
-                    .iter()
-                    .map(|(k, v)| (*k, v.as_str()))
-                    .collect::<Vec<_>>(),
-            )
-            .send()
-            .await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "findTransformations request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
         #[derive(Deserialize)]
         struct GeoTransformGroup {
             #[serde(rename = "geoTransforms")]
@@ -508,7 +439,17 @@ impl<'a> GeometryServiceClient<'a> {
             transformations: Vec<GeoTransformGroup>,
         }
 
-        let response_data: TransformationsResponse = response.json().await?;
+        let response_data: TransformationsResponse = self
+            .client
+            .execute_json(
+                ServiceRequest::get(&url).query(
+                    &form
+                        .iter()
+                        .map(|(k, v)| (*k, v.as_str()))
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .await?;
 
         // Flatten the nested structure
         let all_transformations: Vec<Transformation> = response_data
@@ -589,40 +530,16 @@ impl<'a> GeometryServiceClient<'a> {
         });
         let geometries_json = serde_json::to_string(&geometries_wrapper)?;
         let sr_str = params.sr().to_string();
-        let mut form = vec![
+        let form = vec![
             ("geometries", geometries_json.as_str()),
             ("sr", sr_str.as_str()),
             ("f", "json"),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "simplify request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Debug: log response body
-        let body = response.text().await?;
-        tracing::debug!(response_body = %body, "simplify response");
-
-        let result: SimplifyResult = serde_json::from_str(&body)?;
+        let result: SimplifyResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         tracing::info!(
             result_count = result.geometries().len(),
@@ -703,40 +620,16 @@ impl<'a> GeometryServiceClient<'a> {
         });
         let geometries_json = serde_json::to_string(&geometries_wrapper)?;
         let sr_str = params.sr().to_string();
-        let mut form = vec![
+        let form = vec![
             ("geometries", geometries_json.as_str()),
             ("sr", sr_str.as_str()),
             ("f", "json"),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "union request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Debug: log response body
-        let body = response.text().await?;
-        tracing::debug!(response_body = %body, "union response");
-
-        let result: UnionResult = serde_json::from_str(&body)?;
+        let result: UnionResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         tracing::info!("union completed");
 
@@ -852,33 +745,10 @@ impl<'a> GeometryServiceClient<'a> {
             form.push(("calculationType", calc.as_str()));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "areasAndLengths request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Debug: log response body
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "areasAndLengths response");
-        let result: AreasAndLengthsResult = serde_json::from_str(&response_text)?;
+        let result: AreasAndLengthsResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         tracing::info!(
             result_count = result.areas().len(),
@@ -982,7 +852,7 @@ impl<'a> GeometryServiceClient<'a> {
         );
 
         // Build GET request (like other geometry operations)
-        let mut request = self.client.http().get(&url).query(&[
+        let mut request = ServiceRequest::get(&url).query(&[
             ("geometry1", geometry1_json.as_str()),
             ("geometry2", geometry2_json.as_str()),
             ("sr", sr_str.as_str()),
@@ -1025,30 +895,10 @@ impl<'a> GeometryServiceClient<'a> {
             }
         }
 
-        // Add token as query parameter if required
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token.as_str())]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "distance request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "Distance raw response");
-        crate::check_esri_error(&response_text, "distance")?;
-        let result: DistanceResult = serde_json::from_str(&response_text)?;
+        let result: DistanceResult = self
+            .client
+            .execute_json(request.operation("distance"))
+            .await?;
 
         tracing::info!(distance = result.distance(), "distance completed");
 
@@ -1,6 +1,6 @@
 //! Geoprocessing service client implementation.
 
-use crate::{ArcGISClient, BuilderError, Result};
+use crate::{ArcGISClient, BuilderError, Result, ServiceRequest};
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -35,15 +35,15 @@ use super::types::{GPExecuteResult, GPJobInfo, GPMessage};
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct GeoprocessingServiceClient<'a> {
+pub struct GeoprocessingServiceClient {
     /// Base URL of the geoprocessing service.
     url: String,
 
-    /// Reference to the ArcGIS client.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client.
+    client: ArcGISClient,
 }
 
-impl<'a> GeoprocessingServiceClient<'a> {
+impl GeoprocessingServiceClient {
     /// Creates a new geoprocessing service client.
     ///
     /// # Arguments
@@ -63,10 +63,10 @@ impl<'a> GeoprocessingServiceClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn new(url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
         GeoprocessingServiceClient {
             url: url.into(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -128,16 +128,11 @@ impl<'a> GeoprocessingServiceClient<'a> {
             form.push((key.as_str(), value_str));
         }
 
-        let response = self
+        let result: GPExecuteResult = self
             .client
-            .http()
-            .post(&execute_url)
-            .form(&form)
-            .send()
+            .execute_json(ServiceRequest::post(&execute_url).form(&form))
             .await?;
 
-        let result: GPExecuteResult = response.json().await?;
-
         tracing::debug!(
             result_count = result.results().len(),
             message_count = result.messages().len(),
@@ -184,6 +179,25 @@ impl<'a> GeoprocessingServiceClient<'a> {
     /// ```
     #[instrument(skip(self, parameters), fields(param_count = parameters.len()))]
     pub async fn submit_job(&self, parameters: HashMap<String, Value>) -> Result<GPJobInfo> {
+        self.submit(parameters, None).await
+    }
+
+    /// Submits a job that consumes credits for `units` billable transactions.
+    #[instrument(skip(self, parameters), fields(param_count = parameters.len()))]
+    pub(crate) async fn submit_billable_job(
+        &self,
+        parameters: HashMap<String, Value>,
+        units: u32,
+    ) -> Result<GPJobInfo> {
+        self.submit(parameters, Some(units)).await
+    }
+
+    /// Submits a job, billing `units` if given.
+    async fn submit(
+        &self,
+        parameters: HashMap<String, Value>,
+        units: Option<u32>,
+    ) -> Result<GPJobInfo> {
         tracing::debug!("Submitting asynchronous geoprocessing job");
 
         let submit_url = format!("{}/submitJob", self.url);
@@ -192,12 +206,6 @@ impl<'a> GeoprocessingServiceClient<'a> {
         let mut form: Vec<(&str, String)> = Vec::new();
         form.push(("f", "json".to_string()));
 
-        // Add authentication token if required
-        if let Some(token) = self.client.get_token_if_required().await? {
-            tracing::debug!("Adding authentication token to request");
-            form.push(("token", token));
-        }
-
         // Serialize parameters
         for (key, value) in parameters.iter() {
             let value_str = match value {
@@ -218,30 +226,11 @@ impl<'a> GeoprocessingServiceClient<'a> {
             form.push((key.as_str(), value_str));
         }
 
-        let response = self
-            .client
-            .http()
-            .post(&submit_url)
-            .form(&form)
-            .send()
-            .await?;
-
-        let status = response.status();
-        tracing::debug!(status_code = %status, "Received response");
-
-        // Get response text for better error reporting
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "Raw response body");
-
-        // Try to parse as GPJobInfo
-        let job_info: GPJobInfo = serde_json::from_str(&response_text).map_err(|e| {
-            tracing::error!(
-                parse_error = %e,
-                response = %response_text,
-                "Failed to parse job submission response"
-            );
-            e
-        })?;
+        let mut request = ServiceRequest::post(&submit_url).form(&form);
+        if let Some(units) = units {
+            request = request.billable(units);
+        }
+        let job_info: GPJobInfo = self.client.execute_json(request).await?;
 
         tracing::info!(
             job_id = %job_info.job_id(),
@@ -285,41 +274,13 @@ impl<'a> GeoprocessingServiceClient<'a> {
         tracing::debug!(url = %status_url, "Getting job status from URL");
 
         // Build query parameters
-        let mut query_params = vec![("f", "json")];
-
-        // Add authentication token if required
-        let token_string;
-        if let Some(token) = self.client.get_token_if_required().await? {
-            tracing::debug!("Adding authentication token to status request");
-            token_string = token;
-            query_params.push(("token", token_string.as_str()));
-        }
+        let query_params = vec![("f", "json")];
 
-        let response = self
+        let job_info: GPJobInfo = self
             .client
-            .http()
-            .get(&status_url)
-            .query(&query_params)
-            .send()
+            .execute_json(ServiceRequest::get(&status_url).query(&query_params))
             .await?;
 
-        let status = response.status();
-        tracing::debug!(status_code = %status, "Received status response");
-
-        // Get response text for better error reporting
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "Raw status response body");
-
-        // Try to parse as GPJobInfo
-        let job_info: GPJobInfo = serde_json::from_str(&response_text).map_err(|e| {
-            tracing::error!(
-                parse_error = %e,
-                response = %response_text,
-                "Failed to parse job status response"
-            );
-            e
-        })?;
-
         tracing::debug!(status = ?job_info.job_status(), "Job status retrieved");
 
         Ok(job_info)
@@ -402,16 +363,11 @@ impl<'a> GeoprocessingServiceClient<'a> {
 
         let cancel_url = format!("{}/jobs/{}/cancel", self.url, job_id);
 
-        let response = self
+        let job_info: GPJobInfo = self
             .client
-            .http()
-            .post(&cancel_url)
-            .form(&[("f", "json")])
-            .send()
+            .execute_json(ServiceRequest::post(&cancel_url).form(&[("f", "json")]))
             .await?;
 
-        let job_info: GPJobInfo = response.json().await?;
-
         tracing::info!(status = ?job_info.job_status(), "Job cancel requested");
 
         Ok(job_info)
@@ -457,26 +413,13 @@ impl<'a> GeoprocessingServiceClient<'a> {
         }
 
         // Build query parameters
-        let mut query_params = vec![("f", "json")];
-
-        // Add authentication token if required
-        let token_string;
-        if let Some(token) = self.client.get_token_if_required().await? {
-            tracing::debug!("Adding authentication token to messages request");
-            token_string = token;
-            query_params.push(("token", token_string.as_str()));
-        }
+        let query_params = vec![("f", "json")];
 
-        let response = self
+        let messages_response: MessagesResponse = self
             .client
-            .http()
-            .get(&messages_url)
-            .query(&query_params)
-            .send()
+            .execute_json(ServiceRequest::get(&messages_url).query(&query_params))
             .await?;
 
-        let messages_response: MessagesResponse = response.json().await?;
-
         tracing::debug!(
             message_count = messages_response.messages.len(),
             "Messages retrieved"
@@ -521,41 +464,13 @@ impl<'a> GeoprocessingServiceClient<'a> {
         tracing::debug!(url = %result_url, "Fetching result data from URL");
 
         // Build query parameters
-        let mut query_params = vec![("f", "json")];
-
-        // Add authentication token if required
-        let token_string;
-        if let Some(token) = self.client.get_token_if_required().await? {
-            tracing::debug!("Adding authentication token to result data request");
-            token_string = token;
-            query_params.push(("token", token_string.as_str()));
-        }
+        let query_params = vec![("f", "json")];
 
-        let response = self
+        let result_json: Value = self
             .client
-            .http()
-            .get(&result_url)
-            .query(&query_params)
-            .send()
+            .execute_json(ServiceRequest::get(&result_url).query(&query_params))
             .await?;
 
-        let status = response.status();
-        tracing::debug!(status_code = %status, "Received result data response");
-
-        // Get response text
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "Raw result data response");
-
-        // Parse as JSON
-        let result_json: Value = serde_json::from_str(&response_text).map_err(|e| {
-            tracing::error!(
-                parse_error = %e,
-                response = %response_text,
-                "Failed to parse result data response"
-            );
-            e
-        })?;
-
         tracing::debug!("Result data retrieved and parsed");
 
         Ok(result_json)
@@ -1,6 +1,6 @@
 //! Image service client implementation.
 
-use crate::{ArcGISClient, ArcGISGeometry, Result};
+use crate::{ArcGISClient, ArcGISGeometry, Result, ServiceRequest};
 use tracing::instrument;
 
 use super::types::{
@@ -33,15 +33,15 @@ use super::types::{
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct ImageServiceClient<'a> {
+pub struct ImageServiceClient {
     /// Base URL of the image service.
     url: String,
 
-    /// Reference to the ArcGIS client.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client.
+    client: ArcGISClient,
 }
 
-impl<'a> ImageServiceClient<'a> {
+impl ImageServiceClient {
     /// Creates a new image service client.
     ///
     /// # Arguments
@@ -61,10 +61,10 @@ impl<'a> ImageServiceClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn new(url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
         ImageServiceClient {
             url: url.into(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -111,17 +111,15 @@ impl<'a> ImageServiceClient<'a> {
 
         let export_url = format!("{}/exportImage", self.url);
 
-        let response = self
+        let result: ExportImageResult = self
             .client
-            .http()
-            .get(&export_url)
-            .query(&params)
-            .query(&[("f", "json")])
-            .send()
+            .execute_json(
+                ServiceRequest::get(&export_url)
+                    .query(&params)
+                    .query(&[("f", "json")]),
+            )
             .await?;
 
-        let result: ExportImageResult = response.json().await?;
-
         tracing::debug!(url = %result.href(), "Image exported");
 
         Ok(result)
@@ -174,20 +172,15 @@ impl<'a> ImageServiceClient<'a> {
             _ => "esriGeometryPoint",
         };
 
-        let response = self
+        let result: ImageIdentifyResult = self
             .client
-            .http()
-            .get(&identify_url)
-            .query(&[
+            .execute_json(ServiceRequest::get(&identify_url).query(&[
                 ("f", "json"),
                 ("geometry", &geometry_json),
                 ("geometryType", geometry_type),
-            ])
-            .send()
+            ]))
             .await?;
 
-        let result: ImageIdentifyResult = response.json().await?;
-
         tracing::debug!("Identification complete");
 
         Ok(result)
@@ -267,16 +260,11 @@ impl<'a> ImageServiceClient<'a> {
             query_params.push(("renderingRule", rendering_json));
         }
 
-        let response = self
+        let result: ImageIdentifyResult = self
             .client
-            .http()
-            .get(&identify_url)
-            .query(&query_params)
-            .send()
+            .execute_json(ServiceRequest::get(&identify_url).query(&query_params))
             .await?;
 
-        let result: ImageIdentifyResult = response.json().await?;
-
         tracing::debug!("Identification complete");
 
         Ok(result)
@@ -325,17 +313,15 @@ impl<'a> ImageServiceClient<'a> {
 
         let samples_url = format!("{}/getSamples", self.url);
 
-        let response = self
+        let result: SampleResult = self
             .client
-            .http()
-            .get(&samples_url)
-            .query(&params)
-            .query(&[("f", "json")])
-            .send()
+            .execute_json(
+                ServiceRequest::get(&samples_url)
+                    .query(&params)
+                    .query(&[("f", "json")]),
+            )
             .await?;
 
-        let result: SampleResult = response.json().await?;
-
         tracing::debug!(count = result.samples().len(), "Samples retrieved");
 
         Ok(result)
@@ -381,17 +367,15 @@ impl<'a> ImageServiceClient<'a> {
 
         let histogram_url = format!("{}/computeHistograms", self.url);
 
-        let response = self
+        let result: HistogramResult = self
             .client
-            .http()
-            .get(&histogram_url)
-            .query(&params)
-            .query(&[("f", "json")])
-            .send()
+            .execute_json(
+                ServiceRequest::get(&histogram_url)
+                    .query(&params)
+                    .query(&[("f", "json")]),
+            )
             .await?;
 
-        let result: HistogramResult = response.json().await?;
-
         tracing::debug!(bands = result.histograms().len(), "Histograms computed");
 
         Ok(result)
@@ -429,16 +413,15 @@ impl<'a> ImageServiceClient<'a> {
     pub async fn get_raster_info(&self) -> Result<RasterInfo> {
         tracing::debug!("Getting raster info");
 
-        let response = self
+        let info: RasterInfo = self
             .client
-            .http()
-            .get(&self.url)
-            .query(&[("f", "json")])
-            .send()
+            .execute_json(
+                ServiceRequest::get(&self.url)
+                    .query(&[("f", "json")])
+                    .cacheable(),
+            )
             .await?;
 
-        let info: RasterInfo = response.json().await?;
-
         tracing::debug!("Raster info retrieved");
 
         Ok(info)
@@ -3,7 +3,7 @@
 use super::ResponseFormat;
 use crate::{
     ArcGISClient, ExportMapParams, ExportMapResponse, ExportResult, ExportTarget, IdentifyParams,
-    IdentifyResponse, LegendResponse, MapServiceMetadata, Result, TileCoordinate,
+    IdentifyResponse, LegendResponse, MapServiceMetadata, Result, ServiceRequest, TileCoordinate,
 };
 use futures::StreamExt;
 use tokio::io::AsyncWriteExt;
@@ -38,14 +38,15 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-pub struct MapServiceClient<'a> {
+#[derive(Clone)]
+pub struct MapServiceClient {
     /// Base URL of the map service.
     base_url: String,
-    /// Reference to the ArcGIS client for HTTP operations.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client for HTTP operations.
+    client: ArcGISClient,
 }
 
-impl<'a> MapServiceClient<'a> {
+impl MapServiceClient {
     /// Creates a new Map Service client.
     ///
     /// # Arguments
@@ -67,10 +68,13 @@ impl<'a> MapServiceClient<'a> {
     /// );
     /// ```
     #[instrument(skip(base_url, client))]
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating MapServiceClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+        }
     }
 
     /// Creates a fluent builder for exporting maps.
@@ -108,9 +112,9 @@ impl<'a> MapServiceClient<'a> {
     /// # }
     /// ```
     #[instrument(skip(self), fields(base_url = %self.base_url))]
-    pub fn export(&'a self) -> super::ExportMapBuilder<'a> {
+    pub fn export(&self) -> super::ExportMapBuilder {
         tracing::debug!("Creating export builder");
-        super::ExportMapBuilder::new(self)
+        super::ExportMapBuilder::new(self.clone())
     }
 
     /// Exports a map with pre-built parameters.
@@ -168,7 +172,6 @@ impl<'a> MapServiceClient<'a> {
         }
 
         let url = format!("{}/export", self.base_url);
-        let token_opt = self.client.get_token_if_required().await?;
 
         tracing::debug!(url = %url, "Sending export request");
 
@@ -176,13 +179,11 @@ impl<'a> MapServiceClient<'a> {
         match params.format_response() {
             ResponseFormat::Image => {
                 // Direct binary response - stream immediately
-                self.stream_export(&url, &params, token_opt.as_deref(), target)
-                    .await
+                self.stream_export(&url, &params, target).await
             }
             ResponseFormat::Json | ResponseFormat::PJson => {
                 // JSON response with href - fetch image from href
-                self.export_via_json(&url, &params, token_opt.as_deref(), target)
-                    .await
+                self.export_via_json(&url, &params, target).await
             }
             _ => {
                 tracing::error!(format = ?params.format_response(), "Unsupported response format");
@@ -243,26 +244,9 @@ impl<'a> MapServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending tile request");
 
-        let mut request = self.client.http().get(&url);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
+        let request = ServiceRequest::get(&url);
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "export_tile failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let response = self.client.execute(request).await?;
 
         // Stream tile to target
         self.stream_to_target(response, target).await
@@ -302,28 +286,12 @@ impl<'a> MapServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending legend request");
 
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "get_legend failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let legend: LegendResponse = Self::parse_json_response(response, "legend").await?;
+        let legend: LegendResponse = self
+            .client
+            .execute_json(request.operation("legend"))
+            .await?;
 
         tracing::info!(layer_count = legend.layers().len(), "Legend retrieved");
 
@@ -362,28 +330,12 @@ impl<'a> MapServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending metadata request");
 
-        let mut request = self.client.http().get(url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "get_metadata failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(url).query(&[("f", "json")]);
 
-        let metadata: MapServiceMetadata = Self::parse_json_response(response, "metadata").await?;
+        let metadata: MapServiceMetadata = self
+            .client
+            .execute_json(request.operation("metadata").cacheable())
+            .await?;
 
         tracing::info!(layers = metadata.layers().len(), "Metadata retrieved");
 
@@ -437,29 +389,12 @@ impl<'a> MapServiceClient<'a> {
 
         tracing::debug!(url = %url, "Sending identify request");
 
-        let mut request = self.client.http().get(&url).query(&params);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
+        let request = ServiceRequest::get(&url).query(&params);
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "identify failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let identify_response: IdentifyResponse =
-            Self::parse_json_response(response, "identify").await?;
+        let identify_response: IdentifyResponse = self
+            .client
+            .execute_json(request.operation("identify"))
+            .await?;
 
         tracing::info!(
             result_count = identify_response.results().len(),
@@ -472,73 +407,35 @@ impl<'a> MapServiceClient<'a> {
     // === Helper methods ===
 
     /// Streams export response directly (for ResponseFormat::Image).
-    #[instrument(skip(self, params, token, target))]
+    #[instrument(skip(self, params, target))]
     async fn stream_export(
         &self,
         url: &str,
         params: &ExportMapParams,
-        token: Option<&str>,
         target: ExportTarget,
     ) -> Result<ExportResult> {
         tracing::debug!("Streaming export (direct image response)");
 
-        let mut request = self.client.http().get(url).query(&params);
+        let request = ServiceRequest::get(url).query(&params);
 
-        if let Some(token) = token {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "stream_export failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let response = self.client.execute(request).await?;
 
         self.stream_to_target(response, target).await
     }
 
     /// Exports via JSON response (fetches href).
-    #[instrument(skip(self, params, token, target))]
+    #[instrument(skip(self, params, target))]
     async fn export_via_json(
         &self,
         url: &str,
         params: &ExportMapParams,
-        token: Option<&str>,
         target: ExportTarget,
     ) -> Result<ExportResult> {
         tracing::debug!("Exporting via JSON (will fetch href)");
 
-        let mut request = self.client.http().get(url).query(&params);
+        let request = ServiceRequest::get(url).query(&params);
 
-        if let Some(token) = token {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "export_via_json failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let export_response: ExportMapResponse = response.json().await?;
+        let export_response: ExportMapResponse = self.client.execute_json(request).await?;
 
         tracing::debug!(
             href = %export_response.href(),
@@ -563,26 +460,9 @@ impl<'a> MapServiceClient<'a> {
         tracing::debug!(resolved_url = %image_url, "Fetching image from URL");
 
         // Fetch the actual image from href
-        let mut image_request = self.client.http().get(&image_url);
+        let image_request = ServiceRequest::get(&image_url);
 
-        if let Some(token) = token {
-            image_request = image_request.query(&[("token", token)]);
-        }
-
-        let image_response = image_request.send().await?;
-
-        let status = image_response.status();
-        if !status.is_success() {
-            let error_text = image_response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "Image fetch failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let image_response = self.client.execute(image_request).await?;
 
         self.stream_to_target(image_response, target).await
     }
@@ -692,33 +572,12 @@ impl<'a> MapServiceClient<'a> {
 
         tracing::debug!(url = %url, search_text = %params.search_text(), "Sending find request");
 
-        let mut request = self
-            .client
-            .http()
-            .get(&url)
+        let request = ServiceRequest::get(&url)
             .query(&params)
             .query(&[("f", "json")]);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "find request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: crate::FindResponse = Self::parse_json_response(response, "find").await?;
+        let result: crate::FindResponse =
+            self.client.execute_json(request.operation("find")).await?;
 
         tracing::info!(result_count = result.results().len(), "Find completed");
 
@@ -762,35 +621,11 @@ impl<'a> MapServiceClient<'a> {
 
         tracing::debug!(url = %url, doc_name = %params.doc_name(), "Sending generateKml request");
 
-        let form = serde_urlencoded::to_string(&params)?;
-        let mut request = self
-            .client
-            .http()
-            .get(&url)
+        let request = ServiceRequest::post(&url)
             .query(&[("f", "kmz")]) // KML format
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(form);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+            .form(&params);
 
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "generateKml request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let kml = response.text().await?;
+        let kml = self.client.execute_text(request).await?;
 
         tracing::info!(kml_length = kml.len(), "KML generation completed");
 
@@ -846,32 +681,12 @@ impl<'a> MapServiceClient<'a> {
         );
 
         let params_json = serde_json::to_string(&params)?;
-        let mut form = vec![("classificationDef", params_json.as_str()), ("f", "json")];
-
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "generateRenderer request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let form = vec![("classificationDef", params_json.as_str()), ("f", "json")];
 
-        let result: crate::RendererResponse = response.json().await?;
+        let result: crate::RendererResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         tracing::info!("Renderer generation completed");
 
@@ -927,34 +742,18 @@ impl<'a> MapServiceClient<'a> {
 
         let mut form = vec![("f", "json")];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
         if !layers_str.is_empty() {
             form.push(("layers", &layers_str));
         }
 
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "queryDomains request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: crate::QueryDomainsResponse =
-            Self::parse_json_response(response, "queryDomains").await?;
+        let result: crate::QueryDomainsResponse = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .operation("queryDomains")
+                    .form(&form),
+            )
+            .await?;
 
         tracing::info!(
             layer_count = result.layers().len(),
@@ -963,94 +762,4 @@ impl<'a> MapServiceClient<'a> {
 
         Ok(result)
     }
-
-    /// Attempts to parse a JSON response, handling HTML error pages gracefully.
-    ///
-    /// ArcGIS servers sometimes return HTTP 200 with HTML error pages instead of JSON.
-    /// This helper detects such cases and provides clear error messages while preserving
-    /// the original error content in the error chain.
-    ///
-    /// # Arguments
-    ///
-    /// * `response` - The HTTP response to parse
-    /// * `operation` - Name of the operation (for error messages)
-    async fn parse_json_response<T>(response: reqwest::Response, operation: &str) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        // Extract content-type before consuming response
-        let content_type = response
-            .headers()
-            .get(reqwest::header::CONTENT_TYPE)
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| String::from(""));
-
-        // If content-type indicates HTML, extract error message
-        if content_type.contains("text/html") {
-            let html_body = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read HTML error response: {}", e));
-
-            tracing::error!(
-                content_type = %content_type,
-                operation = %operation,
-                body_preview = %&html_body.chars().take(200).collect::<String>(),
-                "Server returned HTML error page instead of JSON"
-            );
-
-            // Try to extract a meaningful error message from HTML
-            let error_msg = extract_html_error(&html_body)
-                .unwrap_or_else(|| "Server returned HTML error page".to_string());
-
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: 400,
-                message: format!(
-                    "{} operation not supported: {}. HTML response: {}",
-                    operation,
-                    error_msg,
-                    &html_body.chars().take(200).collect::<String>()
-                ),
-            }));
-        }
-
-        // Try to parse as JSON, providing context if it fails
-        response.json().await.map_err(|e| {
-            tracing::error!(
-                error = %e,
-                operation = %operation,
-                content_type = %content_type,
-                "Failed to parse response as JSON"
-            );
-            crate::Error::from(crate::ErrorKind::Http(crate::HttpError::new(e)))
-        })
-    }
-}
-
-/// Extracts a meaningful error message from an HTML error page.
-///
-/// Looks for common patterns in ArcGIS Server error pages.
-fn extract_html_error(html: &str) -> Option<String> {
-    // Look for common error message patterns
-    let patterns = [
-        ("<p><b>Message:</b> <u>", "</u></p>"),
-        ("<p><b>Description:</b> <u>", "</u></p>"),
-        ("<h2>", "</h2>"),
-        ("<h1>", "</h1>"),
-    ];
-
-    for (start, end) in patterns {
-        if let Some(start_idx) = html.find(start) {
-            let content_start = start_idx + start.len();
-            if let Some(end_idx) = html[content_start..].find(end) {
-                let msg = &html[content_start..content_start + end_idx];
-                if !msg.trim().is_empty() {
-                    return Some(msg.trim().to_string());
-                }
-            }
-        }
-    }
-
-    None
 }
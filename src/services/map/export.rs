@@ -35,17 +35,17 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-pub struct ExportMapBuilder<'a> {
-    client: &'a MapServiceClient<'a>,
+pub struct ExportMapBuilder {
+    client: MapServiceClient,
     params: ExportMapParams,
 }
 
-impl<'a> ExportMapBuilder<'a> {
+impl ExportMapBuilder {
     /// Creates a new export builder.
     ///
     /// Typically you don't call this directly - use [`MapServiceClient::export`] instead.
     #[instrument(skip(client))]
-    pub(crate) fn new(client: &'a MapServiceClient<'a>) -> Self {
+    pub(crate) fn new(client: MapServiceClient) -> Self {
         tracing::debug!("Creating ExportMapBuilder");
         Self {
             client,
@@ -60,7 +60,7 @@ impl<'a> ExportMapBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{MapServiceClient, ExportTarget};
-    /// # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
     /// service
     ///     .export()
     ///     .bbox("-180,-90,180,90")
@@ -90,7 +90,7 @@ impl<'a> ExportMapBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{MapServiceClient, ExportTarget};
-    /// # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
     /// service
     ///     .export()
     ///     .bbox("-118,34,-117,35")
@@ -112,7 +112,7 @@ impl<'a> ExportMapBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{MapServiceClient, ExportTarget, LayerOperation};
-    /// # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
     /// service
     ///     .export()
     ///     .bbox("-118,34,-117,35")
@@ -147,7 +147,7 @@ impl<'a> ExportMapBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{MapServiceClient, ExportTarget};
-    /// # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
     /// service
     ///     .export()
     ///     .bbox("-118,34,-117,35")
@@ -183,7 +183,7 @@ impl<'a> ExportMapBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{MapServiceClient, ExportTarget, ImageFormat};
-    /// # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
     /// service
     ///     .export()
     ///     .bbox("-118,34,-117,35")
@@ -205,7 +205,7 @@ impl<'a> ExportMapBuilder<'a> {
     /// # Example
     /// ```no_run
     /// # use arcgis::{MapServiceClient, ExportTarget, ImageFormat};
-    /// # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
     /// service
     ///     .export()
     ///     .bbox("-118,34,-117,35")
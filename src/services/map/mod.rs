@@ -59,7 +59,7 @@
 //!
 //! ```no_run
 //! # use arcgis::{MapServiceClient, ExportTarget};
-//! # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+//! # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
 //! // To file
 //! let result = service
 //!     .export()
@@ -97,7 +97,7 @@
 //!
 //! ```no_run
 //! # use arcgis::{MapServiceClient, ExportTarget, LayerOperation};
-//! # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+//! # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
 //! // Show only specific layers
 //! service
 //!     .export()
@@ -123,7 +123,7 @@
 //!
 //! ```no_run
 //! # use arcgis::{MapServiceClient, TileCoordinate, ExportTarget};
-//! # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+//! # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
 //! let coord = TileCoordinate::new(5, 10, 15);  // level, row, col
 //! let result = service
 //!     .export_tile(coord, ExportTarget::to_bytes())
@@ -138,7 +138,7 @@
 //!
 //! ```no_run
 //! # use arcgis::{MapServiceClient, IdentifyParamsBuilder, LayerSelection, GeometryType};
-//! # async fn example(service: &MapServiceClient<'_>) -> arcgis::Result<()> {
+//! # async fn example(service: &MapServiceClient) -> arcgis::Result<()> {
 //! let params = IdentifyParamsBuilder::default()
 //!     .geometry("{\"x\":-118.0,\"y\":34.0}".to_string())
 //!     .geometry_type(GeometryType::Point)
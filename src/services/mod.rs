@@ -1,5 +1,6 @@
 //! ArcGIS service modules.
 
+mod directory;
 mod elevation;
 mod feature;
 mod geocode;
@@ -13,21 +14,25 @@ mod routing;
 mod vector_tile;
 mod version_management;
 
+pub use directory::{
+    DirectoryFolder, DirectoryLayer, DirectoryListing, DirectoryService, ServiceClient,
+    ServiceEntry, ServiceType, ServicesDirectoryClient,
+};
 pub use elevation::{
     DemResolution, ElevationClient, ElevationPoint, ProfileParameters, ProfileParametersBuilder,
     ProfileResult, SummarizeElevationParameters, SummarizeElevationParametersBuilder,
     SummarizeElevationResult, ViewshedParameters, ViewshedParametersBuilder, ViewshedResult,
 };
 pub use feature::{
-    AddAttachmentResult, AttachmentInfo, AttachmentInfosResponse, AttachmentSource,
+    AddAttachmentResult, AttachmentInfo, AttachmentInfosResponse, AttachmentSource, AuthInfo,
     CalculateResult, CodedValue, DeleteAttachmentResult, DeleteAttachmentsResponse, Domain,
-    DownloadResult, DownloadTarget, EditError, EditOptions, EditResult, EditResultItem, Feature,
-    FeatureQueryParams, FeatureQueryParamsBuilder, FeatureServiceClient, FeatureSet,
-    FeatureStatisticsResponse, FieldCalculation, LayerDomainInfo, QueryBuilder,
+    DownloadResult, DownloadTarget, EditError, EditFailure, EditOperation, EditOptions, EditResult,
+    EditResultItem, Feature, FeatureQueryParams, FeatureQueryParamsBuilder, FeatureServiceClient,
+    FeatureSet, FeatureStatisticsResponse, FieldCalculation, LayerDomainInfo, QueryBuilder,
     QueryDomainsResponse, RelatedRecordGroup, RelatedRecordsParams, RelatedRecordsParamsBuilder,
     RelatedRecordsResponse, RelationshipClass, RelationshipRule, RelationshipsResponse,
-    ResponseFormat, StatisticDefinition, StatisticType, Subtype, TopFeaturesParams,
-    TopFeaturesParamsBuilder, TopFilter, TruncateResult, UpdateAttachmentResult,
+    ResponseFormat, ServerInfo, ServiceCapabilities, StatisticDefinition, StatisticType, Subtype,
+    TopFeaturesParams, TopFeaturesParamsBuilder, TopFilter, TruncateResult, UpdateAttachmentResult,
 };
 pub use geocode::{
     AddressCandidate, BatchGeocodeRecord, BatchGeocodeResponse, BatchLocation, Category, Extent,
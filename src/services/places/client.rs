@@ -1,6 +1,6 @@
 //! Places service client implementation.
 
-use crate::{ArcGISClient, Result};
+use crate::{ArcGISClient, Result, ServiceRequest};
 use tracing::instrument;
 
 use super::types::{
@@ -36,15 +36,15 @@ use super::types::{
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct PlacesClient<'a> {
+pub struct PlacesClient {
     /// Base URL of the places service.
     url: String,
 
-    /// Reference to the ArcGIS client.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client.
+    client: ArcGISClient,
 }
 
-impl<'a> PlacesClient<'a> {
+impl PlacesClient {
     /// Creates a new places service client.
     ///
     /// # Arguments
@@ -62,10 +62,10 @@ impl<'a> PlacesClient<'a> {
     /// let client = ArcGISClient::new(auth);
     /// let places = PlacesClient::new(&client);
     /// ```
-    pub fn new(client: &'a ArcGISClient) -> Self {
+    pub fn new(client: &ArcGISClient) -> Self {
         PlacesClient {
             url: "https://places-api.arcgis.com/arcgis/rest/services/places-service/v1".to_string(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -88,10 +88,10 @@ impl<'a> PlacesClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn with_url(url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn with_url(url: impl Into<String>, client: &ArcGISClient) -> Self {
         PlacesClient {
             url: url.into(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -148,24 +148,16 @@ impl<'a> PlacesClient<'a> {
 
         let search_url = format!("{}/places/near-point", self.url);
 
-        let mut request = self
-            .client
-            .http()
-            .get(&search_url)
+        let request = ServiceRequest::get(&search_url)
             .query(&[
                 ("x", params.x().to_string()),
                 ("y", params.y().to_string()),
                 ("f", "json".to_string()),
             ])
-            .query(&params);
+            .query(&params)
+            .billable(1);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let result: PlaceSearchResult = response.json().await?;
+        let result: PlaceSearchResult = self.client.execute_json(request).await?;
 
         tracing::debug!(count = result.results().len(), "Places found");
 
@@ -208,15 +200,11 @@ impl<'a> PlacesClient<'a> {
 
         let details_url = format!("{}/places/{}", self.url, place_id);
 
-        let mut request = self.client.http().get(&details_url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+        let request = ServiceRequest::get(&details_url)
+            .query(&[("f", "json")])
+            .billable(1);
 
-        let response = request.send().await?;
-
-        let result: PlaceDetailsResult = response.json().await?;
+        let result: PlaceDetailsResult = self.client.execute_json(request).await?;
 
         tracing::debug!(place_id = %place_id, "Place details retrieved");
 
@@ -259,19 +247,9 @@ impl<'a> PlacesClient<'a> {
 
         let categories_url = format!("{}/categories", self.url);
 
-        let mut request = self
-            .client
-            .http()
-            .get(&categories_url)
-            .query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
+        let request = ServiceRequest::get(&categories_url).query(&[("f", "json")]);
 
-        let result: CategoriesResult = response.json().await?;
+        let result: CategoriesResult = self.client.execute_json(request).await?;
 
         tracing::debug!(count = result.categories().len(), "Categories retrieved");
 
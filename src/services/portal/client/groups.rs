@@ -5,11 +5,11 @@ use super::super::{
     UpdateGroupParams,
 };
 use super::PortalClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use serde::Serialize;
 use tracing::instrument;
 
-impl<'a> PortalClient<'a> {
+impl PortalClient {
     /// Searches for portal groups.
     ///
     /// Supports Lucene query syntax for flexible group discovery.
@@ -18,7 +18,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, GroupSearchParameters};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// // Search for public groups with "GIS" in the title
     /// let results = portal
     ///     .search_groups(GroupSearchParameters::new("title:GIS AND access:public"))
@@ -36,7 +36,6 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/groups", self.base_url);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending searchGroups request");
 
         // Build query parameters
@@ -52,12 +51,8 @@ impl<'a> PortalClient<'a> {
             #[serde(skip_serializing_if = "Option::is_none")]
             num: Option<u32>,
             f: &'static str,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            token: Option<String>,
         }
 
-        let token_opt = self.client.get_token_if_required().await?;
-
         let query = GroupSearchQuery {
             q: params.query(),
             sort_field: params.sort_field().as_deref(),
@@ -68,28 +63,13 @@ impl<'a> PortalClient<'a> {
             start: *params.start(),
             num: *params.num(),
             f: "json",
-            token: token_opt,
         };
 
         // Build request
-        let response = self.client.http().get(&url).query(&query).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "searchGroups request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: GroupSearchResult = response.json().await?;
+        let result: GroupSearchResult = self
+            .client
+            .execute_json(ServiceRequest::get(&url).query(&query))
+            .await?;
 
         tracing::debug!(
             total = result.total(),
@@ -108,7 +88,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let group = portal.get_group("abc123def456").await?;
     /// println!("Title: {}", group.title());
     /// println!("Owner: {}", group.owner());
@@ -123,38 +103,14 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/groups/{}", self.base_url, group_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending getGroup request");
 
         // Build request
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "getGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "Raw getGroup response");
-
-        // Check for ESRI error in response body
-        crate::check_esri_error(&response_text, "getGroup")?;
-
-        let group: GroupInfo = serde_json::from_str(&response_text)?;
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
+        let group: GroupInfo = self
+            .client
+            .execute_json(request.operation("getGroup"))
+            .await?;
 
         tracing::debug!(title = %group.title(), owner = %group.owner(), "Got group");
 
@@ -167,7 +123,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, CreateGroupParams};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = CreateGroupParams::new("My Project Group")
     ///     .with_description("Collaboration group for GIS project")
     ///     .with_tags(vec!["project".to_string(), "collaboration".to_string()])
@@ -184,7 +140,6 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/createGroup", self.base_url);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending createGroup request");
 
         // Build form data
@@ -192,11 +147,6 @@ impl<'a> PortalClient<'a> {
             .text("f", "json")
             .text("title", params.title().to_string());
 
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
         if let Some(desc) = params.description() {
             form = form.text("description", desc.to_string());
         }
@@ -234,30 +184,14 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "createGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "Raw createGroup response");
-
-        // Check for ESRI error in response body
-        crate::check_esri_error(&response_text, "createGroup")?;
-
-        let result: GroupResult = serde_json::from_str(&response_text)?;
+        let result: GroupResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("createGroup"),
+            )
+            .await?;
 
         tracing::debug!(success = result.success(), group_id = ?result.group_id(), "Group created");
 
@@ -270,7 +204,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, UpdateGroupParams};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = UpdateGroupParams::new()
     ///     .with_title("Updated Group Title")
     ///     .with_description("Updated description");
@@ -291,17 +225,11 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/groups/{}/update", self.base_url, group_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending updateGroup request");
 
         // Build form data
         let mut form = reqwest::multipart::Form::new().text("f", "json");
 
-        // Add token if required by auth provider
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
         if let Some(title) = params.title() {
             form = form.text("title", title.to_string());
         }
@@ -339,24 +267,10 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "updateGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: GroupResult = response.json().await?;
+        let result: GroupResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
 
         tracing::debug!(success = result.success(), "Group updated");
 
@@ -371,7 +285,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.delete_group("abc123def456").await?;
     /// println!("Delete success: {}", result.success());
     /// # Ok(())
@@ -384,49 +298,16 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/groups/{}/delete", self.base_url, group_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending deleteGroup request");
 
         // Build request
-        let mut form_data = vec![("f", "json")];
-
-        // Add token if required by auth provider
+        let form_data = vec![("f", "json")];
 
-        let token_opt = self.client.get_token_if_required().await?;
-
-        let token_str;
-
-        if let Some(token) = token_opt {
-            token_str = token;
-
-            form_data.push(("token", token_str.as_str()));
-        }
-
-        let response = self
+        let result: GroupResult = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_data)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_data))
             .await?;
 
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "deleteGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: GroupResult = response.json().await?;
-
         tracing::debug!(success = result.success(), "Group deleted");
 
         Ok(result)
@@ -438,7 +319,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.join_group("abc123def456").await?;
     /// println!("Joined: {}", result.success());
     /// # Ok(())
@@ -451,49 +332,16 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/groups/{}/join", self.base_url, group_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending joinGroup request");
 
         // Build request
-        let mut form_data = vec![("f", "json")];
-
-        // Add token if required by auth provider
+        let form_data = vec![("f", "json")];
 
-        let token_opt = self.client.get_token_if_required().await?;
-
-        let token_str;
-
-        if let Some(token) = token_opt {
-            token_str = token;
-
-            form_data.push(("token", token_str.as_str()));
-        }
-
-        let response = self
+        let result: GroupResult = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_data)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_data))
             .await?;
 
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "joinGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: GroupResult = response.json().await?;
-
         tracing::debug!(success = result.success(), "Joined group");
 
         Ok(result)
@@ -505,7 +353,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.leave_group("abc123def456").await?;
     /// println!("Left: {}", result.success());
     /// # Ok(())
@@ -518,49 +366,16 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/community/groups/{}/leave", self.base_url, group_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending leaveGroup request");
 
         // Build request
-        let mut form_data = vec![("f", "json")];
-
-        // Add token if required by auth provider
-
-        let token_opt = self.client.get_token_if_required().await?;
+        let form_data = vec![("f", "json")];
 
-        let token_str;
-
-        if let Some(token) = token_opt {
-            token_str = token;
-
-            form_data.push(("token", token_str.as_str()));
-        }
-
-        let response = self
+        let result: GroupResult = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_data)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_data))
             .await?;
 
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "leaveGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: GroupResult = response.json().await?;
-
         tracing::debug!(success = result.success(), "Left group");
 
         Ok(result)
@@ -572,7 +387,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.add_to_group("group_id", "item_id").await?;
     /// println!("Added to group: {}", result.success());
     /// # Ok(())
@@ -590,55 +405,20 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/content/items/{}/share", self.base_url, item_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending addToGroup request");
 
         // Build request
-        let mut form_data = vec![("f", "json"), ("groups", group_id)];
-
-        // Add token if required by auth provider
-
-        let token_opt = self.client.get_token_if_required().await?;
+        let form_data = vec![("f", "json"), ("groups", group_id)];
 
-        let token_str;
-
-        if let Some(token) = token_opt {
-            token_str = token;
-
-            form_data.push(("token", token_str.as_str()));
-        }
-
-        let response = self
+        let result: crate::ShareItemResult = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_data)
-            .send()
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .form(&form_data)
+                    .operation("addToGroup"),
+            )
             .await?;
 
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "addToGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let response_text = response.text().await?;
-        tracing::debug!(response_body = %response_text, "Raw addToGroup response");
-
-        // Check for ESRI error in response body
-        crate::check_esri_error(&response_text, "addToGroup")?;
-
-        let result: crate::ShareItemResult = serde_json::from_str(&response_text)?;
-
         tracing::debug!(success = result.success(), "Added item to group");
 
         Ok(result)
@@ -650,7 +430,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.remove_from_group("group_id", "item_id").await?;
     /// println!("Removed from group: {}", result.success());
     /// # Ok(())
@@ -668,49 +448,16 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/content/items/{}/unshare", self.base_url, item_id);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending removeFromGroup request");
 
         // Build request
-        let mut form_data = vec![("f", "json"), ("groups", group_id)];
+        let form_data = vec![("f", "json"), ("groups", group_id)];
 
-        // Add token if required by auth provider
-
-        let token_opt = self.client.get_token_if_required().await?;
-
-        let token_str;
-
-        if let Some(token) = token_opt {
-            token_str = token;
-
-            form_data.push(("token", token_str.as_str()));
-        }
-
-        let response = self
+        let result: crate::UnshareItemResult = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_data)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_data))
             .await?;
 
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "removeFromGroup request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: crate::UnshareItemResult = response.json().await?;
-
         tracing::debug!(success = result.success(), "Removed item from group");
 
         Ok(result)
@@ -3,11 +3,11 @@
 use super::PortalClient;
 use crate::{
     AddItemParams, AddItemResult, DeleteItemResult, ItemDataUpload, ItemInfo, Result,
-    UpdateItemParams, UpdateItemResult,
+    ServiceRequest, UpdateItemParams, UpdateItemResult,
 };
 use tracing::instrument;
 
-impl<'a> PortalClient<'a> {
+impl PortalClient {
     /// Gets a portal item by ID.
     ///
     /// Retrieves detailed metadata for a specific item.
@@ -16,7 +16,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let item = portal.get_item("abc123def456").await?;
     /// println!("Title: {}", item.title());
     /// println!("Type: {}", item.item_type());
@@ -31,35 +31,12 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/content/items/{}", self.base_url, item_id);
 
-        // Get authentication token
-
         tracing::debug!(url = %url, "Sending getItem request");
 
         // Build request
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
 
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "getItem request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let item: ItemInfo = response.json().await?;
+        let item: ItemInfo = self.client.execute_json(request).await?;
 
         tracing::debug!(title = %item.title(), item_type = %item.item_type(), "Got item");
 
@@ -74,7 +51,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, AddItemParams};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = AddItemParams::new("My Map", "Web Map")
     ///     .with_description("A sample web map")
     ///     .with_tags(vec!["sample".to_string(), "demo".to_string()]);
@@ -88,8 +65,6 @@ impl<'a> PortalClient<'a> {
     pub async fn add_item(&self, params: AddItemParams) -> Result<AddItemResult> {
         tracing::debug!(title = %params.title(), item_type = %params.item_type(), "Adding item");
 
-        // Get authentication token
-
         // We need the username to construct the URL
         let user = self.get_self().await?;
         let username = user.effective_username().ok_or_else(|| {
@@ -161,37 +136,14 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "addItem request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get response text for debugging
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "addItem raw response");
-
-        // Check for ESRI error in response body
-        crate::check_esri_error(&response_text, "addItem")?;
-
-        // Parse response
-        let result: AddItemResult = serde_json::from_str(&response_text)?;
+        let result: AddItemResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("addItem"),
+            )
+            .await?;
 
         tracing::debug!(item_id = %result.id(), success = result.success(), "Item added");
 
@@ -206,7 +158,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, UpdateItemParams};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = UpdateItemParams::new()
     ///     .with_title("Updated Title")
     ///     .with_description("Updated description");
@@ -225,8 +177,6 @@ impl<'a> PortalClient<'a> {
         let item_id = item_id.as_ref();
         tracing::debug!(item_id = %item_id, "Updating item");
 
-        // Get authentication token
-
         // Get the item to find its owner
         let item = self.get_item(item_id).await?;
         let url = format!(
@@ -283,30 +233,10 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "updateItem request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: UpdateItemResult = response.json().await?;
+        let result: UpdateItemResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
 
         tracing::debug!(success = result.success(), "Item updated");
 
@@ -321,7 +251,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.delete_item("abc123def456").await?;
     /// println!("Delete success: {}", result.success());
     /// # Ok(())
@@ -332,8 +262,6 @@ impl<'a> PortalClient<'a> {
         let item_id = item_id.as_ref();
         tracing::debug!(item_id = %item_id, "Deleting item");
 
-        // Get authentication token
-
         // Get the item to find its owner
         let item = self.get_item(item_id).await?;
         let url = format!(
@@ -346,45 +274,13 @@ impl<'a> PortalClient<'a> {
         tracing::debug!(url = %url, owner = %item.owner(), "Sending deleteItem request");
 
         // Build request
-        let mut form_data = vec![("f", "json")];
+        let form_data = vec![("f", "json")];
 
-        // Add token if required by auth provider
-
-        let token_opt = self.client.get_token_if_required().await?;
-
-        let token_str;
-
-        if let Some(token) = token_opt {
-            token_str = token;
-
-            form_data.push(("token", token_str.as_str()));
-        }
-
-        let response = self
+        let result: DeleteItemResult = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_data)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_data))
             .await?;
 
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "deleteItem request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: DeleteItemResult = response.json().await?;
-
         tracing::debug!(success = result.success(), "Item deleted");
 
         Ok(result)
@@ -401,7 +297,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let data = portal.get_item_data("abc123def456").await?;
     /// println!("Downloaded {} bytes", data.len());
     /// # Ok(())
@@ -417,30 +313,9 @@ impl<'a> PortalClient<'a> {
         tracing::debug!(url = %url, "Sending getItemData request");
 
         // Build request - NO f parameter for most item types
-        let mut request = self.client.http().get(&url);
+        let request = ServiceRequest::get(&url);
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "getItemData request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get bytes
-        let bytes = response.bytes().await?;
+        let bytes = self.client.execute_bytes(request).await?;
 
         tracing::debug!(size = bytes.len(), "Retrieved item data");
 
@@ -456,7 +331,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let zip_data = portal.get_item_data_zip("package_item_id").await?;
     /// std::fs::write("package.zip", zip_data)?;
     /// # Ok(())
@@ -472,30 +347,9 @@ impl<'a> PortalClient<'a> {
         tracing::debug!(url = %url, "Sending getItemData request with f=zip");
 
         // Build request with f=zip for package types
-        let mut request = self.client.http().get(&url).query(&[("f", "zip")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
+        let request = ServiceRequest::get(&url).query(&[("f", "zip")]);
 
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "getItemData (ZIP) request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get bytes
-        let bytes = response.bytes().await?;
+        let bytes = self.client.execute_bytes(request).await?;
 
         tracing::debug!(size = bytes.len(), "Retrieved item data as ZIP");
 
@@ -513,7 +367,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, ItemDataUpload};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// // Upload JSON text
     /// let geojson = r#"{"type":"FeatureCollection","features":[]}"#;
     /// let upload = ItemDataUpload::Text(geojson.to_string());
@@ -590,28 +444,12 @@ impl<'a> PortalClient<'a> {
             }
         }
 
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
         tracing::debug!(url = %url, owner = %item.owner(), "Sending updateItemData request");
 
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "updateItemData request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: UpdateItemResult = response.json().await?;
+        let result: UpdateItemResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
 
         tracing::debug!(success = result.success(), "Item data updated");
 
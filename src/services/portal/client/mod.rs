@@ -40,14 +40,14 @@ use tracing::instrument;
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct PortalClient<'a> {
+pub struct PortalClient {
     /// Base URL of the portal (e.g., "https://www.arcgis.com/sharing/rest").
     pub(super) base_url: String,
-    /// Reference to the ArcGIS client for authentication and HTTP.
-    pub(super) client: &'a ArcGISClient,
+    /// Shared ArcGIS client for authentication and HTTP.
+    pub(super) client: ArcGISClient,
 }
 
-impl<'a> PortalClient<'a> {
+impl PortalClient {
     /// Creates a new PortalClient.
     ///
     /// # Arguments
@@ -72,10 +72,13 @@ impl<'a> PortalClient<'a> {
     /// # }
     /// ```
     #[instrument(skip(base_url, client))]
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating PortalClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+        }
     }
 
     /// Creates a PortalClient for ArcGIS Online.
@@ -99,7 +102,7 @@ impl<'a> PortalClient<'a> {
     /// # }
     /// ```
     #[instrument(skip(client))]
-    pub fn agol(client: &'a ArcGISClient) -> Self {
+    pub fn agol(client: &ArcGISClient) -> Self {
         tracing::debug!("Creating PortalClient for ArcGIS Online");
         Self::new("https://www.arcgis.com/sharing/rest", client)
     }
@@ -134,7 +137,7 @@ impl<'a> PortalClient<'a> {
     /// # }
     /// ```
     #[instrument(skip(client))]
-    pub fn enterprise(client: &'a ArcGISClient) -> crate::Result<Self> {
+    pub fn enterprise(client: &ArcGISClient) -> crate::Result<Self> {
         tracing::debug!("Creating PortalClient for ArcGIS Enterprise");
 
         let config = crate::EnvConfig::global();
@@ -6,10 +6,10 @@ use super::super::{
     UpdateServiceDefinitionParams, UpdateServiceDefinitionResult,
 };
 use super::PortalClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> PortalClient<'a> {
+impl PortalClient {
     /// Creates a new hosted feature service.
     ///
     /// Directly creates a new hosted feature service with the specified configuration.
@@ -23,7 +23,7 @@ impl<'a> PortalClient<'a> {
     /// #     ServiceDefinitionBuilder, LayerDefinitionBuilder,
     /// #     FieldDefinitionBuilder, FieldType, GeometryTypeDefinition
     /// # };
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// // Create a simple feature service with strongly-typed definitions
     /// let oid_field = FieldDefinitionBuilder::default()
     ///     .name("OBJECTID")
@@ -124,41 +124,19 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build form data
-        let mut form = reqwest::multipart::Form::new()
+        let form = reqwest::multipart::Form::new()
             .text("f", "json")
             .text("outputType", "featureService")
             .text("createParameters", create_params_obj.to_string());
 
-        // Add token if required
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "createService request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get response text for debugging
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "createService raw response");
-
-        // Check for ESRI error in response
-        crate::check_esri_error(&response_text, "createService")?;
-
-        // Parse response
-        let result: CreateServiceResult = serde_json::from_str(&response_text)?;
+        let result: CreateServiceResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("createService"),
+            )
+            .await?;
 
         tracing::debug!(
             success = result.success(),
@@ -179,7 +157,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, PublishParameters};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = PublishParameters::new("MyFeatureService")
     ///     .with_description("Published from shapefile")
     ///     .with_max_record_count(1000)
@@ -279,7 +257,7 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build form data
-        let mut form = reqwest::multipart::Form::new()
+        let form = reqwest::multipart::Form::new()
             .text("f", "json")
             .text("itemId", item_id.to_string())
             .text("filetype", file_type)
@@ -289,37 +267,14 @@ impl<'a> PortalClient<'a> {
             );
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "publish request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get response text for debugging
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "publish raw response");
-
-        // Check for ESRI error in response body
-        crate::check_esri_error(&response_text, "publish")?;
-
-        // Parse response
-        let result: PublishResult = serde_json::from_str(&response_text)?;
+        let result: PublishResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("publish"),
+            )
+            .await?;
 
         tracing::debug!(
             success = result.success(),
@@ -336,7 +291,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let status = portal.get_publish_status("job_id").await?;
     /// if let Some(job_status) = status.job_status() {
     ///     println!("Job status: {}", job_status);
@@ -370,30 +325,9 @@ impl<'a> PortalClient<'a> {
         tracing::debug!(url = %url, "Sending getPublishStatus request");
 
         // Build request
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "getPublishStatus request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
 
-        // Parse response
-        let result: PublishStatus = response.json().await?;
+        let result: PublishStatus = self.client.execute_json(request).await?;
 
         tracing::debug!(
             job_status = ?result.job_status(),
@@ -412,7 +346,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, UpdateServiceDefinitionParams};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = UpdateServiceDefinitionParams::new()
     ///     .with_description("Updated service description")
     ///     .with_max_record_count(2000);
@@ -443,7 +377,6 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/updateDefinition", service_url);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending updateServiceDefinition request");
 
         // Build update parameters
@@ -468,33 +401,14 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "updateServiceDefinition request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response - check for ESRI API error first
-        let response_text = response.text().await?;
-        crate::check_esri_error(&response_text, "updateDefinition")?;
-
-        let result: UpdateServiceDefinitionResult = serde_json::from_str(&response_text)?;
+        let result: UpdateServiceDefinitionResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("updateDefinition"),
+            )
+            .await?;
 
         tracing::debug!(success = result.success(), "Service definition updated");
 
@@ -613,36 +527,18 @@ impl<'a> PortalClient<'a> {
         let add_definition_str = serde_json::to_string(&add_definition)?;
 
         // Build multipart form
-        let mut form = reqwest::multipart::Form::new()
+        let form = reqwest::multipart::Form::new()
             .text("f", "json")
             .text("addToDefinition", add_definition_str);
 
-        // Add token if required
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "addToDefinition request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "addToDefinition response");
-        crate::check_esri_error(&response_text, "addToDefinition")?;
-        let result: crate::AddToDefinitionResult = serde_json::from_str(&response_text)?;
+        let result: crate::AddToDefinitionResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("addToDefinition"),
+            )
+            .await?;
 
         tracing::info!(
             success = result.success(),
@@ -662,7 +558,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let result = portal.delete_service("service_item_id").await?;
     /// println!("Delete success: {}", result.success());
     /// # Ok(())
@@ -697,7 +593,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, OverwriteParameters};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// let params = OverwriteParameters::new("new_data_item_id", "existing_service_id")
     ///     .with_preserve_item_id(true);
     ///
@@ -743,33 +639,14 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "overwriteService request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response - check for ESRI API error first
-        let response_text = response.text().await?;
-        crate::check_esri_error(&response_text, "overwriteService")?;
-
-        let result: OverwriteResult = serde_json::from_str(&response_text)?;
+        let result: OverwriteResult = self
+            .client
+            .execute_json(
+                ServiceRequest::post(&url)
+                    .multipart(form)
+                    .operation("overwriteService"),
+            )
+            .await?;
 
         tracing::debug!(success = result.success(), "Service overwritten");
 
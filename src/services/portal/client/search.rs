@@ -2,11 +2,11 @@
 
 use super::super::{SearchParameters, SearchResult, SortOrder};
 use super::PortalClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use serde::Serialize;
 use tracing::instrument;
 
-impl<'a> PortalClient<'a> {
+impl PortalClient {
     /// Searches for portal items.
     ///
     /// Supports Lucene query syntax for flexible item discovery.
@@ -15,7 +15,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, SearchParameters, SortOrder};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// // Search for feature services
     /// let results = portal
     ///     .search(SearchParameters::new("type:\"Feature Service\""))
@@ -42,7 +42,6 @@ impl<'a> PortalClient<'a> {
 
         let url = format!("{}/search", self.base_url);
 
-        // Get authentication token
         tracing::debug!(url = %url, "Sending search request");
 
         // Build query parameters
@@ -62,12 +61,8 @@ impl<'a> PortalClient<'a> {
             #[serde(skip_serializing_if = "Option::is_none")]
             num: Option<u32>,
             f: &'static str,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            token: Option<String>,
         }
 
-        let token_opt = self.client.get_token_if_required().await?;
-
         let query = SearchQuery {
             q: params.query(),
             bbox: params.bbox().as_ref().map(|b| {
@@ -85,28 +80,13 @@ impl<'a> PortalClient<'a> {
             start: *params.start(),
             num: *params.num(),
             f: "json",
-            token: token_opt,
         };
 
         // Build request
-        let response = self.client.http().get(&url).query(&query).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "Search request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: SearchResult = response.json().await?;
+        let result: SearchResult = self
+            .client
+            .execute_json(ServiceRequest::get(&url).query(&query))
+            .await?;
 
         tracing::debug!(
             total = result.total(),
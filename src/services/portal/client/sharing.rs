@@ -2,17 +2,17 @@
 
 use super::super::{ShareItemResult, SharingParameters, UnshareItemResult};
 use super::PortalClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> PortalClient<'a> {
+impl PortalClient {
     /// Shares an item with groups, organization, or everyone (public).
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, SharingParameters};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// // Share with organization and specific groups
     /// let params = SharingParameters::new()
     ///     .with_org(true)
@@ -32,7 +32,6 @@ impl<'a> PortalClient<'a> {
         let item_id = item_id.as_ref();
         tracing::debug!(item_id = %item_id, "Sharing item");
 
-        // Get authentication token
         // Get the item to find its owner
         let item = self.get_item(item_id).await?;
         let url = format!(
@@ -60,30 +59,10 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "shareItem request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: ShareItemResult = response.json().await?;
+        let result: ShareItemResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
 
         tracing::debug!(success = result.success(), "Item shared");
 
@@ -96,7 +75,7 @@ impl<'a> PortalClient<'a> {
     ///
     /// ```no_run
     /// # use arcgis::{ArcGISClient, ApiKeyAuth, PortalClient, SharingParameters};
-    /// # async fn example(portal: &PortalClient<'_>) -> arcgis::Result<()> {
+    /// # async fn example(portal: &PortalClient) -> arcgis::Result<()> {
     /// // Remove organization sharing and specific groups
     /// let params = SharingParameters::new()
     ///     .with_org(false)
@@ -116,7 +95,6 @@ impl<'a> PortalClient<'a> {
         let item_id = item_id.as_ref();
         tracing::debug!(item_id = %item_id, "Unsharing item");
 
-        // Get authentication token
         // Get the item to find its owner
         let item = self.get_item(item_id).await?;
         let url = format!(
@@ -136,30 +114,10 @@ impl<'a> PortalClient<'a> {
         }
 
         // Build request
-        // Add token if required by auth provider
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            form = form.text("token", token);
-        }
-
-        let response = self.client.http().post(&url).multipart(form).send().await?;
-
-        // Check for HTTP errors
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error response: {}", e));
-            tracing::error!(status = %status, error = %error_text, "unshareItem request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Parse response
-        let result: UnshareItemResult = response.json().await?;
+        let result: UnshareItemResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).multipart(form))
+            .await?;
 
         tracing::debug!(success = result.success(), "Item unshared");
 
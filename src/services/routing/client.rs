@@ -1,6 +1,6 @@
 //! Routing Service client for network analysis operations.
 
-use crate::{ArcGISClient, Result};
+use crate::{ArcGISClient, Result, ServiceRequest};
 use tracing::instrument;
 
 use super::{
@@ -27,14 +27,15 @@ use super::{
 /// # Ok(())
 /// # }
 /// ```
-pub struct RoutingServiceClient<'a> {
+#[derive(Clone)]
+pub struct RoutingServiceClient {
     /// Base URL of the routing service.
     base_url: String,
-    /// Reference to the ArcGIS client for HTTP operations.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client for HTTP operations.
+    client: ArcGISClient,
 }
 
-impl<'a> RoutingServiceClient<'a> {
+impl RoutingServiceClient {
     /// Creates a new Routing Service client.
     ///
     /// # Arguments
@@ -53,10 +54,13 @@ impl<'a> RoutingServiceClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating RoutingServiceClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+        }
     }
 
     /// Solves a route between multiple stops.
@@ -147,30 +151,10 @@ impl<'a> RoutingServiceClient<'a> {
             form.push(("outSR", out_sr.as_str()));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "solve route request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: RouteResult = response.json().await?;
+        let result: RouteResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form).billable(1))
+            .await?;
 
         tracing::info!(route_count = result.routes().len(), "solve route completed");
 
@@ -259,30 +243,10 @@ impl<'a> RoutingServiceClient<'a> {
             ));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "solve service area request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: ServiceAreaResult = response.json().await?;
+        let result: ServiceAreaResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form).billable(1))
+            .await?;
 
         tracing::info!(
             polygon_count = result.service_area_polygons().len(),
@@ -420,30 +384,10 @@ impl<'a> RoutingServiceClient<'a> {
             form.push(("accumulateAttributeNames", accumulate_str.as_str()));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "solve closest facility request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: ClosestFacilityResult = response.json().await?;
+        let result: ClosestFacilityResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form).billable(1))
+            .await?;
 
         tracing::info!(
             route_count = result.routes().len(),
@@ -557,30 +501,10 @@ impl<'a> RoutingServiceClient<'a> {
             form.push(("accumulateAttributeNames", accumulate_str.as_str()));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "generate OD cost matrix request failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let result: ODCostMatrixResult = response.json().await?;
+        let result: ODCostMatrixResult = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form).billable(1))
+            .await?;
 
         tracing::info!(
             od_line_count = result.od_lines().len(),
@@ -1,6 +1,6 @@
 //! Vector tile service client implementation.
 
-use crate::{ArcGISClient, Result, TileCoordinate};
+use crate::{ArcGISClient, Result, ServiceRequest, TileCoordinate};
 use tracing::instrument;
 
 use super::types::{FontStack, GlyphRange, VectorTileStyle};
@@ -31,15 +31,15 @@ use super::types::{FontStack, GlyphRange, VectorTileStyle};
 /// # }
 /// ```
 #[derive(Clone)]
-pub struct VectorTileServiceClient<'a> {
+pub struct VectorTileServiceClient {
     /// Base URL of the vector tile service.
     url: String,
 
-    /// Reference to the ArcGIS client.
-    client: &'a ArcGISClient,
+    /// Shared ArcGIS client.
+    client: ArcGISClient,
 }
 
-impl<'a> VectorTileServiceClient<'a> {
+impl VectorTileServiceClient {
     /// Creates a new vector tile service client.
     ///
     /// # Arguments
@@ -59,10 +59,10 @@ impl<'a> VectorTileServiceClient<'a> {
     ///     &client
     /// );
     /// ```
-    pub fn new(url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(url: impl Into<String>, client: &ArcGISClient) -> Self {
         VectorTileServiceClient {
             url: url.into(),
-            client,
+            client: client.clone(),
         }
     }
 
@@ -111,9 +111,10 @@ impl<'a> VectorTileServiceClient<'a> {
             tile.col()
         );
 
-        let response = self.client.http().get(&tile_url).send().await?;
-
-        let bytes = response.bytes().await?;
+        let bytes = self
+            .client
+            .execute_bytes(ServiceRequest::get(&tile_url))
+            .await?;
 
         tracing::debug!(size = bytes.len(), "Tile retrieved");
 
@@ -211,9 +212,10 @@ impl<'a> VectorTileServiceClient<'a> {
 
         let style_url = format!("{}/resources/styles/root.json", self.url);
 
-        let response = self.client.http().get(&style_url).send().await?;
-
-        let style: VectorTileStyle = response.json().await?;
+        let style: VectorTileStyle = self
+            .client
+            .execute_json(ServiceRequest::get(&style_url).cacheable())
+            .await?;
 
         tracing::debug!(
             version = style.version(),
@@ -270,9 +272,10 @@ impl<'a> VectorTileServiceClient<'a> {
             range.format()
         );
 
-        let response = self.client.http().get(&font_url).send().await?;
-
-        let bytes = response.bytes().await?;
+        let bytes = self
+            .client
+            .execute_bytes(ServiceRequest::get(&font_url))
+            .await?;
 
         tracing::debug!(size = bytes.len(), "Glyphs retrieved");
 
@@ -311,9 +314,10 @@ impl<'a> VectorTileServiceClient<'a> {
 
         let sprite_url = format!("{}/resources/sprites/sprite.json", self.url);
 
-        let response = self.client.http().get(&sprite_url).send().await?;
-
-        let metadata: serde_json::Value = response.json().await?;
+        let metadata: serde_json::Value = self
+            .client
+            .execute_json(ServiceRequest::get(&sprite_url))
+            .await?;
 
         tracing::debug!("Sprite metadata retrieved");
 
@@ -352,9 +356,10 @@ impl<'a> VectorTileServiceClient<'a> {
 
         let sprite_url = format!("{}/resources/sprites/sprite.png", self.url);
 
-        let response = self.client.http().get(&sprite_url).send().await?;
-
-        let bytes = response.bytes().await?;
+        let bytes = self
+            .client
+            .execute_bytes(ServiceRequest::get(&sprite_url))
+            .await?;
 
         tracing::debug!(size = bytes.len(), "Sprite image retrieved");
 
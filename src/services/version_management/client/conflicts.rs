@@ -5,10 +5,10 @@ use super::super::{
     RestoreRowsResponse, SessionId, VersionGuid,
 };
 use super::VersionManagementClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> VersionManagementClient<'a> {
+impl VersionManagementClient {
     /// Retrieves conflicts detected during the last reconcile operation.
     ///
     /// Returns all conflicts organized by layer and type (update-update, update-delete,
@@ -89,32 +89,12 @@ impl<'a> VersionManagementClient<'a> {
         tracing::debug!(url = %url, "Sending conflicts request");
 
         let session_id_str = session_id.to_string();
-        let mut form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
+        let form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "conflicts failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let conflicts_response: ConflictsResponse = response.json().await?;
+        let conflicts_response: ConflictsResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *conflicts_response.success() {
             let conflict_count = conflicts_response
@@ -261,29 +241,11 @@ impl<'a> VersionManagementClient<'a> {
 
         let form_refs: Vec<(&str, &str)> = form.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-        let response = self
+        let inspect_response: InspectConflictsResponse = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_refs)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_refs))
             .await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "inspect conflicts failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let inspect_response: InspectConflictsResponse = response.json().await?;
-
         if *inspect_response.success() {
             tracing::info!(
                 version_guid = %version_guid,
@@ -399,29 +361,11 @@ impl<'a> VersionManagementClient<'a> {
 
         let form_refs: Vec<(&str, &str)> = form.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-        let response = self
+        let restore_response: RestoreRowsResponse = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_refs)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_refs))
             .await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "restore rows failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let restore_response: RestoreRowsResponse = response.json().await?;
-
         if *restore_response.success() {
             tracing::info!(
                 version_guid = %version_guid,
@@ -67,14 +67,15 @@ use tracing::instrument;
 /// # Ok(())
 /// # }
 /// ```
-pub struct VersionManagementClient<'a> {
+#[derive(Clone)]
+pub struct VersionManagementClient {
     /// Base URL of the Version Management Service
     pub(super) base_url: String,
-    /// Reference to the ArcGIS client for HTTP operations
-    pub(super) client: &'a ArcGISClient,
+    /// Shared ArcGIS client for HTTP operations
+    pub(super) client: ArcGISClient,
 }
 
-impl<'a> VersionManagementClient<'a> {
+impl VersionManagementClient {
     /// Creates a new Version Management Service client.
     ///
     /// # Arguments
@@ -96,9 +97,12 @@ impl<'a> VersionManagementClient<'a> {
     /// );
     /// ```
     #[instrument(skip(base_url, client))]
-    pub fn new(base_url: impl Into<String>, client: &'a ArcGISClient) -> Self {
+    pub fn new(base_url: impl Into<String>, client: &ArcGISClient) -> Self {
         let base_url = base_url.into();
         tracing::debug!(base_url = %base_url, "Creating VersionManagementClient");
-        Self { base_url, client }
+        Self {
+            base_url,
+            client: client.clone(),
+        }
     }
 }
@@ -4,10 +4,10 @@ use super::super::{
     ConflictDetection, PartialPostRow, PostResponse, ReconcileResponse, SessionId, VersionGuid,
 };
 use super::VersionManagementClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> VersionManagementClient<'a> {
+impl VersionManagementClient {
     /// Reconciles a version against the DEFAULT version.
     ///
     /// Reconciliation compares the current version against the DEFAULT version,
@@ -115,7 +115,7 @@ impl<'a> VersionManagementClient<'a> {
         tracing::debug!(url = %url, "Sending reconcile request");
 
         let session_id_str = session_id.to_string();
-        let mut form = vec![
+        let form = vec![
             ("sessionId", session_id_str.as_str()),
             ("abortIfConflicts", abort_str),
             ("conflictDetection", conflict_detection_str.as_str()),
@@ -123,30 +123,10 @@ impl<'a> VersionManagementClient<'a> {
             ("f", "json"),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "reconcile failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let reconcile_response: ReconcileResponse = response.json().await?;
+        let reconcile_response: ReconcileResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *reconcile_response.success() {
             tracing::info!(
@@ -313,29 +293,11 @@ impl<'a> VersionManagementClient<'a> {
 
         let form_refs: Vec<(&str, &str)> = form.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-        let response = self
+        let post_response: PostResponse = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_refs)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_refs))
             .await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "post failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let post_response: PostResponse = response.json().await?;
-
         if *post_response.success() {
             tracing::info!(
                 version_guid = %version_guid,
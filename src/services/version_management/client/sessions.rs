@@ -5,10 +5,10 @@ use super::super::{
     StopReadingResponse, VersionGuid,
 };
 use super::VersionManagementClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> VersionManagementClient<'a> {
+impl VersionManagementClient {
     /// Starts an edit session on a version.
     ///
     /// Starting an edit session acquires a write lock on the version, preventing
@@ -76,32 +76,12 @@ impl<'a> VersionManagementClient<'a> {
         tracing::debug!(url = %url, "Sending startEditing request");
 
         let session_id_str = session_id.to_string();
-        let mut form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
+        let form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "startEditing failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let start_response: StartEditingResponse = response.json().await?;
+        let start_response: StartEditingResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *start_response.success() {
             tracing::info!(
@@ -198,36 +178,16 @@ impl<'a> VersionManagementClient<'a> {
         tracing::debug!(url = %url, save_edits = save_edits, "Sending stopEditing request");
 
         let session_id_str = session_id.to_string();
-        let mut form = vec![
+        let form = vec![
             ("sessionId", session_id_str.as_str()),
             ("saveEdits", save_edits_str),
             ("f", "json"),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "stopEditing failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let stop_response: StopEditingResponse = response.json().await?;
+        let stop_response: StopEditingResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *stop_response.success() {
             tracing::info!(
@@ -320,32 +280,12 @@ impl<'a> VersionManagementClient<'a> {
         tracing::debug!(url = %url, "Sending startReading request");
 
         let session_id_str = session_id.to_string();
-        let mut form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
+        let form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "startReading failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let start_response: StartReadingResponse = response.json().await?;
+        let start_response: StartReadingResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *start_response.success() {
             tracing::info!(
@@ -428,32 +368,12 @@ impl<'a> VersionManagementClient<'a> {
         tracing::debug!(url = %url, "Sending stopReading request");
 
         let session_id_str = session_id.to_string();
-        let mut form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
-
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "stopReading failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let form = vec![("sessionId", session_id_str.as_str()), ("f", "json")];
 
-        let stop_response: StopReadingResponse = response.json().await?;
+        let stop_response: StopReadingResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *stop_response.success() {
             tracing::info!(
@@ -4,10 +4,10 @@ use super::super::{
     DeleteForwardEditsResponse, DifferenceResultType, DifferencesResponse, SessionId, VersionGuid,
 };
 use super::VersionManagementClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> VersionManagementClient<'a> {
+impl VersionManagementClient {
     /// Deletes forward edits after a specified moment.
     ///
     /// This operation supports undo functionality by removing all edits made after
@@ -99,36 +99,16 @@ impl<'a> VersionManagementClient<'a> {
 
         let session_id_str = session_id.to_string();
         let moment_str = moment.to_string();
-        let mut form = vec![
+        let form = vec![
             ("sessionId", session_id_str.as_str()),
             ("moment", moment_str.as_str()),
             ("f", "json"),
         ];
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "delete forward edits failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let delete_response: DeleteForwardEditsResponse = response.json().await?;
+        let delete_response: DeleteForwardEditsResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *delete_response.success() {
             tracing::info!(
@@ -257,29 +237,11 @@ impl<'a> VersionManagementClient<'a> {
 
         let form_refs: Vec<(&str, &str)> = form.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-        let response = self
+        let diffs_response: DifferencesResponse = self
             .client
-            .http()
-            .post(&url)
-            .form(&form_refs)
-            .send()
+            .execute_json(ServiceRequest::post(&url).form(&form_refs))
             .await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "differences failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let diffs_response: DifferencesResponse = response.json().await?;
-
         if *diffs_response.success() {
             tracing::info!(
                 version_guid = %version_guid,
@@ -5,10 +5,10 @@ use super::super::{
     VersionGuid, VersionInfo, VersionInfosResponse,
 };
 use super::VersionManagementClient;
-use crate::Result;
+use crate::{Result, ServiceRequest};
 use tracing::instrument;
 
-impl<'a> VersionManagementClient<'a> {
+impl VersionManagementClient {
     /// Creates a new version from the DEFAULT version.
     ///
     /// Creates a named version that branches from the DEFAULT version. This is the
@@ -76,36 +76,12 @@ impl<'a> VersionManagementClient<'a> {
             form.push(("description", description.as_str()));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
         tracing::debug!(url = %url, "Sending create request");
 
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "create failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get raw response text for debugging
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "Raw create version response");
-
-        // Try to deserialize
-        let create_response: CreateVersionResponse = serde_json::from_str(&response_text)?;
+        let create_response: CreateVersionResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if create_response.success().is_some_and(|s| s) {
             tracing::info!(
@@ -190,14 +166,6 @@ impl<'a> VersionManagementClient<'a> {
             form.push(("versionName", version_name_str.as_str()));
         }
 
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
         let access_str;
         if let Some(access) = params.access() {
             access_str = access.to_string();
@@ -212,22 +180,10 @@ impl<'a> VersionManagementClient<'a> {
 
         tracing::debug!(url = %url, "Sending alter request");
 
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "alter failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let alter_response: AlterResponse = response.json().await?;
+        let alter_response: AlterResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&form))
+            .await?;
 
         if *alter_response.success() {
             tracing::info!(
@@ -301,32 +257,10 @@ impl<'a> VersionManagementClient<'a> {
 
         tracing::debug!(url = %url, "Sending delete request");
 
-        let mut form = vec![];
-
-        // Add token if required by auth provider
-        let token_opt = self.client.get_token_if_required().await?;
-        let token_str;
-        if let Some(token) = token_opt {
-            token_str = token;
-            form.push(("token", token_str.as_str()));
-        }
-
-        let response = self.client.http().post(&url).form(&form).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "delete failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        let delete_response: DeleteResponse = response.json().await?;
+        let delete_response: DeleteResponse = self
+            .client
+            .execute_json(ServiceRequest::post(&url).form(&[("f", "json")]))
+            .await?;
 
         if *delete_response.success() {
             tracing::info!(
@@ -393,28 +327,9 @@ impl<'a> VersionManagementClient<'a> {
 
         tracing::debug!(url = %url, "Sending get version info request");
 
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "get version info failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
 
-        let version_info: VersionInfo = response.json().await?;
+        let version_info: VersionInfo = self.client.execute_json(request).await?;
 
         tracing::info!(
             version_guid = %version_guid,
@@ -466,49 +381,9 @@ impl<'a> VersionManagementClient<'a> {
 
         tracing::debug!(url = %url, "Sending list versions request");
 
-        let mut request = self.client.http().get(&url).query(&[("f", "json")]);
-
-        if let Some(token) = self.client.get_token_if_required().await? {
-            request = request.query(&[("token", token)]);
-        }
-
-        let response = request.send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error: {}", e));
-            tracing::error!(status = %status, error = %error_text, "list versions failed");
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: status.as_u16() as i32,
-                message: format!("HTTP {}: {}", status, error_text),
-            }));
-        }
-
-        // Get raw response text for debugging
-        let response_text = response.text().await?;
-        tracing::debug!(response = %response_text, "Raw list versions response");
-
-        // Check for API error response
-        if let Ok(error_response) = serde_json::from_str::<serde_json::Value>(&response_text) {
-            if let Some(error_obj) = error_response.get("error") {
-                let code = error_obj.get("code").and_then(|c| c.as_i64()).unwrap_or(0);
-                let message = error_obj
-                    .get("message")
-                    .and_then(|m| m.as_str())
-                    .unwrap_or("Unknown error");
-                tracing::error!(code = code, message = message, "API returned error");
-                return Err(crate::Error::from(crate::ErrorKind::Api {
-                    code: code as i32,
-                    message: message.to_string(),
-                }));
-            }
-        }
+        let request = ServiceRequest::get(&url).query(&[("f", "json")]);
 
-        // Try to deserialize
-        let versions_response: VersionInfosResponse = serde_json::from_str(&response_text)?;
+        let versions_response: VersionInfosResponse = self.client.execute_json(request).await?;
 
         tracing::info!(
             version_count = versions_response.versions().len(),
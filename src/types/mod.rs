@@ -1,5 +1,7 @@
 //! Shared types used across ArcGIS services.
 
 mod ids;
+mod service_kind;
 
 pub use ids::*;
+pub use service_kind::ServiceKind;
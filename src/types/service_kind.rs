@@ -0,0 +1,118 @@
+//! Classification of ArcGIS REST endpoints by service type.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use url::Url;
+
+/// Kind of ArcGIS service targeted by a request.
+///
+/// Derived from the request URL (e.g. `.../GeocodeServer/findAddressCandidates`
+/// is [`ServiceKind::Geocode`]). Used to apply per-service client behavior such
+/// as rate limits.
+///
+/// # Example
+///
+/// ```
+/// use arcgis::ServiceKind;
+///
+/// let kind = ServiceKind::from_url_str(
+///     "https://geocode.arcgis.com/arcgis/rest/services/World/GeocodeServer/findAddressCandidates",
+/// );
+/// assert_eq!(kind, ServiceKind::Geocode);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ServiceKind {
+    /// Feature Service (`FeatureServer`).
+    Feature,
+    /// Map Service (`MapServer`).
+    Map,
+    /// Image Service (`ImageServer`).
+    Image,
+    /// Vector Tile Service (`VectorTileServer`).
+    VectorTile,
+    /// Geocoding Service (`GeocodeServer`).
+    Geocode,
+    /// Routing and network analysis (`NAServer`).
+    Routing,
+    /// Geometry Service (`GeometryServer`).
+    Geometry,
+    /// Geoprocessing Service (`GPServer`).
+    Geoprocessing,
+    /// Elevation analysis services (`elevation.arcgis.com`).
+    Elevation,
+    /// Places service (`places-api.arcgis.com`).
+    Places,
+    /// Version Management Service (`VersionManagementServer`).
+    VersionManagement,
+    /// Portal sharing REST API (`/sharing/rest`).
+    Portal,
+    /// Any endpoint that does not match a known service.
+    Other,
+}
+
+impl ServiceKind {
+    /// Classifies a request URL.
+    pub fn from_url(url: &Url) -> Self {
+        let host = url.host_str().unwrap_or_default();
+        let path = url.path();
+
+        if host.starts_with("places-api.") {
+            Self::Places
+        } else if host.starts_with("elevation.") {
+            Self::Elevation
+        } else if path.contains("/GeocodeServer") {
+            Self::Geocode
+        } else if path.contains("/NAServer") {
+            Self::Routing
+        } else if path.contains("/FeatureServer") {
+            Self::Feature
+        } else if path.contains("/MapServer") {
+            Self::Map
+        } else if path.contains("/ImageServer") {
+            Self::Image
+        } else if path.contains("/VectorTileServer") {
+            Self::VectorTile
+        } else if path.contains("/GeometryServer") {
+            Self::Geometry
+        } else if path.contains("/GPServer") {
+            Self::Geoprocessing
+        } else if path.contains("/VersionManagementServer") {
+            Self::VersionManagement
+        } else if path.contains("/sharing/rest") {
+            Self::Portal
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Classifies a request URL given as a string.
+    ///
+    /// Returns [`ServiceKind::Other`] if the string is not a valid URL.
+    pub fn from_url_str(url: &str) -> Self {
+        Url::parse(url)
+            .map(|url| Self::from_url(&url))
+            .unwrap_or(Self::Other)
+    }
+}
+
+impl fmt::Display for ServiceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Feature => "feature",
+            Self::Map => "map",
+            Self::Image => "image",
+            Self::VectorTile => "vector_tile",
+            Self::Geocode => "geocode",
+            Self::Routing => "routing",
+            Self::Geometry => "geometry",
+            Self::Geoprocessing => "geoprocessing",
+            Self::Elevation => "elevation",
+            Self::Places => "places",
+            Self::VersionManagement => "version_management",
+            Self::Portal => "portal",
+            Self::Other => "other",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -1,5 +1,10 @@
 //! Utility functions and helpers.
 
+mod redact;
+
+pub use redact::redact_tokens;
+pub(crate) use redact::{REDACTED, SENSITIVE_PARAMS};
+
 use crate::Result;
 
 /// Check for an ESRI error embedded in a successful HTTP response.
@@ -14,6 +19,10 @@ use crate::Result;
 /// Call this after verifying the HTTP status is successful but before deserializing
 /// the response body.
 ///
+/// The error is classified with [`ErrorKind::from_esri`](crate::ErrorKind::from_esri),
+/// so token, permission, not-found, transfer-limit, and parameter failures
+/// can be matched directly, with the `details` array preserved.
+///
 /// # Example
 ///
 /// ```no_run
@@ -30,45 +39,72 @@ pub fn check_esri_error(response_text: &str, operation: &str) -> Result<()> {
         return Ok(());
     }
 
-    tracing::error!(operation = %operation, response = %response_text, "ESRI returned error in response body");
-
-    // Try format 1: {"error": {"code": 400, "message": "..."}}
+    // Only a top-level "error" member signals failure; nested ones (per-item
+    // edit results, attribute values) are part of a successful payload.
     #[derive(serde::Deserialize)]
-    struct ErrorResponse1 {
-        error: ErrorDetail1,
+    struct TopLevel {
+        error: Option<serde::de::IgnoredAny>,
     }
-    #[derive(serde::Deserialize)]
-    struct ErrorDetail1 {
-        code: i32,
-        message: String,
+    if let Ok(TopLevel { error: None }) = serde_json::from_str::<TopLevel>(response_text) {
+        return Ok(());
     }
 
-    if let Ok(err) = serde_json::from_str::<ErrorResponse1>(response_text) {
-        return Err(crate::Error::from(crate::ErrorKind::Api {
-            code: err.error.code,
-            message: err.error.message,
-        }));
-    }
+    tracing::error!(operation = %operation, response = %redact_tokens(response_text), "ESRI returned error in response body");
 
-    // Try format 2: {"success": false, "error": {"message": "..."}}
-    #[derive(serde::Deserialize)]
-    struct ErrorResponse2 {
-        success: bool,
-        error: ErrorDetail2,
-    }
-    #[derive(serde::Deserialize)]
-    struct ErrorDetail2 {
-        message: String,
-        #[serde(default)]
-        code: Option<i32>,
-    }
+    // Formats seen in the wild:
+    //   {"error": {"code": 400, "message": "...", "details": ["..."]}}
+    //   {"success": false, "error": {"message": "..."}}
+    //   {"error": {"code": "498", "description": "..."}}
+    //   {"error": "Token Required"}
+    if let Ok(body) = serde_json::from_str::<serde_json::Value>(response_text) {
+        let succeeded = body.get("success").and_then(|v| v.as_bool()) == Some(true);
+        let error = match body.get("error") {
+            Some(error) if !succeeded => error,
+            _ => return Ok(()),
+        };
+
+        if let Some(message) = error.as_str() {
+            return Err(crate::Error::from(crate::ErrorKind::from_esri(
+                0,
+                message,
+                Vec::new(),
+            )));
+        }
+
+        if error.is_object() {
+            let code = error
+                .get("code")
+                .and_then(|code| match code {
+                    serde_json::Value::Number(n) => n.as_i64(),
+                    serde_json::Value::String(s) => s.trim().parse().ok(),
+                    _ => None,
+                })
+                .and_then(|code| i32::try_from(code).ok())
+                .unwrap_or(0);
+            let message = ["message", "description"]
+                .iter()
+                .find_map(|key| error.get(*key).and_then(|v| v.as_str()))
+                .filter(|message| !message.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{} failed", operation));
+            let details = error
+                .get("details")
+                .and_then(|v| v.as_array())
+                .map(|details| {
+                    details
+                        .iter()
+                        .map(|detail| match detail {
+                            serde_json::Value::String(s) => s.clone(),
+                            other => other.to_string(),
+                        })
+                        .filter(|detail| !detail.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
 
-    if let Ok(err) = serde_json::from_str::<ErrorResponse2>(response_text) {
-        if !err.success {
-            return Err(crate::Error::from(crate::ErrorKind::Api {
-                code: err.error.code.unwrap_or(0),
-                message: err.error.message,
-            }));
+            return Err(crate::Error::from(crate::ErrorKind::from_esri(
+                code, message, details,
+            )));
         }
     }
 
@@ -0,0 +1,78 @@
+//! Credential redaction for logs and error messages.
+
+use std::borrow::Cow;
+
+/// Parameters whose values are credentials.
+pub(crate) const SENSITIVE_PARAMS: &[&str] = &[
+    "token",
+    "access_token",
+    "refresh_token",
+    "apiKey",
+    "api_key",
+    "client_secret",
+    "password",
+    "code_verifier",
+];
+
+/// Placeholder for redacted credential values.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Replaces credential values in `key=value` pairs with `[REDACTED]`.
+///
+/// Covers query strings, form bodies, and URLs embedded in free text, such
+/// as `reqwest` error messages. Values end at `&`, `#`, whitespace, or a
+/// quote or bracket. The SDK applies this to every error it creates and to
+/// response bodies it logs; apply it to your own log lines that may contain
+/// request URLs.
+///
+/// # Example
+///
+/// ```
+/// use arcgis::redact_tokens;
+///
+/// let url = "https://example.com/FeatureServer/0/query?where=1%3D1&token=abc123&f=json";
+/// assert_eq!(
+///     redact_tokens(url),
+///     "https://example.com/FeatureServer/0/query?where=1%3D1&token=[REDACTED]&f=json"
+/// );
+/// ```
+pub fn redact_tokens(text: &str) -> Cow<'_, str> {
+    if !text.contains('=') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut changed = false;
+
+    for (eq, _) in text.match_indices('=') {
+        if eq < copied {
+            continue;
+        }
+        let key_start = text[..eq]
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        if !SENSITIVE_PARAMS.contains(&&text[key_start..eq]) {
+            continue;
+        }
+
+        let value_start = eq + 1;
+        let value_end = text[value_start..]
+            .find(|c: char| c.is_whitespace() || "&#\"'()<>[]{},".contains(c))
+            .map_or(text.len(), |i| value_start + i);
+        if value_end == value_start {
+            continue;
+        }
+
+        output.push_str(&text[copied..value_start]);
+        output.push_str(REDACTED);
+        copied = value_end;
+        changed = true;
+    }
+
+    if !changed {
+        return Cow::Borrowed(text);
+    }
+    output.push_str(&text[copied..]);
+    Cow::Owned(output)
+}
@@ -0,0 +1,272 @@
+//! Tests for the authorization code + PKCE provider.
+//!
+//! A mock server stands in for the portal's token endpoint, and the
+//! authorize-URL handler plays the browser by calling the loopback redirect.
+//! These tests require no credentials.
+
+mod common;
+
+use arcgis::{AuthProvider, AuthorizationCodeAuth};
+use secrecy::ExposeSecret;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Acts as the browser: redirects to the listener with the given parameters.
+///
+/// `state` overrides the state from the authorization URL when given.
+fn browser(
+    params: &'static [(&'static str, &'static str)],
+    state: Option<&'static str>,
+) -> impl Fn(&str) + Send + Sync + 'static {
+    move |authorize_url: &str| {
+        let url = url::Url::parse(authorize_url).expect("Valid authorize URL");
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(query["response_type"], "code");
+        assert_eq!(query["code_challenge_method"], "S256");
+        assert!(query.contains_key("code_challenge"));
+
+        let mut redirect = url::Url::parse(&query["redirect_uri"]).expect("Valid redirect URI");
+        redirect
+            .query_pairs_mut()
+            .extend_pairs(params)
+            .append_pair("state", state.unwrap_or(&query["state"]));
+
+        tokio::spawn(async move {
+            let response = arcgis::reqwest::get(redirect)
+                .await
+                .expect("Redirect delivered");
+            tracing::info!(status = %response.status(), "Browser received redirect response");
+        });
+    }
+}
+
+#[tokio::test]
+async fn test_sign_in_exchanges_code_with_pkce() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_sign_in_exchanges_code_with_pkce: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let token = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+            mockito::Matcher::UrlEncoded("code".into(), "test-code".into()),
+            mockito::Matcher::UrlEncoded("client_id".into(), "cli-app".into()),
+            mockito::Matcher::Regex("code_verifier=".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"access_token": "user-token", "expires_in": 1800,
+                "refresh_token": "refresh-1", "username": "analyst", "ssl": true}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = AuthorizationCodeAuth::builder("cli-app")
+        .portal_url(format!("{}/portal", server.url()))
+        .on_authorize_url(browser(&[("code", "test-code")], None))
+        .sign_in_timeout(Duration::from_secs(10))
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "user-token");
+    assert_eq!(auth.get_token().await?, "user-token");
+
+    let refresh_token = auth.refresh_token().await.expect("Refresh token stored");
+    assert_eq!(refresh_token.expose_secret(), "refresh-1");
+    assert!(!format!("{:?}", auth).contains("refresh-1"));
+
+    token.assert_async().await;
+
+    tracing::info!("test_sign_in_exchanges_code_with_pkce: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_token_renews_access_tokens() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_refresh_token_renews_access_tokens: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let refresh = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+            mockito::Matcher::UrlEncoded("refresh_token".into(), "saved-refresh".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "renewed-token", "expires_in": 0}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let auth = AuthorizationCodeAuth::builder("cli-app")
+        .portal_url(format!("{}/portal/sharing/rest", server.url()))
+        .refresh_token("saved-refresh")
+        .on_authorize_url(|_| panic!("Resumed session should not sign in"))
+        .build()?;
+
+    // Expired immediately, so each call renews with the same refresh token
+    assert_eq!(auth.get_token().await?, "renewed-token");
+    assert_eq!(auth.get_token().await?, "renewed-token");
+
+    refresh.assert_async().await;
+
+    tracing::info!("test_refresh_token_renews_access_tokens: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rejected_refresh_token_falls_back_to_sign_in() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_rejected_refresh_token_falls_back_to_sign_in: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    // ArcGIS nests the OAuth error code and answers with HTTP 200
+    let refresh = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "grant_type".into(),
+            "refresh_token".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "text/plain")
+        .with_body(
+            r#"{"error": {"code": 400, "error": "invalid_grant",
+                "error_description": "Invalid refresh_token", "message": "Invalid refresh_token",
+                "details": []}}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let exchange = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "grant_type".into(),
+            "authorization_code".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"access_token": "user-token", "expires_in": 1800, "refresh_token": "refresh-2"}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = AuthorizationCodeAuth::builder("cli-app")
+        .portal_url(format!("{}/portal", server.url()))
+        .refresh_token("revoked-refresh")
+        .on_authorize_url(browser(&[("code", "test-code")], None))
+        .sign_in_timeout(Duration::from_secs(10))
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "user-token");
+    let refresh_token = auth.refresh_token().await.expect("Refresh token replaced");
+    assert_eq!(refresh_token.expose_secret(), "refresh-2");
+
+    refresh.assert_async().await;
+    exchange.assert_async().await;
+
+    tracing::info!("test_rejected_refresh_token_falls_back_to_sign_in: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sign_in_ignores_forged_and_stalled_redirects() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_sign_in_ignores_forged_and_stalled_redirects: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let token = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "code".into(),
+            "test-code".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "user-token", "expires_in": 1800}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    // A silent connection, then a forged redirect, then the real one
+    let browser = |authorize_url: &str| {
+        let url = url::Url::parse(authorize_url).expect("Valid authorize URL");
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        let redirect = url::Url::parse(&query["redirect_uri"]).expect("Valid redirect URI");
+        let mut forged = redirect.clone();
+        forged
+            .query_pairs_mut()
+            .append_pair("code", "forged-code")
+            .append_pair("state", "forged");
+        let mut genuine = redirect;
+        genuine
+            .query_pairs_mut()
+            .append_pair("code", "test-code")
+            .append_pair("state", &query["state"]);
+
+        tokio::spawn(async move {
+            let address = format!(
+                "127.0.0.1:{}",
+                forged.port().expect("Redirect URI has a port")
+            );
+            let _stalled = tokio::net::TcpStream::connect(address)
+                .await
+                .expect("Listener accepts connections");
+
+            let response = arcgis::reqwest::get(forged)
+                .await
+                .expect("Forged redirect answered");
+            assert_eq!(response.status(), 400);
+
+            arcgis::reqwest::get(genuine)
+                .await
+                .expect("Redirect delivered");
+        });
+    };
+
+    let auth = AuthorizationCodeAuth::builder("cli-app")
+        .portal_url(format!("{}/portal", server.url()))
+        .on_authorize_url(browser)
+        .sign_in_timeout(Duration::from_secs(20))
+        .build()?;
+
+    auth.sign_in().await?;
+    assert_eq!(auth.get_token().await?, "user-token");
+
+    token.assert_async().await;
+
+    tracing::info!("test_sign_in_ignores_forged_and_stalled_redirects: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sign_in_reports_denied_authorization() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_sign_in_reports_denied_authorization: Starting");
+
+    let auth = AuthorizationCodeAuth::builder("cli-app")
+        .portal_url("http://127.0.0.1:1/portal")
+        .on_authorize_url(browser(
+            &[
+                ("error", "access_denied"),
+                ("error_description", "The user denied your request."),
+            ],
+            None,
+        ))
+        .sign_in_timeout(Duration::from_secs(10))
+        .build()?;
+
+    let err = auth
+        .sign_in()
+        .await
+        .expect_err("Denied sign-in should fail");
+    assert!(err.to_string().contains("denied your request"), "{}", err);
+
+    tracing::info!("test_sign_in_reports_denied_authorization: Completed");
+    Ok(())
+}
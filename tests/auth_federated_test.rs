@@ -0,0 +1,386 @@
+//! Tests for federated server token exchange.
+//!
+//! A mock server plays both the portal and a federated ArcGIS Server.
+//! These tests require no credentials.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, AuthProvider, FeatureServiceClient, FederatedServerAuth, LayerId,
+    MapServiceClient, NoAuth,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+/// `generateToken` response for a token expiring in the year 2100.
+fn token_body(token: &str) -> String {
+    format!(
+        r#"{{"token": "{}", "expires": 4102444800000, "ssl": true}}"#,
+        token
+    )
+}
+
+fn token_is(token: &str) -> mockito::Matcher {
+    mockito::Matcher::UrlEncoded("token".into(), token.into())
+}
+
+/// Mocks the `rest/info` of the server at `/server`, federated with the
+/// portal at `/portal` on the same mock server.
+async fn mock_federated_info(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    let body = format!(
+        r#"{{"currentVersion": 11.1, "owningSystemUrl": "{}/portal"}}"#,
+        server.url()
+    );
+    server
+        .mock("GET", "/server/rest/info")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_server_token_is_exchanged_once_per_server() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_server_token_is_exchanged_once_per_server: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let server_url = format!("{}/server", server.url());
+    let info = mock_federated_info(&mut server).await;
+    let exchange = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("request".into(), "getToken".into()),
+            mockito::Matcher::UrlEncoded("serverUrl".into(), server_url.clone()),
+            token_is("portal-token"),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(token_body("server-token"))
+        .expect(1)
+        .create_async()
+        .await;
+    let query = server
+        .mock("GET", "/server/rest/services/Parcels/FeatureServer/0/query")
+        .match_query(token_is("server-token"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+    let metadata = server
+        .mock("GET", "/server/rest/services/Basemap/MapServer")
+        .match_query(token_is("server-token"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"currentVersion": 11.1}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = FederatedServerAuth::new(
+        format!("{}/portal", server.url()),
+        ApiKeyAuth::new("portal-token"),
+    )?;
+    let client = ArcGISClient::new(auth);
+
+    let features = FeatureServiceClient::new(
+        format!("{}/rest/services/Parcels/FeatureServer", server_url),
+        &client,
+    );
+    features.query(LayerId::new(0)).execute().await?;
+
+    let map = MapServiceClient::new(
+        format!("{}/rest/services/Basemap/MapServer", server_url),
+        &client,
+    );
+    map.get_metadata().await?;
+
+    info.assert_async().await;
+    exchange.assert_async().await;
+    query.assert_async().await;
+    metadata.assert_async().await;
+
+    tracing::info!("test_server_token_is_exchanged_once_per_server: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_portal_urls_use_portal_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_portal_urls_use_portal_token: Starting");
+
+    let auth = FederatedServerAuth::new(
+        "https://gis.example.com/portal",
+        ApiKeyAuth::new("portal-token"),
+    )?;
+    assert_eq!(
+        auth.token_url(),
+        "https://gis.example.com/portal/sharing/rest/generateToken"
+    );
+
+    for url in [
+        "https://gis.example.com/portal/sharing/rest/content/items/abc",
+        "https://GIS.Example.com/portal/sharing/rest/content/items/abc",
+        "https://gis.example.com/portal/rest/services",
+    ] {
+        let token = auth.token_for_url(url).await?;
+        assert_eq!(token.as_deref(), Some("portal-token"), "{}", url);
+    }
+    assert_eq!(auth.get_token().await?, "portal-token");
+
+    tracing::info!("test_portal_urls_use_portal_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rejected_server_token_is_exchanged_again() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_rejected_server_token_is_exchanged_again: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    mock_federated_info(&mut server).await;
+    let exchanges = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&exchanges);
+    let exchange = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => token_body("stale-token"),
+                _ => token_body("fresh-token"),
+            }
+            .into_bytes()
+        })
+        .expect(2)
+        .create_async()
+        .await;
+    let rejected = server
+        .mock("GET", "/server/rest/services/Parcels/FeatureServer/0/query")
+        .match_query(token_is("stale-token"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": {"code": 498, "message": "Invalid token.", "details": []}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let accepted = server
+        .mock("GET", "/server/rest/services/Parcels/FeatureServer/0/query")
+        .match_query(token_is("fresh-token"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = FederatedServerAuth::new(
+        format!("{}/portal", server.url()),
+        ApiKeyAuth::new("portal-token"),
+    )?;
+    let client = ArcGISClient::new(auth);
+    let service = FeatureServiceClient::new(
+        format!(
+            "{}/server/rest/services/Parcels/FeatureServer",
+            server.url()
+        ),
+        &client,
+    );
+
+    let features = service.query(LayerId::new(0)).execute().await?;
+    assert!(features.features().is_empty());
+
+    exchange.assert_async().await;
+    assert_eq!(exchanges.load(Ordering::SeqCst), 2);
+    rejected.assert_async().await;
+    accepted.assert_async().await;
+
+    tracing::info!("test_rejected_server_token_is_exchanged_again: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unfederated_servers_get_no_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_unfederated_servers_get_no_token: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let info = server
+        .mock("GET", "/standalone/rest/info")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"currentVersion": 11.1}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let exchange = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let auth = FederatedServerAuth::new(
+        format!("{}/portal", server.url()),
+        ApiKeyAuth::new("portal-token"),
+    )?;
+    let url = format!(
+        "{}/standalone/rest/services/Parcels/MapServer",
+        server.url()
+    );
+
+    // Checked once, then remembered
+    assert_eq!(auth.token_for_url(&url).await?, None);
+    assert_eq!(auth.token_for_url(&url).await?, None);
+
+    info.assert_async().await;
+    exchange.assert_async().await;
+
+    tracing::info!("test_unfederated_servers_get_no_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_registered_server_skips_federation_check() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_registered_server_skips_federation_check: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let server_url = format!("{}/server", server.url());
+    let info = server
+        .mock("GET", "/server/rest/info")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let exchange = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "serverUrl".into(),
+            server_url.clone(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(token_body("server-token"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = FederatedServerAuth::new(
+        format!("{}/portal", server.url()),
+        ApiKeyAuth::new("portal-token"),
+    )?
+    .federated_server(&server_url);
+
+    let token = auth
+        .token_for_url(&format!(
+            "{}/rest/services/Parcels/FeatureServer/0/query",
+            server_url
+        ))
+        .await?;
+    assert_eq!(token.as_deref(), Some("server-token"));
+
+    info.assert_async().await;
+    exchange.assert_async().await;
+
+    tracing::info!("test_registered_server_skips_federation_check: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_anonymous_portal_sends_no_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_anonymous_portal_sends_no_token: Starting");
+
+    let auth = FederatedServerAuth::new("https://gis.example.com/portal", NoAuth)?;
+    assert!(!auth.requires_token_param());
+    assert_eq!(
+        auth.token_for_url("https://gis.example.com/server/rest/services/Parcels/MapServer")
+            .await?,
+        None
+    );
+
+    tracing::info!("test_anonymous_portal_sends_no_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_federation_check_is_retried() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_failed_federation_check_is_retried: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let server_url = format!("{}/server", server.url());
+    let unavailable = server
+        .mock("GET", "/server/rest/info")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let info = mock_federated_info(&mut server).await;
+    let exchange = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(token_body("server-token"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = FederatedServerAuth::new(
+        format!("{}/portal", server.url()),
+        ApiKeyAuth::new("portal-token"),
+    )?;
+    let url = format!("{}/rest/services/Parcels/FeatureServer/0/query", server_url);
+
+    // The outage is not remembered; the next request checks again
+    assert_eq!(auth.token_for_url(&url).await?, None);
+    assert_eq!(
+        auth.token_for_url(&url).await?.as_deref(),
+        Some("server-token")
+    );
+
+    unavailable.assert_async().await;
+    info.assert_async().await;
+    exchange.assert_async().await;
+
+    tracing::info!("test_failed_federation_check_is_retried: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_enterprise_portal_token_stays_off_arcgis_online() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_enterprise_portal_token_stays_off_arcgis_online: Starting");
+
+    let enterprise = FederatedServerAuth::new(
+        "https://gis.example.com/portal",
+        ApiKeyAuth::new("enterprise-token"),
+    )?;
+    let token = enterprise
+        .token_for_url(
+            "https://services.arcgis.com/org123/arcgis/rest/services/Parcels/FeatureServer",
+        )
+        .await;
+    assert_eq!(token?, None);
+
+    let online =
+        FederatedServerAuth::new("https://www.arcgis.com", ApiKeyAuth::new("online-token"))?;
+    let token = online
+        .token_for_url(
+            "https://services.arcgis.com/org123/arcgis/rest/services/Parcels/FeatureServer",
+        )
+        .await?;
+    assert_eq!(token.as_deref(), Some("online-token"));
+
+    tracing::info!("test_enterprise_portal_token_stays_off_arcgis_online: Completed");
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//! Tests for routing requests to authentication providers by URL.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ApiKeyTier, ArcGISClient, AuthProvider, AuthRouter, ErrorKind,
+    FeatureServiceClient, LayerId, MapServiceClient, NoAuth, ServiceKind,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+#[tokio::test]
+async fn test_routes_match_in_order() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_routes_match_in_order: Starting");
+
+    let auth = AuthRouter::builder(NoAuth)
+        .path_prefix(
+            "/server/rest/services/Secure",
+            ApiKeyAuth::new("secure-key"),
+        )
+        .host("GIS.example.com", ApiKeyAuth::new("enterprise-key"))
+        .service_kind(ServiceKind::Geocode, ApiKeyAuth::new("geocode-key"))
+        .api_key_tier(ApiKeyTier::Location, ApiKeyAuth::new("location-key"))
+        .build();
+
+    let token = |url: &'static str| {
+        let auth = &auth;
+        async move { auth.token_for_url(url).await }
+    };
+
+    assert_eq!(
+        token("https://gis.example.com/server/rest/services/Secure/FeatureServer/0/query")
+            .await?
+            .as_deref(),
+        Some("secure-key")
+    );
+    assert_eq!(
+        token("https://gis.example.com/server/rest/services/Open/MapServer")
+            .await?
+            .as_deref(),
+        Some("enterprise-key")
+    );
+    assert_eq!(
+        token("https://geocode.arcgis.com/arcgis/rest/services/World/GeocodeServer/findAddressCandidates")
+            .await?
+            .as_deref(),
+        Some("geocode-key")
+    );
+    assert_eq!(
+        token("https://route-api.arcgis.com/arcgis/rest/services/World/Route/NAServer/Route_World/solve")
+            .await?
+            .as_deref(),
+        Some("location-key")
+    );
+    assert_eq!(
+        token("https://services.arcgisonline.com/arcgis/rest/services/World_Imagery/MapServer")
+            .await?,
+        None
+    );
+    assert_eq!(token("not a url").await?, None);
+    assert!(!auth.requires_token_param());
+
+    tracing::info!("test_routes_match_in_order: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_single_client_uses_routed_providers() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_single_client_uses_routed_providers: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/rest/services/Parcels/FeatureServer/0/query")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "token".into(),
+            "features-key".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+    let anonymous = server
+        .mock("GET", "/rest/services/Basemap/MapServer")
+        // Anonymous: no token parameter
+        .match_query(mockito::Matcher::Exact("f=json".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"currentVersion": 11.1}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = AuthRouter::builder(NoAuth)
+        .api_key_tier(ApiKeyTier::Features, ApiKeyAuth::new("features-key"))
+        .build();
+    let client = ArcGISClient::new(auth);
+
+    let features = FeatureServiceClient::new(
+        format!("{}/rest/services/Parcels/FeatureServer", server.url()),
+        &client,
+    );
+    features.query(LayerId::new(0)).execute().await?;
+
+    let map = MapServiceClient::new(
+        format!("{}/rest/services/Basemap/MapServer", server.url()),
+        &client,
+    );
+    map.get_metadata().await?;
+
+    query.assert_async().await;
+    anonymous.assert_async().await;
+
+    tracing::info!("test_single_client_uses_routed_providers: Completed");
+    Ok(())
+}
+
+/// Stands in for an OAuth provider that can always refresh its own token.
+#[derive(Clone, Default)]
+struct RefreshableAuth {
+    invalidations: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl AuthProvider for RefreshableAuth {
+    async fn get_token(&self) -> arcgis::Result<String> {
+        Ok("oauth-token".to_string())
+    }
+
+    async fn invalidate_token(&self, _token: &str) -> bool {
+        self.invalidations.fetch_add(1, Ordering::SeqCst);
+        true
+    }
+}
+
+#[tokio::test]
+async fn test_rejected_token_invalidates_only_routed_provider() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_rejected_token_invalidates_only_routed_provider: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let rejected = server
+        .mock("GET", "/rest/services/Parcels/FeatureServer/0/query")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "token".into(),
+            "revoked-key".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": {"code": 498, "message": "Invalid token.", "details": []}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let oauth = RefreshableAuth::default();
+    let auth = AuthRouter::builder(oauth.clone())
+        .api_key_tier(ApiKeyTier::Features, ApiKeyAuth::new("revoked-key"))
+        .build();
+    let client = ArcGISClient::new(auth);
+
+    let features = FeatureServiceClient::new(
+        format!("{}/rest/services/Parcels/FeatureServer", server.url()),
+        &client,
+    );
+    let err = features
+        .query(LayerId::new(0))
+        .execute()
+        .await
+        .expect_err("Revoked key should not be replayed");
+    assert!(matches!(err.kind(), ErrorKind::InvalidToken { .. }));
+
+    rejected.assert_async().await;
+    assert_eq!(oauth.invalidations.load(Ordering::SeqCst), 0);
+
+    tracing::info!("test_rejected_token_invalidates_only_routed_provider: Completed");
+    Ok(())
+}
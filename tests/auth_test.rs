@@ -2,15 +2,17 @@
 
 mod common;
 
-use arcgis::ApiKeyAuth;
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, AuthProvider, ClientCredentialsAuth, ErrorKind, TokenClient,
+    UsernamePasswordAuth,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[tokio::test]
 async fn test_api_key_auth() -> anyhow::Result<()> {
     common::init_tracing();
     tracing::info!("test_api_key_auth: Starting");
 
-    use arcgis::AuthProvider;
-
     tracing::info!("test_api_key_auth: Creating API key auth");
     let auth = ApiKeyAuth::new("test_api_key");
 
@@ -23,3 +25,324 @@ async fn test_api_key_auth() -> anyhow::Result<()> {
     tracing::info!("test_api_key_auth: Completed");
     Ok(())
 }
+
+const TOKEN_RESPONSE: &str = r#"{"access_token": "portal-token", "expires_in": 7200}"#;
+
+#[tokio::test]
+async fn test_client_credentials_uses_configured_portal() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_client_credentials_uses_configured_portal: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let token = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "grant_type".into(),
+            "client_credentials".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(TOKEN_RESPONSE)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = ClientCredentialsAuth::builder("client_id", "client_secret")
+        .portal_url(format!("{}/portal/", server.url()))
+        .build()?;
+    assert_eq!(
+        auth.token_url(),
+        format!("{}/portal/sharing/rest/oauth2/token", server.url())
+    );
+
+    assert_eq!(auth.get_token().await?, "portal-token");
+    assert_eq!(auth.get_token().await?, "portal-token");
+
+    token.assert_async().await;
+
+    tracing::info!("test_client_credentials_uses_configured_portal: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_credentials_discovers_owning_portal() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_client_credentials_discovers_owning_portal: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let info = server
+        .mock("GET", "/server/rest/info")
+        .match_query(mockito::Matcher::UrlEncoded("f".into(), "json".into()))
+        .match_header("user-agent", "discovery-test/1.0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"currentVersion": 11.3, "owningSystemUrl": "{}/portal"}}"#,
+            server.url()
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let token = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_header("user-agent", "discovery-test/1.0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(TOKEN_RESPONSE)
+        .expect(1)
+        .create_async()
+        .await;
+
+    // Discovery runs before the client exists, so it takes the settings early
+    let client_builder = ArcGISClient::builder().user_agent("discovery-test/1.0");
+    let service_url = format!("{}/server/rest/services/Cities/FeatureServer", server.url());
+    let auth = ClientCredentialsAuth::builder("client_id", "client_secret")
+        .http_config(client_builder.http_config())
+        .discover_portal(&service_url)
+        .await?
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "portal-token");
+
+    info.assert_async().await;
+    token.assert_async().await;
+
+    tracing::info!("test_client_credentials_discovers_owning_portal: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_discovery_fails_for_standalone_server() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_discovery_fails_for_standalone_server: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let _info = server
+        .mock("GET", "/server/rest/info")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"currentVersion": 11.3}"#)
+        .create_async()
+        .await;
+
+    let err = ClientCredentialsAuth::builder("client_id", "client_secret")
+        .discover_portal(&format!("{}/server", server.url()))
+        .await
+        .expect_err("Standalone server has no owning portal");
+    assert!(matches!(err.kind(), ErrorKind::Auth(_)));
+
+    tracing::info!("test_discovery_fails_for_standalone_server: Completed");
+    Ok(())
+}
+
+#[test]
+fn test_client_credentials_defaults_to_arcgis_online() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_client_credentials_defaults_to_arcgis_online: Starting");
+
+    let auth = ClientCredentialsAuth::new("client_id".to_string(), "secret".to_string())?;
+    assert_eq!(
+        auth.token_url(),
+        "https://www.arcgis.com/sharing/rest/oauth2/token"
+    );
+
+    tracing::info!("test_client_credentials_defaults_to_arcgis_online: Completed");
+    Ok(())
+}
+
+/// Builds a `generateToken` response expiring `valid_for` from now.
+fn generate_token_response(token: &str, valid_for: Duration) -> anyhow::Result<String> {
+    let expires = (SystemTime::now() + valid_for)
+        .duration_since(UNIX_EPOCH)?
+        .as_millis();
+    Ok(format!(
+        r#"{{"token": "{}", "expires": {}, "ssl": true}}"#,
+        token, expires
+    ))
+}
+
+#[tokio::test]
+async fn test_username_password_generates_and_caches_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_username_password_generates_and_caches_token: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("username".into(), "analyst".into()),
+            mockito::Matcher::UrlEncoded("password".into(), "hunter2".into()),
+            mockito::Matcher::UrlEncoded("client".into(), "requestip".into()),
+            mockito::Matcher::UrlEncoded("expiration".into(), "120".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(generate_token_response(
+            "user-token",
+            Duration::from_secs(7200),
+        )?)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = UsernamePasswordAuth::builder("analyst", "hunter2")
+        .portal_url(format!("{}/portal", server.url()))
+        .expiration(Duration::from_secs(2 * 60 * 60))
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "user-token");
+    assert_eq!(auth.get_token().await?, "user-token");
+    assert!(!format!("{:?}", auth).contains("hunter2"));
+
+    generate.assert_async().await;
+
+    tracing::info!("test_username_password_generates_and_caches_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_username_password_referer_mode() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_username_password_referer_mode: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("client".into(), "referer".into()),
+            mockito::Matcher::UrlEncoded("referer".into(), "https://app.example.com".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(generate_token_response(
+            "referer-token",
+            Duration::from_secs(3600),
+        )?)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = UsernamePasswordAuth::builder("analyst", "hunter2")
+        .portal_url(format!("{}/portal/sharing/rest", server.url()))
+        .client(TokenClient::Referer("https://app.example.com".to_string()))
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "referer-token");
+
+    generate.assert_async().await;
+
+    tracing::info!("test_username_password_referer_mode: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_username_password_refreshes_expiring_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_username_password_refreshes_expiring_token: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(generate_token_response("short-lived", Duration::ZERO)?)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let auth = UsernamePasswordAuth::builder("analyst", "hunter2")
+        .portal_url(format!("{}/portal", server.url()))
+        .build()?;
+
+    auth.get_token().await?;
+    auth.get_token().await?;
+
+    generate.assert_async().await;
+
+    tracing::info!("test_username_password_refreshes_expiring_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_username_password_rejected_credentials() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_username_password_rejected_credentials: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let _generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"error": {"code": 400, "message": "Unable to generate token.",
+                          "details": ["Invalid username or password."]}}"#,
+        )
+        .create_async()
+        .await;
+
+    let auth = UsernamePasswordAuth::builder("analyst", "wrong")
+        .portal_url(format!("{}/portal", server.url()))
+        .build()?;
+
+    let err = auth
+        .get_token()
+        .await
+        .expect_err("Rejected credentials should fail");
+    assert!(
+        err.to_string().contains("Invalid username or password"),
+        "{}",
+        err
+    );
+
+    tracing::info!("test_username_password_rejected_credentials: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_username_password_discovers_portal_with_http_config() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_username_password_discovers_portal_with_http_config: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let info = server
+        .mock("GET", "/server/rest/info")
+        .match_query(mockito::Matcher::UrlEncoded("f".into(), "json".into()))
+        .match_header("user-agent", "discovery-test/1.0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"currentVersion": 11.3, "owningSystemUrl": "{}/portal"}}"#,
+            server.url()
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .match_header("user-agent", "discovery-test/1.0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(generate_token_response(
+            "user-token",
+            Duration::from_secs(3600),
+        )?)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client_builder = ArcGISClient::builder().user_agent("discovery-test/1.0");
+    let auth = UsernamePasswordAuth::builder("analyst", "hunter2")
+        .http_config(client_builder.http_config())
+        .discover_portal(&format!("{}/server", server.url()))
+        .await?
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "user-token");
+
+    info.assert_async().await;
+    generate.assert_async().await;
+
+    tracing::info!("test_username_password_discovers_portal_with_http_config: Completed");
+    Ok(())
+}
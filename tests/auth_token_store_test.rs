@@ -0,0 +1,289 @@
+//! Tests for persistent token stores.
+//!
+//! Mock servers stand in for the portal token endpoints. These tests require
+//! no credentials.
+
+mod common;
+
+use arcgis::{
+    AuthProvider, AuthorizationCodeAuth, ClientCredentialsAuth, FileTokenStore, MemoryTokenStore,
+    PersistedToken, TokenClient, TokenKey, TokenStore, UsernamePasswordAuth,
+};
+use chrono::{Duration, Utc};
+use std::path::PathBuf;
+
+fn temp_store_path() -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("arcgis-tokens-{}", uuid::Uuid::new_v4()))
+        .join("tokens.json")
+}
+
+#[tokio::test]
+async fn test_file_store_round_trip_and_evict() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_file_store_round_trip_and_evict: Starting");
+
+    let path = temp_store_path();
+    let store = FileTokenStore::new(&path);
+    let key = TokenKey::new(
+        "client_credentials",
+        "app",
+        "https://example.com/oauth2/token",
+    );
+    let other = TokenKey::new("password", "analyst", "https://example.com/generateToken");
+
+    assert!(store.get(&key).await?.is_none());
+
+    let token = PersistedToken::new("secret-token", Utc::now() + Duration::hours(1));
+    store.put(&key, token.clone()).await?;
+    store
+        .put(
+            &other,
+            PersistedToken::new("other-token", Utc::now() + Duration::hours(1)),
+        )
+        .await?;
+
+    // A new instance reads what the previous one wrote
+    let reopened = FileTokenStore::new(&path);
+    assert_eq!(reopened.get(&key).await?, Some(token));
+    assert!(!format!("{:?}", reopened.get(&key).await?).contains("secret-token"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path)?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let dir_mode = std::fs::metadata(path.parent().expect("Parent dir"))?
+            .permissions()
+            .mode();
+        assert_eq!(dir_mode & 0o777, 0o700);
+    }
+
+    reopened.evict(&key).await?;
+    assert!(store.get(&key).await?.is_none());
+    assert!(store.get(&other).await?.is_some());
+
+    if let Some(dir) = path.parent() {
+        std::fs::remove_dir_all(dir)?;
+    }
+
+    tracing::info!("test_file_store_round_trip_and_evict: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_credentials_reuse_stored_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_client_credentials_reuse_stored_token: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let token = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "app-token", "expires_in": 7200}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let store = MemoryTokenStore::new();
+    let build = || {
+        ClientCredentialsAuth::builder("app", "secret")
+            .portal_url(format!("{}/portal", server.url()))
+            .token_store(store.clone())
+            .build()
+    };
+
+    // The second provider stands in for the next run of the program
+    assert_eq!(build()?.get_token().await?, "app-token");
+    let next_run = build()?;
+    assert_eq!(next_run.get_token().await?, "app-token");
+
+    token.assert_async().await;
+
+    // A rejected token is evicted so the next run requests a new one
+    assert!(next_run.invalidate_token("app-token").await);
+    let key = TokenKey::new("client_credentials", "app", next_run.token_url());
+    assert!(store.get(&key).await?.is_none());
+
+    // Concurrent rejections of the same token are still recognized, but a
+    // provider holding no token has nothing to refresh
+    assert!(next_run.invalidate_token("app-token").await);
+    assert!(!build()?.invalidate_token("tier-api-key").await);
+
+    tracing::info!("test_client_credentials_reuse_stored_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_username_password_skips_expired_stored_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_username_password_skips_expired_stored_token: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"token": "fresh-token", "expires": 4102444800000, "ssl": true}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let path = temp_store_path();
+    let token_url = format!("{}/portal/sharing/rest/generateToken", server.url());
+    FileTokenStore::new(&path)
+        .put(
+            &TokenKey::new("password", "analyst", token_url.as_str()),
+            PersistedToken::new("expired-token", Utc::now() - Duration::minutes(1)),
+        )
+        .await?;
+
+    let build = || {
+        UsernamePasswordAuth::builder("analyst", "password")
+            .token_url(token_url.as_str())
+            .token_store(FileTokenStore::new(&path))
+            .build()
+    };
+
+    assert_eq!(build()?.get_token().await?, "fresh-token");
+    assert_eq!(build()?.get_token().await?, "fresh-token");
+
+    generate.assert_async().await;
+
+    if let Some(dir) = path.parent() {
+        std::fs::remove_dir_all(dir)?;
+    }
+
+    tracing::info!("test_username_password_skips_expired_stored_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_authorization_code_resumes_from_stored_refresh_token() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_authorization_code_resumes_from_stored_refresh_token: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let refresh = server
+        .mock("POST", "/portal/sharing/rest/oauth2/token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+            mockito::Matcher::UrlEncoded("refresh_token".into(), "stored-refresh".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "renewed-token", "expires_in": 1800}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let store = MemoryTokenStore::new();
+    let token_url = format!("{}/portal/sharing/rest/oauth2/token", server.url());
+    let key = TokenKey::new("authorization_code", "cli-app", token_url.as_str());
+    store
+        .put(
+            &key,
+            PersistedToken::new("expired-token", Utc::now() - Duration::minutes(1))
+                .with_refresh_token("stored-refresh"),
+        )
+        .await?;
+
+    let auth = AuthorizationCodeAuth::builder("cli-app")
+        .portal_url(format!("{}/portal", server.url()))
+        .token_store(store.clone())
+        .on_authorize_url(|_| panic!("Stored session should not sign in"))
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "renewed-token");
+
+    let stored = store.get(&key).await?.expect("Renewed token stored");
+    assert_eq!(stored.token(), "renewed-token");
+    assert_eq!(stored.refresh_token().as_deref(), Some("stored-refresh"));
+
+    refresh.assert_async().await;
+
+    tracing::info!("test_authorization_code_resumes_from_stored_refresh_token: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_store_backs_up_unreadable_file() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_file_store_backs_up_unreadable_file: Starting");
+
+    let path = temp_store_path();
+    let dir = path.parent().expect("Parent dir");
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(&path, b"not json")?;
+
+    let store = FileTokenStore::new(&path);
+    let key = TokenKey::new("password", "analyst", "https://example.com/generateToken");
+    store
+        .put(
+            &key,
+            PersistedToken::new("secret-token", Utc::now() + Duration::hours(1)),
+        )
+        .await?;
+
+    assert_eq!(std::fs::read(dir.join("tokens.json.bak"))?, b"not json");
+    assert!(store.get(&key).await?.is_some());
+
+    std::fs::remove_dir_all(dir)?;
+
+    tracing::info!("test_file_store_backs_up_unreadable_file: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_referer_bound_tokens_are_stored_apart() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_referer_bound_tokens_are_stored_apart: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let generate = server
+        .mock("POST", "/portal/sharing/rest/generateToken")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "referer".into(),
+            "https://app.example.com".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"token": "referer-token", "expires": 4102444800000, "ssl": true}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let store = MemoryTokenStore::new();
+    let token_url = format!("{}/portal/sharing/rest/generateToken", server.url());
+    let key = TokenKey::new("password", "analyst", token_url.as_str());
+    store
+        .put(
+            &key,
+            PersistedToken::new("ip-token", Utc::now() + Duration::hours(1)),
+        )
+        .await?;
+
+    let auth = UsernamePasswordAuth::builder("analyst", "password")
+        .token_url(token_url.as_str())
+        .client(TokenClient::Referer("https://app.example.com".to_string()))
+        .token_store(store.clone())
+        .build()?;
+
+    assert_eq!(auth.get_token().await?, "referer-token");
+
+    let stored = store
+        .get(&key.clone().with_referer("https://app.example.com"))
+        .await?
+        .expect("Referer-bound token stored");
+    assert_eq!(stored.token(), "referer-token");
+    assert_eq!(
+        store.get(&key).await?.map(|token| token.token().clone()),
+        Some("ip-token".to_string())
+    );
+
+    generate.assert_async().await;
+
+    tracing::info!("test_referer_bound_tokens_are_stored_apart: Completed");
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Tests for the blocking service clients.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use arcgis::blocking::{ArcGISClient, FeatureServiceClient, GeocodeServiceClient};
+use arcgis::{ErrorKind, LayerId, NoAuth, RetryPolicy};
+use mockito::Matcher;
+
+#[test]
+fn test_blocking_feature_query() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_blocking_feature_query: Starting");
+
+    let mut server = mockito::Server::new();
+    let root = server
+        .mock("GET", "/FeatureServer")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"currentVersion": 11.1, "maxRecordCount": 1000, "layers": [{"id": 0, "name": "Parcels"}]}"#)
+        .create();
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(Matcher::UrlEncoded("where".into(), "1=1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"features": [{"attributes": {"OBJECTID": 1}}, {"attributes": {"OBJECTID": 2}}]}"#,
+        )
+        .expect(3)
+        .create();
+
+    let client = ArcGISClient::new(NoAuth)?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let capabilities = service.capabilities()?;
+    assert_eq!(*capabilities.max_record_count(), Some(1000));
+    assert!(service.cached_capabilities().is_some());
+
+    let page = service
+        .query(LayerId::new(0))
+        .where_clause("1=1")
+        .execute()?;
+    assert_eq!(page.features().len(), 2);
+
+    // Clones share the runtime
+    let all = service
+        .clone()
+        .query(LayerId::new(0))
+        .where_clause("1=1")
+        .execute_all()?;
+    assert_eq!(all.features().len(), 2);
+
+    let streamed = service
+        .query(LayerId::new(0))
+        .where_clause("1=1")
+        .stream()
+        .collect::<arcgis::Result<Vec<_>>>()?;
+    assert_eq!(streamed.len(), 2);
+
+    root.assert();
+    query.assert();
+
+    tracing::info!("test_blocking_feature_query: Completed");
+    Ok(())
+}
+
+#[test]
+fn test_blocking_client_from_async_reports_errors() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_blocking_client_from_async_reports_errors: Starting");
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/GeocodeServer/findAddressCandidates")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": {"code": 498, "message": "Invalid token.", "details": []}}"#)
+        .create();
+
+    let async_client = arcgis::ArcGISClient::builder()
+        .retry_policy(RetryPolicy::none())
+        .build()?;
+    let client = ArcGISClient::from_async(async_client)?;
+    let geocoder = GeocodeServiceClient::new(format!("{}/GeocodeServer", server.url()), &client);
+
+    let err = geocoder
+        .find_address_candidates("380 New York St, Redlands, CA")
+        .expect_err("Invalid token should fail");
+    assert!(matches!(err.kind(), ErrorKind::InvalidToken { .. }));
+
+    mock.assert();
+
+    tracing::info!("test_blocking_client_from_async_reports_errors: Completed");
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! Tests for ArcGISClient builder transport settings.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{ArcGISClient, ClientCredentialsAuth, FeatureServiceClient, LayerId, RetryPolicy};
+use std::time::Duration;
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+#[tokio::test]
+async fn test_builder_sends_user_agent_and_referer() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_builder_sends_user_agent_and_referer: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .match_header("user-agent", "arcgis-tests/1.0")
+        .match_header("referer", "https://app.example.com")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::builder()
+        .user_agent("arcgis-tests/1.0")
+        .referer("https://app.example.com")
+        .connect_timeout(Duration::from_secs(5))
+        .build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    service.query(LayerId::new(0)).execute().await?;
+
+    query.assert_async().await;
+
+    tracing::info!("test_builder_sends_user_agent_and_referer: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_uses_preconfigured_client() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_builder_uses_preconfigured_client: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .match_header("x-custom", "preconfigured")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut headers = arcgis::reqwest::header::HeaderMap::new();
+    headers.insert("x-custom", "preconfigured".parse()?);
+    let http = arcgis::reqwest::Client::builder()
+        .default_headers(headers)
+        .build()?;
+
+    let auth = ClientCredentialsAuth::new("client_id".to_string(), "secret".to_string())?;
+    let client = ArcGISClient::builder()
+        .auth(auth)
+        .http_client(http)
+        .build()?;
+    assert!(client.auth().requires_token_param());
+
+    let public = ArcGISClient::builder()
+        .http_client(client.http().clone())
+        .build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &public);
+    service.query(LayerId::new(0)).execute().await?;
+
+    query.assert_async().await;
+
+    tracing::info!("test_builder_uses_preconfigured_client: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_timeout_applies() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_builder_timeout_applies: Starting");
+
+    // Accepts connections (via the OS backlog) but never responds.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}/FeatureServer", listener.local_addr()?);
+
+    let client = ArcGISClient::builder()
+        .timeout(Duration::from_millis(200))
+        .retry_policy(RetryPolicy::none())
+        .build()?;
+    let service = FeatureServiceClient::new(url, &client);
+
+    let started = std::time::Instant::now();
+    let result = service.query(LayerId::new(0)).execute().await;
+    assert!(result.is_err(), "Request to silent server should time out");
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    drop(listener);
+
+    tracing::info!("test_builder_timeout_applies: Completed");
+    Ok(())
+}
+
+#[test]
+fn test_builder_rejects_invalid_referer() {
+    common::init_tracing();
+    tracing::info!("test_builder_rejects_invalid_referer: Starting");
+
+    let result = ArcGISClient::builder()
+        .referer("https://bad.example.com/\nInjected: header")
+        .build();
+    assert!(result.is_err());
+
+    tracing::info!("test_builder_rejects_invalid_referer: Completed");
+}
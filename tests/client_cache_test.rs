@@ -0,0 +1,210 @@
+//! Tests for the metadata response cache.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, FeatureServiceClient, LayerId, MapServiceClient, NoAuth,
+    ResponseCache,
+};
+use std::time::Duration;
+
+const SERVICE_DEFINITION: &str = r#"{"layers": [{"id": 0, "name": "Cities", "geometryType": "esriGeometryPoint"}], "tables": []}"#;
+
+#[tokio::test]
+async fn test_fresh_definition_served_from_cache() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_fresh_definition_served_from_cache: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let definition = server
+        .mock("GET", "/FeatureServer")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(SERVICE_DEFINITION)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_cache(ResponseCache::memory());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let first = service.get_definition().await?;
+    let second = service.get_definition().await?;
+    assert_eq!(first.layers().len(), second.layers().len());
+    assert_eq!(second.layers().len(), 1);
+
+    definition.assert_async().await;
+
+    tracing::info!("test_fresh_definition_served_from_cache: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stale_entry_revalidated_with_etag() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_stale_entry_revalidated_with_etag: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let initial = server
+        .mock("GET", "/MapServer")
+        .match_query(mockito::Matcher::Any)
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(r#"{"layers": [{"id": 0, "name": "Parcels"}]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let not_modified = server
+        .mock("GET", "/MapServer")
+        .match_query(mockito::Matcher::Any)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let cache = ResponseCache::memory().with_ttl(Duration::ZERO);
+    let client = ArcGISClient::builder().cache(cache).build()?;
+    let service = MapServiceClient::new(format!("{}/MapServer", server.url()), &client);
+
+    for _ in 0..3 {
+        let metadata = service.get_metadata().await?;
+        assert_eq!(metadata.layers().len(), 1);
+    }
+
+    initial.assert_async().await;
+    not_modified.assert_async().await;
+
+    tracing::info!("test_stale_entry_revalidated_with_etag: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_disk_cache_survives_new_client() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_disk_cache_survives_new_client: Starting");
+
+    let dir = std::env::temp_dir().join(format!("arcgis-cache-{}", uuid::Uuid::new_v4()));
+
+    let mut server = mockito::Server::new_async().await;
+    let layer = server
+        .mock("GET", "/FeatureServer/0")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": 0, "name": "Cities", "type": "Feature Layer", "geometryType": "esriGeometryPoint", "fields": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let url = format!("{}/FeatureServer", server.url());
+
+    for _ in 0..2 {
+        let client = ArcGISClient::new(NoAuth).with_cache(ResponseCache::disk(&dir));
+        let service = FeatureServiceClient::new(&url, &client);
+        let definition = service.get_layer_definition(LayerId::new(0)).await?;
+        assert_eq!(definition.name(), "Cities");
+    }
+
+    layer.assert_async().await;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let dir_mode = std::fs::metadata(&dir)?.permissions().mode();
+        assert_eq!(dir_mode & 0o777, 0o700);
+        for entry in std::fs::read_dir(&dir)? {
+            let mode = entry?.metadata()?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    ResponseCache::disk(&dir).clear().await?;
+    std::fs::remove_dir_all(&dir)?;
+
+    tracing::info!("test_disk_cache_survives_new_client: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_errors_are_not_cached() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_errors_are_not_cached: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let error = server
+        .mock("GET", "/FeatureServer")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": {"code": 500, "message": "Service not started"}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth)
+        .with_retry_policy(arcgis::RetryPolicy::none())
+        .with_cache(ResponseCache::memory());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    assert!(service.get_definition().await.is_err());
+    assert!(service.get_definition().await.is_err());
+
+    error.assert_async().await;
+
+    tracing::info!("test_errors_are_not_cached: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shared_cache_keeps_identities_apart() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_shared_cache_keeps_identities_apart: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let alice = server
+        .mock("GET", "/FeatureServer")
+        .match_query(mockito::Matcher::UrlEncoded("token".into(), "alice".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(SERVICE_DEFINITION)
+        .expect(1)
+        .create_async()
+        .await;
+    let bob = server
+        .mock("GET", "/FeatureServer")
+        .match_query(mockito::Matcher::UrlEncoded("token".into(), "bob".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"layers": [], "tables": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let cache = ResponseCache::memory();
+    let url = format!("{}/FeatureServer", server.url());
+    let alice_client = ArcGISClient::new(ApiKeyAuth::new("alice")).with_cache(cache.clone());
+    let bob_client = ArcGISClient::new(ApiKeyAuth::new("bob")).with_cache(cache);
+
+    for _ in 0..2 {
+        let definition = FeatureServiceClient::new(&url, &alice_client)
+            .get_definition()
+            .await?;
+        assert_eq!(definition.layers().len(), 1);
+
+        let definition = FeatureServiceClient::new(&url, &bob_client)
+            .get_definition()
+            .await?;
+        assert!(definition.layers().is_empty());
+    }
+
+    alice.assert_async().await;
+    bob.assert_async().await;
+
+    tracing::info!("test_shared_cache_keeps_identities_apart: Completed");
+    Ok(())
+}
@@ -0,0 +1,189 @@
+//! Tests for record/replay cassettes.
+//!
+//! Recording tests run against a local mock server; replay tests run offline
+//! from cassettes in `tests/cassettes`. No credentials are required.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, CassetteTransport, FeatureServiceClient, GeocodeServiceClient,
+    LayerId, NoAuth, PortalClient, SearchParameters,
+};
+use std::path::PathBuf;
+
+const WORLD_CITIES: &str =
+    "https://services.arcgis.com/P3ePLMYs2RVChkJx/arcgis/rest/services/World_Cities/FeatureServer";
+const WORLD_GEOCODER: &str =
+    "https://geocode-api.arcgis.com/arcgis/rest/services/World/GeocodeServer";
+const PORTAL: &str = "https://www.arcgis.com/sharing/rest";
+
+/// Returns a unique cassette path in the system temp directory.
+fn temp_cassette(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "arcgis-cassette-{}-{}-{}.json",
+        name,
+        std::process::id(),
+        uuid::Uuid::new_v4()
+    ))
+}
+
+#[tokio::test]
+async fn test_record_then_replay_offline() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_record_then_replay_offline: Starting");
+
+    let path = temp_cassette("record-replay");
+
+    {
+        let mut server = mockito::Server::new_async().await;
+        let query = server
+            .mock("GET", "/FeatureServer/0/query")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"features": [{"attributes": {"NAME": "Lagos"}}]}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let transport = CassetteTransport::record(&path, arcgis::reqwest::Client::new());
+        let client = ArcGISClient::builder()
+            .auth(ApiKeyAuth::new("secret-api-key"))
+            .transport(transport)
+            .build()?;
+        let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+        service
+            .query(LayerId::new(0))
+            .where_clause("NAME = 'Lagos'")
+            .execute()
+            .await?;
+
+        query.assert_async().await;
+    }
+
+    let contents = std::fs::read_to_string(&path)?;
+    assert!(
+        !contents.contains("secret-api-key"),
+        "Token must be scrubbed"
+    );
+    assert!(contents.contains("NAME = 'Lagos'"));
+
+    // The mock server is gone and no token is configured; replay still matches.
+    let client = ArcGISClient::builder()
+        .auth(NoAuth)
+        .transport(CassetteTransport::replay(&path)?)
+        .build()?;
+    let service = FeatureServiceClient::new("https://replayed.example.com/FeatureServer", &client);
+    let features = service
+        .query(LayerId::new(0))
+        .where_clause("NAME = 'Lagos'")
+        .execute()
+        .await?;
+    assert_eq!(features.features().len(), 1);
+
+    std::fs::remove_file(&path)?;
+
+    tracing::info!("test_record_then_replay_offline: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_scrubs_tokens_in_responses() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_record_scrubs_tokens_in_responses: Starting");
+
+    let path = temp_cassette("scrub");
+
+    let mut server = mockito::Server::new_async().await;
+    let _generate = server
+        .mock("POST", "/sharing/rest/generateToken")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"token": "live-session-token", "expires": 1700000000000, "ssl": true}"#)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::builder()
+        .transport(CassetteTransport::record(
+            &path,
+            arcgis::reqwest::Client::new(),
+        ))
+        .build()?;
+    let request =
+        arcgis::ServiceRequest::post(format!("{}/sharing/rest/generateToken", server.url())).form(
+            &[
+                ("f", "json"),
+                ("username", "analyst"),
+                ("password", "hunter2"),
+            ],
+        );
+    let body: serde_json::Value = client.execute_json(request).await?;
+    assert_eq!(body["token"], "live-session-token");
+
+    let contents = std::fs::read_to_string(&path)?;
+    assert!(!contents.contains("live-session-token"));
+    assert!(!contents.contains("hunter2"));
+    assert!(contents.contains("[REDACTED]"));
+    assert!(contents.contains("analyst"));
+
+    std::fs::remove_file(&path)?;
+
+    tracing::info!("test_record_scrubs_tokens_in_responses: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replay_unmatched_request_fails() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_replay_unmatched_request_fails: Starting");
+
+    let path = temp_cassette("empty");
+    std::fs::write(&path, r#"{"interactions": []}"#)?;
+
+    let client = ArcGISClient::builder()
+        .transport(CassetteTransport::replay(&path)?)
+        .build()?;
+    let service = FeatureServiceClient::new(WORLD_CITIES, &client);
+    let result = service.query(LayerId::new(0)).execute().await;
+    assert!(result.is_err(), "Request missing from cassette should fail");
+
+    std::fs::remove_file(&path)?;
+
+    tracing::info!("test_replay_unmatched_request_fails: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_public_services_cassette() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_public_services_cassette: Starting");
+
+    let client = ArcGISClient::builder()
+        .auth(NoAuth)
+        .transport(common::cassette("public_services")?)
+        .build()?;
+
+    let cities = FeatureServiceClient::new(WORLD_CITIES, &client)
+        .query(LayerId::new(0))
+        .where_clause("POP > 10000000")
+        .out_fields(&["CITY_NAME", "POP"])
+        .return_geometry(false)
+        .execute()
+        .await?;
+    assert!(!cities.features().is_empty());
+
+    let candidates = GeocodeServiceClient::new(WORLD_GEOCODER, &client)
+        .find_address_candidates("380 New York St, Redlands, CA 92373")
+        .await?;
+    assert!(!candidates.candidates().is_empty());
+
+    let items = PortalClient::new(PORTAL, &client)
+        .search(
+            SearchParameters::new("title:\"World Cities\" AND owner:esri").with_pagination(1, 2),
+        )
+        .await?;
+    assert!(*items.total() > 0);
+
+    tracing::info!("test_public_services_cassette: Completed");
+    Ok(())
+}
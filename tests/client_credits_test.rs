@@ -0,0 +1,260 @@
+//! Tests for credit estimates and budgets.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, BatchGeocodeRecord, CreditMeter, ElevationClient, ErrorKind,
+    GeocodeServiceClient, NoAuth, RetryPolicy, ServiceKind, ServiceRequest,
+    ViewshedParametersBuilder,
+};
+use std::time::Duration;
+
+const EMPTY_BATCH: &str = r#"{"spatialReference": {"wkid": 4326}, "locations": []}"#;
+
+#[test]
+fn test_estimates_use_rates() {
+    common::init_tracing();
+    tracing::info!("test_estimates_use_rates: Starting");
+
+    let meter = CreditMeter::new()
+        .with_rate(ServiceKind::Routing, 0.005)
+        .with_operation_rate(ServiceKind::Routing, "solveServiceArea", 0.01);
+
+    assert!((meter.estimate(ServiceKind::Geocode, "geocodeAddresses", 250) - 1.0).abs() < 1e-9);
+    assert!((meter.estimate(ServiceKind::Routing, "solve", 2) - 0.01).abs() < 1e-9);
+    assert!((meter.estimate(ServiceKind::Routing, "solveServiceArea", 1) - 0.01).abs() < 1e-9);
+    assert_eq!(meter.estimate(ServiceKind::Feature, "query", 1), 0.0);
+    assert_eq!(meter.budget(), None);
+    assert_eq!(meter.remaining(), None);
+
+    tracing::info!("test_estimates_use_rates: Completed");
+}
+
+#[tokio::test]
+async fn test_batch_geocode_charged_per_record() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_batch_geocode_charged_per_record: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/GeocodeServer/geocodeAddresses")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_BATCH)
+        .create_async()
+        .await;
+
+    let meter = CreditMeter::new().with_budget(1.0);
+    let client = ArcGISClient::new(NoAuth).with_credit_meter(meter.clone());
+    let geocoder = GeocodeServiceClient::new(format!("{}/GeocodeServer", server.url()), &client);
+
+    let records = (1..=3)
+        .map(|id| BatchGeocodeRecord::with_single_line(id, "380 New York St, Redlands, CA"))
+        .collect();
+    geocoder.geocode_addresses(records).await?;
+
+    mock.assert_async().await;
+    let usage = meter.usage(ServiceKind::Geocode);
+    assert_eq!(*usage.requests(), 1);
+    assert_eq!(*usage.units(), 3);
+    assert!((meter.spent() - 0.012).abs() < 1e-9);
+    assert!((meter.remaining().unwrap_or_default() - 0.988).abs() < 1e-9);
+
+    tracing::info!("test_batch_geocode_charged_per_record: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exhausted_budget_fails_without_sending() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_exhausted_budget_fails_without_sending: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let solve = server
+        .mock("POST", "/NAServer/Route/solve")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"messages": []}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let meter = CreditMeter::new()
+        .with_rate(ServiceKind::Routing, 0.5)
+        .with_budget(1.0);
+    let client = ArcGISClient::builder()
+        .credit_meter(meter.clone())
+        .build()?;
+    let url = format!("{}/NAServer/Route/solve", server.url());
+
+    for _ in 0..2 {
+        let request = ServiceRequest::post(&url)
+            .form(&[("f", "json")])
+            .billable(1);
+        client.execute_text(request).await?;
+    }
+
+    let request = ServiceRequest::post(&url)
+        .form(&[("f", "json")])
+        .billable(1);
+    let err = client
+        .execute_text(request)
+        .await
+        .expect_err("Budget should be exhausted");
+    match err.kind() {
+        ErrorKind::CreditBudgetExceeded {
+            budget,
+            spent,
+            estimate,
+        } => {
+            assert_eq!(*budget, 1.0);
+            assert!((spent - 1.0).abs() < 1e-9);
+            assert_eq!(*estimate, 0.5);
+        }
+        other => panic!("Expected CreditBudgetExceeded, got {other:?}"),
+    }
+
+    // Requests that are not billable still go through
+    let free = server
+        .mock("GET", "/NAServer/Route")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+    let request =
+        ServiceRequest::get(format!("{}/NAServer/Route", server.url())).query(&[("f", "json")]);
+    client.execute_text(request).await?;
+
+    solve.assert_async().await;
+    free.assert_async().await;
+    assert_eq!(*meter.usage(ServiceKind::Routing).requests(), 2);
+    assert_eq!(meter.remaining(), Some(0.0));
+
+    tracing::info!("test_exhausted_budget_fails_without_sending: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_elevation_jobs_are_billable() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_elevation_jobs_are_billable: Starting");
+
+    // A zero budget rejects billable requests before they are sent
+    let meter = CreditMeter::new().with_budget(0.0);
+    let client = ArcGISClient::builder()
+        .credit_meter(meter.clone())
+        .build()?;
+    let elevation = ElevationClient::new(&client);
+
+    let params = ViewshedParametersBuilder::default()
+        .input_points(r#"{"geometryType": "esriGeometryPoint", "features": []}"#)
+        .build()?;
+    let err = elevation
+        .submit_viewshed(params)
+        .await
+        .expect_err("Viewshed job should be charged");
+    assert!(
+        matches!(err.kind(), ErrorKind::CreditBudgetExceeded { .. }),
+        "{err}"
+    );
+    assert_eq!(meter.spent(), 0.0);
+
+    tracing::info!("test_elevation_jobs_are_billable: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_request_is_refunded() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_failed_request_is_refunded: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/NAServer/Route/solve")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": {"code": 400, "message": "Unable to complete operation.", "details": ["Stops parameter is invalid"]}}"#)
+        .create_async()
+        .await;
+
+    let meter = CreditMeter::new();
+    let client = ArcGISClient::new(NoAuth)
+        .with_retry_policy(RetryPolicy::none())
+        .with_credit_meter(meter.clone());
+
+    let request = ServiceRequest::post(format!("{}/NAServer/Route/solve", server.url()))
+        .form(&[("f", "json")])
+        .billable(1);
+    assert!(client.execute_text(request).await.is_err());
+
+    mock.assert_async().await;
+    assert_eq!(*meter.usage(ServiceKind::Routing).requests(), 0);
+    assert_eq!(meter.spent(), 0.0);
+
+    tracing::info!("test_failed_request_is_refunded: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unreachable_server_is_refunded() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_unreachable_server_is_refunded: Starting");
+
+    let meter = CreditMeter::new();
+    let client = ArcGISClient::new(NoAuth)
+        .with_retry_policy(RetryPolicy::none())
+        .with_credit_meter(meter.clone());
+
+    // Nothing listens on port 1, so the connection is refused
+    let request = ServiceRequest::post("http://127.0.0.1:1/NAServer/Route/solve")
+        .form(&[("f", "json")])
+        .billable(1);
+    assert!(client.execute_text(request).await.is_err());
+
+    assert_eq!(*meter.usage(ServiceKind::Routing).requests(), 0);
+    assert_eq!(meter.spent(), 0.0);
+
+    tracing::info!("test_unreachable_server_is_refunded: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_timed_out_request_stays_charged() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_timed_out_request_stays_charged: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/NAServer/Route/solve")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(Duration::from_millis(500));
+            writer.write_all(br#"{"messages": []}"#)
+        })
+        .create_async()
+        .await;
+
+    let meter = CreditMeter::new();
+    let client = ArcGISClient::builder()
+        .timeout(Duration::from_millis(100))
+        .retry_policy(RetryPolicy::none())
+        .credit_meter(meter.clone())
+        .build()?;
+
+    let request = ServiceRequest::post(format!("{}/NAServer/Route/solve", server.url()))
+        .form(&[("f", "json")])
+        .billable(1);
+    assert!(client.execute_text(request).await.is_err());
+
+    // The server may have solved the route before the client gave up
+    mock.assert_async().await;
+    assert_eq!(*meter.usage(ServiceKind::Routing).requests(), 1);
+
+    tracing::info!("test_timed_out_request_stays_charged: Completed");
+    Ok(())
+}
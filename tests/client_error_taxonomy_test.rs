@@ -0,0 +1,175 @@
+//! Tests for classification of ESRI errors into typed error variants.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, EditOperation, EditOptions, ErrorKind, FeatureServiceClient, LayerId, NoAuth,
+    ObjectId, RetryPolicy,
+};
+
+/// Queries layer 0 of a mock service that returns `status` and `body`.
+async fn query_error(status: usize, body: &str) -> anyhow::Result<arcgis::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(RetryPolicy::none());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let err = service
+        .query(LayerId::new(0))
+        .execute()
+        .await
+        .expect_err("Mock error should surface");
+    Ok(err)
+}
+
+#[tokio::test]
+async fn test_body_errors_are_classified() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_body_errors_are_classified: Starting");
+
+    let err = query_error(
+        200,
+        r#"{"error": {"code": 498, "message": "Invalid token.", "details": []}}"#,
+    )
+    .await?;
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::InvalidToken { code: 498, .. }
+    ));
+
+    let err = query_error(
+        200,
+        r#"{"error": {"code": 403, "message": "You do not have permissions to access this resource or perform this operation.", "details": []}}"#,
+    )
+    .await?;
+    assert!(matches!(err.kind(), ErrorKind::PermissionDenied { .. }));
+
+    let err = query_error(
+        200,
+        r#"{"error": {"code": 404, "message": "Service not found.", "details": []}}"#,
+    )
+    .await?;
+    assert!(matches!(err.kind(), ErrorKind::NotFound { .. }));
+    assert_eq!(err.kind().code(), Some(404));
+
+    let err = query_error(
+        200,
+        r#"{"error": {"code": 500, "message": "Requested operation exceeds the transfer limit.", "details": []}}"#,
+    )
+    .await?;
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::TransferLimitExceeded { code: 500, .. }
+    ));
+
+    tracing::info!("test_body_errors_are_classified: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_parameter_preserves_details() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_invalid_parameter_preserves_details: Starting");
+
+    let err = query_error(
+        200,
+        r#"{"error": {"code": "400", "message": "Unable to complete operation.",
+                      "details": ["'where' parameter is invalid"]}}"#,
+    )
+    .await?;
+
+    match err.kind() {
+        ErrorKind::InvalidParameter { message, details } => {
+            assert_eq!(message, "Unable to complete operation.");
+            assert_eq!(details, &vec!["'where' parameter is invalid".to_string()]);
+        }
+        other => panic!("Expected InvalidParameter, got {:?}", other),
+    }
+    assert!(err.to_string().contains("'where' parameter is invalid"));
+
+    tracing::info!("test_invalid_parameter_preserves_details: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_status_errors_are_classified() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_http_status_errors_are_classified: Starting");
+
+    let err = query_error(403, "Forbidden").await?;
+    assert!(matches!(err.kind(), ErrorKind::PermissionDenied { .. }));
+
+    let err = query_error(404, "Not Found").await?;
+    assert!(matches!(err.kind(), ErrorKind::NotFound { .. }));
+
+    let err = query_error(500, "Internal Server Error").await?;
+    assert!(matches!(err.kind(), ErrorKind::Api { code: 500, .. }));
+
+    tracing::info!("test_http_status_errors_are_classified: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_edit_failures_become_error() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_edit_failures_become_error: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let _apply = server
+        .mock("POST", "/FeatureServer/0/applyEdits")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"addResults": [],
+                "updateResults": [],
+                "deleteResults": [
+                    {"objectId": 1, "success": true},
+                    {"objectId": 2, "success": false,
+                     "error": {"code": 1019, "description": "Delete for the object was not attempted. Object may not exist."}}
+                ]}"#,
+        )
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service
+        .apply_edits(
+            LayerId::new(0),
+            None,
+            None,
+            Some(vec![ObjectId::new(1), ObjectId::new(2)]),
+            EditOptions::default(),
+        )
+        .await?;
+    assert_eq!(result.failure_count(), 1);
+
+    let err = result
+        .error_for_failures()
+        .expect_err("Rejected delete should fail");
+    match err.kind() {
+        ErrorKind::EditFailed(failures) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(*failures[0].operation(), EditOperation::Delete);
+            assert_eq!(*failures[0].object_id(), Some(ObjectId::new(2)));
+            let error = failures[0].error().as_ref().expect("Item error present");
+            assert_eq!(*error.code(), 1019);
+        }
+        other => panic!("Expected EditFailed, got {:?}", other),
+    }
+    assert!(err.to_string().contains("ObjectID 2"));
+
+    tracing::info!("test_edit_failures_become_error: Completed");
+    Ok(())
+}
@@ -0,0 +1,242 @@
+//! Tests for the unified request executor and middleware hooks.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, ArcGISGeometry, ArcGISPoint, EditOptions, ErrorKind,
+    FeatureQueryParams, FeatureServiceClient, ImageServiceClient, LayerId, Middleware,
+    RequestContext, RetryPolicy, ServiceRequest,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const ESRI_ERROR: &str =
+    r#"{"error": {"code": 400, "message": "Unable to complete operation.", "details": []}}"#;
+
+/// Injects a header and counts responses and errors.
+#[derive(Clone, Default)]
+struct Recorder {
+    responses: Arc<AtomicUsize>,
+    errors: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn on_request(
+        &self,
+        _context: &RequestContext,
+        request: &mut arcgis::reqwest::Request,
+    ) -> arcgis::Result<()> {
+        request.headers_mut().insert(
+            "x-request-source",
+            "executor-test".parse().expect("Valid header"),
+        );
+        Ok(())
+    }
+
+    async fn on_response(&self, _context: &RequestContext, _response: &arcgis::reqwest::Response) {
+        self.responses.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn on_error(&self, _context: &RequestContext, _error: &arcgis::Error) {
+        self.errors.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn test_query_with_params_surfaces_esri_error() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_query_with_params_surfaces_esri_error: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(ESRI_ERROR)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::builder()
+        .retry_policy(RetryPolicy::none())
+        .build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let err = service
+        .query_with_params(LayerId::new(0), FeatureQueryParams::default())
+        .await
+        .expect_err("ESRI error body should fail the query");
+    assert!(matches!(err.kind(), ErrorKind::Api { code: 400, .. }));
+
+    query.assert_async().await;
+
+    tracing::info!("test_query_with_params_surfaces_esri_error: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_middleware_hooks_run() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_middleware_hooks_run: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let ok = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .match_header("x-request-source", "executor-test")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"features": [], "exceededTransferLimit": false}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let failed = server
+        .mock("GET", "/FeatureServer/1/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(ESRI_ERROR)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let recorder = Recorder::default();
+    let client = ArcGISClient::builder()
+        .retry_policy(RetryPolicy::none())
+        .middleware(recorder.clone())
+        .build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    service.query(LayerId::new(0)).execute().await?;
+    assert!(service.query(LayerId::new(1)).execute().await.is_err());
+
+    ok.assert_async().await;
+    failed.assert_async().await;
+    assert_eq!(recorder.responses.load(Ordering::SeqCst), 2);
+    assert_eq!(recorder.errors.load(Ordering::SeqCst), 1);
+
+    tracing::info!("test_middleware_hooks_run: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_attached_to_image_requests() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_token_attached_to_image_requests: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let identify = server
+        .mock("GET", "/ImageServer/identify")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "token".into(),
+            "test-api-key".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"value": "42"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key"));
+    let service = ImageServiceClient::new(format!("{}/ImageServer", server.url()), &client);
+
+    let geometry = ArcGISGeometry::Point(ArcGISPoint::new(-120.0, 40.0));
+    let result = service.identify(&geometry).await?;
+    assert_eq!(result.value().as_deref(), Some("42"));
+
+    identify.assert_async().await;
+
+    tracing::info!("test_token_attached_to_image_requests: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_attached_to_post_form() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_token_attached_to_post_form: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let edits = server
+        .mock("POST", "/FeatureServer/0/applyEdits")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "token".into(),
+            "test-api-key".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"addResults": [], "updateResults": [],
+                "deleteResults": [{"objectId": 7, "success": false,
+                                   "error": {"code": 1018, "description": "Delete failed"}}]}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key"));
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    // A per-item "error" is part of a successful payload, not a request failure.
+    let result = service
+        .apply_edits(
+            LayerId::new(0),
+            None,
+            None,
+            Some(vec![arcgis::ObjectId::new(7)]),
+            EditOptions::default(),
+        )
+        .await?;
+    assert_eq!(result.failure_count(), 1);
+
+    edits.assert_async().await;
+
+    tracing::info!("test_token_attached_to_post_form: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_reports_html_error_page() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_execute_reports_html_error_page: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let page = server
+        .mock("GET", "/MapServer/legend")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "text/html")
+        .with_body("<html><body><h1>Operation not supported</h1></body></html>")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::builder()
+        .retry_policy(RetryPolicy::none())
+        .build()?;
+    let request =
+        ServiceRequest::get(format!("{}/MapServer/legend", server.url())).query(&[("f", "json")]);
+
+    let err = client
+        .execute_json::<serde_json::Value>(request)
+        .await
+        .expect_err("HTML page should not parse as JSON");
+    match err.kind() {
+        ErrorKind::Api { code, message } => {
+            assert_eq!(*code, 400);
+            assert!(message.contains("legend operation not supported"));
+            assert!(message.contains("Operation not supported"));
+        }
+        other => panic!("Unexpected error kind: {:?}", other),
+    }
+
+    page.assert_async().await;
+
+    tracing::info!("test_execute_reports_html_error_page: Completed");
+    Ok(())
+}
@@ -0,0 +1,187 @@
+//! Tests for per-request metrics.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, FeatureServiceClient, LayerId, MemoryMetricsRecorder, NoAuth, RequestMetrics,
+    RetryPolicy, ServiceKind, ServiceRequest,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+#[tokio::test]
+async fn test_records_successful_request() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_records_successful_request: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .create_async()
+        .await;
+
+    let recorder = MemoryMetricsRecorder::new();
+    let client = ArcGISClient::new(NoAuth).with_metrics(recorder.clone());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+    service.query(LayerId::new(0)).execute().await?;
+
+    mock.assert_async().await;
+    let records = recorder.records();
+    assert_eq!(records.len(), 1);
+    let metrics = &records[0];
+    assert!(metrics.is_success());
+    assert_eq!(metrics.operation(), "query");
+    assert_eq!(*metrics.service_kind(), ServiceKind::Feature);
+    assert_eq!(*metrics.status(), Some(200));
+    assert_eq!(*metrics.esri_code(), None);
+    assert_eq!(
+        *metrics.response_bytes(),
+        Some(EMPTY_FEATURE_SET.len() as u64)
+    );
+    assert_eq!(*metrics.retries(), 0);
+    assert!(!metrics.url().contains('?'));
+
+    tracing::info!("test_records_successful_request: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_records_size_of_chunked_body() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_records_size_of_chunked_body: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    // Chunked responses carry no Content-Length
+    let mock = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| writer.write_all(EMPTY_FEATURE_SET.as_bytes()))
+        .create_async()
+        .await;
+
+    let recorder = MemoryMetricsRecorder::new();
+    let client = ArcGISClient::new(NoAuth).with_metrics(recorder.clone());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+    service.query(LayerId::new(0)).execute().await?;
+
+    mock.assert_async().await;
+    let records = recorder.records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        *records[0].response_bytes(),
+        Some(EMPTY_FEATURE_SET.len() as u64)
+    );
+
+    tracing::info!("test_records_size_of_chunked_body: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_records_esri_error_and_retries() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_records_esri_error_and_retries: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let esri_error = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": {"code": 400, "message": "Unable to complete operation.", "details": ["'where' parameter is invalid"]}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let recorder = MemoryMetricsRecorder::new();
+    let policy = RetryPolicy::builder()
+        .max_attempts(3_u32)
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+        .build()?;
+    let client = ArcGISClient::builder()
+        .retry_policy(policy)
+        .metrics(recorder.clone())
+        .build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service.query(LayerId::new(0)).execute().await;
+    assert!(result.is_err());
+
+    unavailable.assert_async().await;
+    esri_error.assert_async().await;
+    let records = recorder.records();
+    assert_eq!(records.len(), 1);
+    let metrics = &records[0];
+    assert!(!metrics.is_success());
+    assert_eq!(*metrics.status(), Some(200));
+    assert_eq!(*metrics.esri_code(), Some(400));
+    assert_eq!(*metrics.error_type(), Some("invalid_parameter"));
+    assert_eq!(*metrics.retries(), 1);
+
+    tracing::info!("test_records_esri_error_and_retries: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_records_request_bytes_with_closure_recorder() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_records_request_bytes_with_closure_recorder: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/GPServer/Buffer/execute")
+        .with_status(500)
+        .with_body("Internal error")
+        .create_async()
+        .await;
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let request_bytes = Arc::new(AtomicUsize::new(0));
+    let client = ArcGISClient::new(NoAuth)
+        .with_retry_policy(RetryPolicy::none())
+        .with_metrics({
+            let count = Arc::clone(&count);
+            let request_bytes = Arc::clone(&request_bytes);
+            move |metrics: &RequestMetrics| {
+                assert_eq!(*metrics.service_kind(), ServiceKind::Geoprocessing);
+                assert_eq!(*metrics.status(), Some(500));
+                assert_eq!(*metrics.error_type(), Some("api"));
+                count.fetch_add(1, Ordering::SeqCst);
+                request_bytes.store(*metrics.request_bytes() as usize, Ordering::SeqCst);
+            }
+        });
+
+    let form = [("f", "json"), ("distance", "100")];
+    let request =
+        ServiceRequest::post(format!("{}/GPServer/Buffer/execute", server.url())).form(&form);
+    let result = client.execute_text(request).await;
+    assert!(result.is_err());
+
+    mock.assert_async().await;
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        request_bytes.load(Ordering::SeqCst),
+        "f=json&distance=100".len()
+    );
+
+    tracing::info!("test_records_request_bytes_with_closure_recorder: Completed");
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//! Tests for owned, cloneable service clients.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, ElevationClient, ExportMapBuilder, FeatureServiceClient, GeocodeServiceClient,
+    GeometryServiceClient, GeoprocessingServiceClient, ImageServiceClient, LayerId,
+    MapServiceClient, NoAuth, PlacesClient, PortalClient, QueryBuilder, RoutingServiceClient,
+    VectorTileServiceClient, VersionManagementClient,
+};
+
+fn assert_owned<T: Clone + Send + Sync + 'static>() {}
+
+#[test]
+fn test_clients_are_owned_send_sync() {
+    common::init_tracing();
+    tracing::info!("test_clients_are_owned_send_sync: Starting");
+
+    assert_owned::<ArcGISClient>();
+    assert_owned::<FeatureServiceClient>();
+    assert_owned::<MapServiceClient>();
+    assert_owned::<GeocodeServiceClient>();
+    assert_owned::<GeometryServiceClient>();
+    assert_owned::<GeoprocessingServiceClient>();
+    assert_owned::<ImageServiceClient>();
+    assert_owned::<VectorTileServiceClient>();
+    assert_owned::<RoutingServiceClient>();
+    assert_owned::<ElevationClient>();
+    assert_owned::<PlacesClient>();
+    assert_owned::<PortalClient>();
+    assert_owned::<VersionManagementClient>();
+
+    fn assert_send_static<T: Send + 'static>() {}
+    assert_send_static::<QueryBuilder>();
+    assert_send_static::<ExportMapBuilder>();
+
+    tracing::info!("test_clients_are_owned_send_sync: Completed");
+}
+
+/// Builds a service client without keeping the `ArcGISClient` alive.
+fn cities_service(url: String) -> FeatureServiceClient {
+    let client = ArcGISClient::new(NoAuth);
+    FeatureServiceClient::new(url, &client)
+}
+
+#[tokio::test]
+async fn test_service_client_moves_into_spawned_tasks() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_service_client_moves_into_spawned_tasks: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"features": [{"attributes": {"OBJECTID": 1}}]}"#)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let service = cities_service(format!("{}/FeatureServer", server.url()));
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let service = service.clone();
+            tokio::spawn(async move { service.query(LayerId::new(0)).execute().await })
+        })
+        .collect();
+
+    for handle in handles {
+        let features = handle.await??;
+        assert_eq!(features.features().len(), 1);
+    }
+
+    query.assert_async().await;
+
+    tracing::info!("test_service_client_moves_into_spawned_tasks: Completed");
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Tests for switching long GET requests to form-encoded POST.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, FeatureQueryParams, FeatureServiceClient, LayerId, ObjectId,
+};
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+fn object_ids(count: u32) -> Vec<ObjectId> {
+    (1..=count).map(ObjectId::new).collect()
+}
+
+#[tokio::test]
+async fn test_short_query_uses_get() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_short_query_uses_get: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let get = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "objectIds".into(),
+            "1,2,3".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key"));
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let params = FeatureQueryParams::builder()
+        .object_ids(object_ids(3))
+        .build()?;
+    service.query_with_params(LayerId::new(0), params).await?;
+
+    get.assert_async().await;
+
+    tracing::info!("test_short_query_uses_get: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_long_query_switches_to_post() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_long_query_switches_to_post: Starting");
+
+    let ids = object_ids(1000);
+    let id_list = ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut server = mockito::Server::new_async().await;
+    let post = server
+        .mock("POST", "/FeatureServer/0/query")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("objectIds".into(), id_list),
+            mockito::Matcher::UrlEncoded("token".into(), "test-api-key".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key"));
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let params = FeatureQueryParams::builder().object_ids(ids).build()?;
+    service.query_with_params(LayerId::new(0), params).await?;
+
+    post.assert_async().await;
+
+    tracing::info!("test_long_query_switches_to_post: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_url_length_is_configurable() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_max_url_length_is_configurable: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let post = server
+        .mock("POST", "/FeatureServer/0/query")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "objectIds".into(),
+            "1,2,3".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::builder().max_url_length(32).build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let params = FeatureQueryParams::builder()
+        .object_ids(object_ids(3))
+        .build()?;
+    service.query_with_params(LayerId::new(0), params).await?;
+
+    post.assert_async().await;
+
+    tracing::info!("test_max_url_length_is_configurable: Completed");
+    Ok(())
+}
@@ -0,0 +1,192 @@
+//! Tests for client-side rate limiting.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, FeatureServiceClient, LayerId, NoAuth, RateLimit, RateLimiter, ServiceKind,
+};
+use std::time::{Duration, Instant};
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+#[test]
+fn test_service_kind_from_url() {
+    common::init_tracing();
+    tracing::info!("test_service_kind_from_url: Starting");
+
+    let cases = [
+        (
+            "https://geocode.arcgis.com/arcgis/rest/services/World/GeocodeServer/findAddressCandidates",
+            ServiceKind::Geocode,
+        ),
+        (
+            "https://route-api.arcgis.com/arcgis/rest/services/World/Route/NAServer/Route_World/solve",
+            ServiceKind::Routing,
+        ),
+        (
+            "https://services.arcgis.com/org/arcgis/rest/services/Cities/FeatureServer/0/query",
+            ServiceKind::Feature,
+        ),
+        (
+            "https://sampleserver6.arcgisonline.com/arcgis/rest/services/USA/MapServer/export",
+            ServiceKind::Map,
+        ),
+        (
+            "https://elevation.arcgis.com/arcgis/rest/services/Tools/Elevation/GPServer/Viewshed",
+            ServiceKind::Elevation,
+        ),
+        (
+            "https://places-api.arcgis.com/arcgis/rest/services/places-service/v1/categories",
+            ServiceKind::Places,
+        ),
+        (
+            "https://www.arcgis.com/sharing/rest/community/self",
+            ServiceKind::Portal,
+        ),
+        ("https://example.com/anything", ServiceKind::Other),
+        ("not a url", ServiceKind::Other),
+    ];
+
+    for (url, expected) in cases {
+        tracing::debug!(url = %url, expected = %expected, "Classifying URL");
+        assert_eq!(ServiceKind::from_url_str(url), expected, "URL: {}", url);
+    }
+
+    tracing::info!("test_service_kind_from_url: Completed");
+}
+
+#[test]
+fn test_rate_limit_configuration() {
+    common::init_tracing();
+    tracing::info!("test_rate_limit_configuration: Starting");
+
+    let limit = RateLimit::per_second(2.5);
+    assert_eq!(*limit.burst(), 3);
+    assert_eq!(*limit.max_in_flight(), None);
+
+    let limit = RateLimit::per_minute(120.0)
+        .with_burst(5)
+        .with_max_in_flight(2);
+    assert!((limit.requests_per_second() - 2.0).abs() < f64::EPSILON);
+    assert_eq!(*limit.burst(), 5);
+    assert_eq!(*limit.max_in_flight(), Some(2));
+
+    assert!(RateLimiter::new().is_unlimited());
+    assert!(!RateLimiter::new().with_max_in_flight(4).is_unlimited());
+
+    tracing::info!("test_rate_limit_configuration: Completed");
+}
+
+#[tokio::test]
+async fn test_service_limit_paces_requests() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_service_limit_paces_requests: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(4)
+        .create_async()
+        .await;
+
+    // 20 requests/second with no burst: 4 requests need at least 3 refill intervals.
+    let limiter = RateLimiter::new().with_service_limit(
+        ServiceKind::Feature,
+        RateLimit::per_second(20.0).with_burst(1),
+    );
+    let client = ArcGISClient::new(NoAuth).with_rate_limiter(limiter);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let started = Instant::now();
+    for _ in 0..4 {
+        service.query(LayerId::new(0)).execute().await?;
+    }
+    let elapsed = started.elapsed();
+    tracing::info!(
+        elapsed_ms = elapsed.as_millis() as u64,
+        "Requests completed"
+    );
+    assert!(elapsed >= Duration::from_millis(140));
+
+    query.assert_async().await;
+
+    tracing::info!("test_service_limit_paces_requests: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unmatched_limits_do_not_delay() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_unmatched_limits_do_not_delay: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(3)
+        .create_async()
+        .await;
+
+    // Geocode limit must not slow down feature queries.
+    let limiter = RateLimiter::new().with_service_limit(
+        ServiceKind::Geocode,
+        RateLimit::per_minute(1.0).with_burst(1),
+    );
+    let client = ArcGISClient::new(NoAuth).with_rate_limiter(limiter);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        service.query(LayerId::new(0)).execute().await?;
+    }
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    query.assert_async().await;
+
+    tracing::info!("test_unmatched_limits_do_not_delay: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_host_limit_ignores_case() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_host_limit_ignores_case: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let limiter =
+        RateLimiter::new().with_host_limit("LocalHost", RateLimit::per_second(20.0).with_burst(1));
+    let client = ArcGISClient::new(NoAuth).with_rate_limiter(limiter);
+    let port = server.socket_address().port();
+    let service =
+        FeatureServiceClient::new(format!("http://localhost:{}/FeatureServer", port), &client);
+
+    let started = Instant::now();
+    for _ in 0..4 {
+        service.query(LayerId::new(0)).execute().await?;
+    }
+    assert!(started.elapsed() >= Duration::from_millis(140));
+
+    query.assert_async().await;
+
+    tracing::info!("test_host_limit_ignores_case: Completed");
+    Ok(())
+}
@@ -0,0 +1,304 @@
+//! Tests for the client retry policy.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, EditOptions, ErrorKind, FeatureServiceClient, LayerId, NoAuth, ObjectId,
+    RetryPolicy, ServiceRequest,
+};
+use std::time::Duration;
+
+/// Retry policy with negligible delays for fast tests.
+fn fast_policy(max_attempts: u32) -> anyhow::Result<RetryPolicy> {
+    Ok(RetryPolicy::builder()
+        .max_attempts(max_attempts)
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+        .build()?)
+}
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+#[test]
+fn test_retry_policy_defaults() {
+    common::init_tracing();
+    tracing::info!("test_retry_policy_defaults: Starting");
+
+    let policy = RetryPolicy::default();
+    assert_eq!(*policy.max_attempts(), 3);
+    assert!(*policy.respect_retry_after());
+    assert!(!*policy.retry_non_idempotent());
+
+    let none = RetryPolicy::none();
+    assert_eq!(*none.max_attempts(), 1);
+
+    tracing::info!("test_retry_policy_defaults: Completed");
+}
+
+#[tokio::test]
+async fn test_retries_transient_http_status() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_retries_transient_http_status: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let success = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(fast_policy(3)?);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service.query(LayerId::new(0)).execute().await?;
+    assert!(result.features().is_empty());
+
+    unavailable.assert_async().await;
+    success.assert_async().await;
+
+    tracing::info!("test_retries_transient_http_status: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retries_transient_esri_body_error() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_retries_transient_esri_body_error: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let esri_error = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "text/plain; charset=utf-8")
+        .with_body(r#"{"error": {"code": 500, "message": "Unable to complete operation.", "details": []}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let success = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(fast_policy(3)?);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service.query(LayerId::new(0)).execute().await?;
+    assert!(result.features().is_empty());
+
+    esri_error.assert_async().await;
+    success.assert_async().await;
+
+    tracing::info!("test_retries_transient_esri_body_error: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_gives_up_after_max_attempts: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(502)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(fast_policy(2)?);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service.query(LayerId::new(0)).execute().await;
+    let err = result.expect_err("Query should fail after retries are exhausted");
+    assert!(matches!(err.kind(), ErrorKind::Api { code: 502, .. }));
+
+    unavailable.assert_async().await;
+
+    tracing::info!("test_gives_up_after_max_attempts: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_does_not_retry_apply_edits_by_default() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_does_not_retry_apply_edits_by_default: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("POST", "/FeatureServer/0/applyEdits")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(fast_policy(3)?);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service
+        .apply_edits(
+            LayerId::new(0),
+            None,
+            None,
+            Some(vec![ObjectId::new(1)]),
+            EditOptions::default(),
+        )
+        .await;
+    assert!(result.is_err());
+
+    unavailable.assert_async().await;
+
+    tracing::info!("test_does_not_retry_apply_edits_by_default: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retries_apply_edits_when_opted_in() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_retries_apply_edits_when_opted_in: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("POST", "/FeatureServer/0/applyEdits")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let success = server
+        .mock("POST", "/FeatureServer/0/applyEdits")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"deleteResults": [{"objectId": 1, "success": true}]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let policy = RetryPolicy::builder()
+        .max_attempts(3u32)
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+        .retry_non_idempotent(true)
+        .build()?;
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(policy);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let result = service
+        .apply_edits(
+            LayerId::new(0),
+            None,
+            None,
+            Some(vec![ObjectId::new(1)]),
+            EditOptions::default(),
+        )
+        .await?;
+    assert!(result.all_succeeded());
+
+    unavailable.assert_async().await;
+    success.assert_async().await;
+
+    tracing::info!("test_retries_apply_edits_when_opted_in: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_honors_retry_after_header() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_honors_retry_after_header: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let throttled = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(429)
+        .with_header("retry-after", "1")
+        .expect(1)
+        .create_async()
+        .await;
+    let success = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(fast_policy(2)?);
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let started = std::time::Instant::now();
+    service.query(LayerId::new(0)).execute().await?;
+    assert!(started.elapsed() >= Duration::from_secs(1));
+
+    throttled.assert_async().await;
+    success.assert_async().await;
+
+    tracing::info!("test_honors_retry_after_header: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retries_unlisted_post_only_when_marked_idempotent() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_retries_unlisted_post_only_when_marked_idempotent: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("POST", "/GPServer/Tool/execute")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let success = server
+        .mock("POST", "/GPServer/Tool/execute")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"results": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(fast_policy(3)?);
+    let url = format!("{}/GPServer/Tool/execute", server.url());
+
+    // Operations missing from the read-only list are not replayed
+    let result = client
+        .execute_json::<serde_json::Value>(ServiceRequest::post(&url).form(&[("f", "json")]))
+        .await;
+    assert!(result.is_err());
+
+    let result: serde_json::Value = client
+        .execute_json(
+            ServiceRequest::post(&url)
+                .form(&[("f", "json")])
+                .idempotent(),
+        )
+        .await?;
+    assert!(result["results"].is_array());
+
+    unavailable.assert_async().await;
+    success.assert_async().await;
+
+    tracing::info!("test_retries_unlisted_post_only_when_marked_idempotent: Completed");
+    Ok(())
+}
@@ -0,0 +1,237 @@
+//! Tests for header token placement and credential redaction.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::reqwest::Request;
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, EditOptions, FeatureServiceClient, LayerId, Middleware, ObjectId,
+    RequestContext, RetryPolicy, ServiceRequest, TokenPlacement, redact_tokens,
+};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+/// A request as seen by middleware.
+#[derive(Clone)]
+struct Recorded {
+    url: String,
+    authorization: Option<String>,
+    body: String,
+}
+
+/// Records the URL, authorization header, and body of each request.
+#[derive(Clone, Default)]
+struct Recorder {
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl Recorder {
+    fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().expect("Lock not poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn on_request(
+        &self,
+        _context: &RequestContext,
+        request: &mut Request,
+    ) -> arcgis::Result<()> {
+        let authorization = request
+            .headers()
+            .get("x-esri-authorization")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+            .unwrap_or_default();
+        self.requests
+            .lock()
+            .expect("Lock not poisoned")
+            .push(Recorded {
+                url: request.url().to_string(),
+                authorization,
+                body,
+            });
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_header_placement_keeps_token_out_of_url() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_header_placement_keeps_token_out_of_url: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .match_header("x-esri-authorization", "Bearer test-api-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let recorder = Recorder::default();
+    let client = ArcGISClient::builder()
+        .auth(ApiKeyAuth::new("test-api-key"))
+        .token_placement(TokenPlacement::Header)
+        .middleware(recorder.clone())
+        .build()?;
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    service.query(LayerId::new(0)).execute().await?;
+
+    query.assert_async().await;
+    let requests = recorder.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(!requests[0].url.contains("token="));
+    assert_eq!(
+        requests[0].authorization.as_deref(),
+        Some("Bearer test-api-key")
+    );
+
+    tracing::info!("test_header_placement_keeps_token_out_of_url: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_header_placement_keeps_token_out_of_form() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_header_placement_keeps_token_out_of_form: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let apply = server
+        .mock("POST", "/FeatureServer/0/applyEdits")
+        .match_header("x-esri-authorization", "Bearer test-api-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"addResults": [], "updateResults": [], "deleteResults": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let recorder = Recorder::default();
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key"))
+        .with_token_placement(TokenPlacement::Header)
+        .with_middleware(recorder.clone());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    service
+        .apply_edits(
+            LayerId::new(0),
+            None,
+            None,
+            Some(vec![ObjectId::new(1)]),
+            EditOptions::default(),
+        )
+        .await?;
+
+    apply.assert_async().await;
+    let requests = recorder.requests();
+    assert!(!requests[0].body.contains("token="));
+
+    tracing::info!("test_header_placement_keeps_token_out_of_form: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_in_query_overrides_header_placement() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_token_in_query_overrides_header_placement: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let info = server
+        .mock("GET", "/rest/info")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "token".into(),
+            "test-api-key".into(),
+        ))
+        .match_header("x-esri-authorization", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"currentVersion": 11.3}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key"))
+        .with_token_placement(TokenPlacement::Header);
+    let request = ServiceRequest::get(format!("{}/rest/info", server.url()))
+        .query(&[("f", "json")])
+        .token_in_query();
+    let _: serde_json::Value = client.execute_json(request).await?;
+
+    info.assert_async().await;
+
+    tracing::info!("test_token_in_query_overrides_header_placement: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_errors_redact_tokens() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_errors_redact_tokens: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let _failure = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(500)
+        .with_body("Upstream failed for /query?where=1%3D1&token=secret-api-key")
+        .create_async()
+        .await;
+
+    let client =
+        ArcGISClient::new(ApiKeyAuth::new("secret-api-key")).with_retry_policy(RetryPolicy::none());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let err = service
+        .query(LayerId::new(0))
+        .execute()
+        .await
+        .expect_err("HTTP 500 should fail");
+    let message = err.to_string();
+    assert!(!message.contains("secret-api-key"), "{}", message);
+    assert!(message.contains("token=[REDACTED]"), "{}", message);
+
+    // Transport errors carry the request URL, token included
+    let unreachable = FeatureServiceClient::new("http://127.0.0.1:1/FeatureServer", &client);
+    let err = unreachable
+        .query(LayerId::new(0))
+        .execute()
+        .await
+        .expect_err("Connection should be refused");
+    let message = format!("{} {:?}", err, err);
+    assert!(!message.contains("secret-api-key"), "{}", message);
+
+    tracing::info!("test_errors_redact_tokens: Completed");
+    Ok(())
+}
+
+#[test]
+fn test_redact_tokens_in_free_text() {
+    common::init_tracing();
+    tracing::info!("test_redact_tokens_in_free_text: Starting");
+
+    assert_eq!(
+        redact_tokens("POST body: f=json&password=hunter2&username=analyst"),
+        "POST body: f=json&password=[REDACTED]&username=analyst"
+    );
+    assert_eq!(
+        redact_tokens("error sending request for url (https://x/y?access_token=abc)"),
+        "error sending request for url (https://x/y?access_token=[REDACTED])"
+    );
+    assert_eq!(redact_tokens("mytoken=kept&f=json"), "mytoken=kept&f=json");
+    assert_eq!(redact_tokens("no credentials here"), "no credentials here");
+
+    tracing::info!("test_redact_tokens_in_free_text: Completed");
+}
@@ -0,0 +1,154 @@
+//! Tests for token refresh and replay after ESRI code 498.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, AuthProvider, ErrorKind, FeatureServiceClient, LayerId,
+    MapServiceClient, RetryPolicy,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const INVALID_TOKEN: &str =
+    r#"{"error": {"code": 498, "message": "Invalid token.", "details": []}}"#;
+const EMPTY_FEATURE_SET: &str = r#"{"features": [], "exceededTransferLimit": false}"#;
+
+/// Issues `token-1`, `token-2`, ... and moves to the next token when invalidated.
+#[derive(Clone, Default)]
+struct RotatingAuth {
+    generation: Arc<AtomicUsize>,
+    invalidations: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl AuthProvider for RotatingAuth {
+    async fn get_token(&self) -> arcgis::Result<String> {
+        Ok(format!(
+            "token-{}",
+            self.generation.load(Ordering::SeqCst) + 1
+        ))
+    }
+
+    async fn invalidate_token(&self, token: &str) -> bool {
+        self.invalidations.fetch_add(1, Ordering::SeqCst);
+        let current = format!("token-{}", self.generation.load(Ordering::SeqCst) + 1);
+        if current == token {
+            self.generation.fetch_add(1, Ordering::SeqCst);
+        }
+        true
+    }
+}
+
+fn token_is(token: &str) -> mockito::Matcher {
+    mockito::Matcher::UrlEncoded("token".into(), token.into())
+}
+
+#[tokio::test]
+async fn test_rejected_token_is_refreshed_and_replayed() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_rejected_token_is_refreshed_and_replayed: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let rejected = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(token_is("token-1"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(INVALID_TOKEN)
+        .expect(1)
+        .create_async()
+        .await;
+    let accepted = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(token_is("token-2"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_FEATURE_SET)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let auth = RotatingAuth::default();
+    let client = ArcGISClient::new(auth.clone());
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let features = service.query(LayerId::new(0)).execute().await?;
+    assert!(features.features().is_empty());
+
+    rejected.assert_async().await;
+    accepted.assert_async().await;
+    assert_eq!(auth.invalidations.load(Ordering::SeqCst), 1);
+
+    tracing::info!("test_rejected_token_is_refreshed_and_replayed: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replay_happens_at_most_once() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_replay_happens_at_most_once: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let metadata = server
+        .mock("GET", "/MapServer")
+        .match_query(mockito::Matcher::Any)
+        .with_status(498)
+        .with_body("Invalid token")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let auth = RotatingAuth::default();
+    let client = ArcGISClient::new(auth.clone()).with_retry_policy(RetryPolicy::none());
+    let service = MapServiceClient::new(format!("{}/MapServer", server.url()), &client);
+
+    let err = service
+        .get_metadata()
+        .await
+        .expect_err("Second rejection should surface");
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::InvalidToken { code: 498, .. }
+    ));
+
+    metadata.assert_async().await;
+    assert_eq!(auth.invalidations.load(Ordering::SeqCst), 1);
+
+    tracing::info!("test_replay_happens_at_most_once: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_static_credentials_are_not_replayed() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_static_credentials_are_not_replayed: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let query = server
+        .mock("GET", "/FeatureServer/0/query")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(INVALID_TOKEN)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("revoked-key"));
+    let service = FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client);
+
+    let err = service
+        .query(LayerId::new(0))
+        .execute()
+        .await
+        .expect_err("Rejected API key should surface");
+    assert!(matches!(err.kind(), ErrorKind::InvalidToken { .. }));
+
+    query.assert_async().await;
+
+    tracing::info!("test_static_credentials_are_not_replayed: Completed");
+    Ok(())
+}
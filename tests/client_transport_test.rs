@@ -0,0 +1,157 @@
+//! Tests for pluggable HTTP transports.
+//!
+//! These tests answer requests from memory and require no network access.
+
+mod common;
+
+use arcgis::reqwest::{Request, Response};
+use arcgis::{
+    ApiKeyAuth, ArcGISClient, ErrorKind, FeatureServiceClient, GeocodeServiceClient, HttpTransport,
+    LayerId, RetryPolicy,
+};
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Answers requests with queued canned responses and records request URLs.
+#[derive(Clone, Default)]
+struct CannedTransport {
+    responses: Arc<Mutex<VecDeque<(u16, &'static str)>>>,
+    urls: Arc<Mutex<Vec<String>>>,
+}
+
+impl CannedTransport {
+    fn respond(self, status: u16, body: &'static str) -> Self {
+        self.responses
+            .lock()
+            .expect("Lock not poisoned")
+            .push_back((status, body));
+        self
+    }
+
+    fn urls(&self) -> Vec<String> {
+        self.urls.lock().expect("Lock not poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for CannedTransport {
+    async fn execute(&self, request: Request) -> arcgis::Result<Response> {
+        self.urls
+            .lock()
+            .expect("Lock not poisoned")
+            .push(request.url().to_string());
+
+        let (status, body) = self
+            .responses
+            .lock()
+            .expect("Lock not poisoned")
+            .pop_front()
+            .unwrap_or((404, r#"{"error": {"code": 404, "message": "Not found"}}"#));
+
+        let response = http::Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(body)
+            .expect("Valid canned response");
+        Ok(Response::from(response))
+    }
+}
+
+#[tokio::test]
+async fn test_feature_query_uses_custom_transport() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_feature_query_uses_custom_transport: Starting");
+
+    let transport = CannedTransport::default().respond(
+        200,
+        r#"{"features": [{"attributes": {"OBJECTID": 1, "NAME": "Springfield"}}],
+            "exceededTransferLimit": false}"#,
+    );
+
+    let client = ArcGISClient::builder()
+        .auth(ApiKeyAuth::new("test-api-key"))
+        .transport(transport.clone())
+        .build()?;
+    let service = FeatureServiceClient::new(
+        "https://example.com/arcgis/rest/services/Cities/FeatureServer",
+        &client,
+    );
+
+    let features = service.query(LayerId::new(0)).execute().await?;
+    assert_eq!(features.features().len(), 1);
+
+    let urls = transport.urls();
+    assert_eq!(urls.len(), 1);
+    assert!(
+        urls[0]
+            .starts_with("https://example.com/arcgis/rest/services/Cities/FeatureServer/0/query?")
+    );
+    assert!(urls[0].contains("token=test-api-key"));
+
+    tracing::info!("test_feature_query_uses_custom_transport: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_geocode_uses_custom_transport() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_geocode_uses_custom_transport: Starting");
+
+    let transport = CannedTransport::default().respond(
+        200,
+        r#"{"candidates": [{"address": "380 New York St, Redlands, California",
+                            "location": {"x": -117.19, "y": 34.05},
+                            "score": 100}]}"#,
+    );
+
+    let client = ArcGISClient::new(ApiKeyAuth::new("test-api-key")).with_transport(transport);
+    let geocoder = GeocodeServiceClient::new(
+        "https://geocode.example.com/arcgis/rest/services/World/GeocodeServer",
+        &client,
+    );
+
+    let response = geocoder
+        .find_address_candidates("380 New York St, Redlands, CA")
+        .await?;
+    assert_eq!(response.candidates().len(), 1);
+    assert_eq!(*response.candidates()[0].score(), 100.0);
+
+    tracing::info!("test_geocode_uses_custom_transport: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_transport_runs_under_retries_and_error_checks() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_custom_transport_runs_under_retries_and_error_checks: Starting");
+
+    let transport = CannedTransport::default().respond(503, "").respond(
+        200,
+        r#"{"error": {"code": 400, "message": "Invalid where clause"}}"#,
+    );
+
+    let client = ArcGISClient::builder()
+        .transport(transport.clone())
+        .retry_policy(
+            RetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(1))
+                .jitter(false)
+                .build()?,
+        )
+        .build()?;
+    let service = FeatureServiceClient::new("https://example.com/FeatureServer", &client);
+
+    let err = service
+        .query(LayerId::new(0))
+        .where_clause("bad")
+        .execute()
+        .await
+        .expect_err("ESRI error should surface");
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter { .. }));
+    assert_eq!(transport.urls().len(), 2);
+
+    tracing::info!("test_custom_transport_runs_under_retries_and_error_checks: Completed");
+    Ok(())
+}
@@ -3,7 +3,9 @@
 //! These tests target ArcGIS Online (AGOL) and require credentials
 //! set in a `.env` file at the repository root.
 
+use arcgis::{CassetteMode, CassetteTransport};
 use config::{Config, File};
+use std::path::PathBuf;
 use std::sync::OnceLock;
 use tracing::instrument;
 
@@ -84,6 +86,62 @@ pub fn api_key() -> anyhow::Result<String> {
     })
 }
 
+/// Get the path of a named cassette.
+///
+/// Cassettes live in the directory configured under `[cassettes]` in
+/// `config/test-tiers.toml`.
+///
+/// # Errors
+///
+/// Returns an error if the tier configuration cannot be loaded.
+#[instrument]
+pub fn cassette_path(name: &str) -> anyhow::Result<PathBuf> {
+    use anyhow::Context;
+
+    let settings = Config::builder()
+        .add_source(File::with_name("config/test-tiers"))
+        .build()
+        .context("Failed to load config/test-tiers.toml")?;
+    let dir = settings
+        .get_string("cassettes.dir")
+        .context("No cassettes.dir configured")?;
+
+    Ok(PathBuf::from(dir).join(format!("{}.json", name)))
+}
+
+/// Get a record/replay transport for a named cassette.
+///
+/// Replays the cassette offline unless `ARCGIS_CASSETTE_MODE=record` is set,
+/// in which case requests go to the live service and the cassette is rewritten.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded, or if replaying
+/// and the cassette is missing or invalid.
+///
+/// # Example
+///
+/// ```no_run
+/// #[tokio::test]
+/// async fn test_query_cities() -> anyhow::Result<()> {
+///     let client = ArcGISClient::builder()
+///         .transport(common::cassette("world_cities")?)
+///         .build()?;
+///     // ... test code
+///     Ok(())
+/// }
+/// ```
+#[instrument]
+pub fn cassette(name: &str) -> anyhow::Result<CassetteTransport> {
+    load_env();
+
+    let path = cassette_path(name)?;
+    let mode = CassetteMode::from_env();
+    tracing::debug!(path = %path.display(), mode = ?mode, "Opening cassette");
+
+    Ok(CassetteTransport::new(path, mode)?)
+}
+
 /// Determine the active test tier from compile-time feature flags.
 fn active_tier() -> &'static str {
     if cfg!(feature = "test-public") {
@@ -166,4 +224,20 @@ mod tests {
             "API key lookup completed"
         );
     }
+
+    #[test]
+    fn test_cassette_helper() {
+        init_tracing();
+
+        let path = cassette_path("public_services").expect("Cassette directory configured");
+        assert!(path.ends_with("public_services.json"));
+
+        // Replay mode unless recording was requested for this run
+        let result = cassette("public_services");
+        tracing::debug!(
+            loaded = result.is_ok(),
+            path = %path.display(),
+            "Cassette lookup completed"
+        );
+    }
 }
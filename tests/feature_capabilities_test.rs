@@ -0,0 +1,412 @@
+//! Tests for server info and feature service capability checks.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{
+    ArcGISClient, EditOptions, ErrorKind, FeatureServiceClient, LayerId, NoAuth,
+    ServiceCapabilities,
+};
+use futures::StreamExt;
+use mockito::{Matcher, ServerGuard};
+
+const SERVICE_PATH: &str = "/arcgis/rest/services/Parcels/FeatureServer";
+
+const SERVICE_ROOT: &str = r#"{
+    "currentVersion": 11.1,
+    "maxRecordCount": 2,
+    "supportedQueryFormats": "JSON, geoJSON",
+    "capabilities": "Query,Create,Delete",
+    "supportsApplyEditsWithGlobalIds": false,
+    "supportsTruncate": false,
+    "advancedQueryCapabilities": {"supportsPagination": true},
+    "layers": [{"id": 0, "name": "Parcels"}]
+}"#;
+
+/// Answers like a layer without pagination: paging parameters are rejected.
+fn unpaged_response(body: &'static str) -> impl Fn(&mockito::Request) -> Vec<u8> + Send + Sync {
+    move |request| {
+        if request.path_and_query().contains("resultOffset") {
+            br#"{"error": {"code": 400, "message": "Pagination is not supported.", "details": []}}"#
+                .to_vec()
+        } else {
+            body.as_bytes().to_vec()
+        }
+    }
+}
+
+/// Creates a feature service client for the mock server with capabilities loaded.
+async fn loaded_service(
+    server: &mut ServerGuard,
+    root: &str,
+) -> anyhow::Result<FeatureServiceClient> {
+    let mock = server
+        .mock("GET", SERVICE_PATH)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(root)
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth);
+    let service = FeatureServiceClient::new(format!("{}{}", server.url(), SERVICE_PATH), &client);
+    service.capabilities().await?;
+
+    mock.assert_async().await;
+    Ok(service)
+}
+
+#[test]
+fn test_capabilities_deserialize() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_capabilities_deserialize: Starting");
+
+    let capabilities: ServiceCapabilities = serde_json::from_str(SERVICE_ROOT)?;
+    assert_eq!(*capabilities.current_version(), Some(11.1));
+    assert_eq!(*capabilities.max_record_count(), Some(2));
+    assert_eq!(*capabilities.supports_pagination(), Some(true));
+    assert_eq!(capabilities.supported_query_formats(), &["JSON", "geoJSON"]);
+    assert_eq!(
+        *capabilities.supports_apply_edits_with_global_ids(),
+        Some(false)
+    );
+    assert_eq!(*capabilities.supports_truncate(), Some(false));
+    assert!(capabilities.has_capability("create"));
+    assert!(!capabilities.has_capability("Update"));
+
+    // Older servers report pagination at the root and omit formats
+    let legacy: ServiceCapabilities = serde_json::from_str(r#"{"supportsPagination": false}"#)?;
+    assert_eq!(*legacy.supports_pagination(), Some(false));
+    assert!(legacy.supported_query_formats().is_empty());
+    assert!(legacy.has_capability("Update"));
+
+    let round_trip: ServiceCapabilities =
+        serde_json::from_str(&serde_json::to_string(&capabilities)?)?;
+    assert_eq!(round_trip, capabilities);
+
+    tracing::info!("test_capabilities_deserialize: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_server_info() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_server_info: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/arcgis/rest/info")
+        .match_query(Matcher::UrlEncoded("f".into(), "json".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "currentVersion": 11.1,
+                "fullVersion": "11.1.0",
+                "owningSystemUrl": "https://host/portal",
+                "authInfo": {
+                    "isTokenBasedSecurity": true,
+                    "tokenServicesUrl": "https://host/portal/sharing/rest/generateToken",
+                    "shortLivedTokenValidity": 60
+                }
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth);
+    let service = FeatureServiceClient::new(format!("{}{}", server.url(), SERVICE_PATH), &client);
+    let info = service.server_info().await?;
+
+    mock.assert_async().await;
+    assert_eq!(*info.current_version(), Some(11.1));
+    assert_eq!(info.full_version().as_deref(), Some("11.1.0"));
+    assert_eq!(
+        info.token_services_url(),
+        Some("https://host/portal/sharing/rest/generateToken")
+    );
+    assert!(service.cached_capabilities().is_none());
+
+    tracing::info!("test_server_info: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unsupported_edits_fail_fast() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_unsupported_edits_fail_fast: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let service = loaded_service(&mut server, SERVICE_ROOT).await?;
+    let never = server
+        .mock("POST", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let err = service
+        .truncate(LayerId::new(0))
+        .await
+        .expect_err("Truncate should be rejected");
+    assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+
+    let err = service
+        .apply_edits_with_global_ids(
+            LayerId::new(0),
+            None,
+            None,
+            Some(vec!["{8F2A4C1E-0000-0000-0000-000000000001}".to_string()]),
+            EditOptions::default(),
+        )
+        .await
+        .expect_err("Global ID edits should be rejected");
+    assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+
+    let err = service
+        .update_features(LayerId::new(0), Vec::new(), EditOptions::default())
+        .await
+        .expect_err("Updates should be rejected");
+    assert!(err.to_string().contains("Update edits"));
+
+    // Clones share the loaded capabilities
+    assert!(service.clone().cached_capabilities().is_some());
+    never.assert_async().await;
+
+    tracing::info!("test_unsupported_edits_fail_fast: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_editing_capability_allows_all_edits() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_editing_capability_allows_all_edits: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let service = loaded_service(
+        &mut server,
+        r#"{"capabilities": "Query,Editing", "layers": [{"id": 0, "name": "Parcels"}]}"#,
+    )
+    .await?;
+    let update = server
+        .mock(
+            "POST",
+            format!("{}/0/updateFeatures", SERVICE_PATH).as_str(),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"updateResults": []}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    service
+        .update_features(LayerId::new(0), Vec::new(), EditOptions::default())
+        .await?;
+
+    update.assert_async().await;
+
+    tracing::info!("test_editing_capability_allows_all_edits: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_negotiates_format_and_page_size() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_query_negotiates_format_and_page_size: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let service = loaded_service(&mut server, SERVICE_ROOT).await?;
+
+    let query_path = format!("{}/0/query", SERVICE_PATH);
+    let first = server
+        .mock("GET", query_path.as_str())
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("f".into(), "json".into()),
+            Matcher::UrlEncoded("resultOffset".into(), "0".into()),
+            Matcher::UrlEncoded("resultRecordCount".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"features": [{"attributes": {"OBJECTID": 1}}, {"attributes": {"OBJECTID": 2}}], "exceededTransferLimit": true}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", query_path.as_str())
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("f".into(), "json".into()),
+            Matcher::UrlEncoded("resultOffset".into(), "2".into()),
+            Matcher::UrlEncoded("resultRecordCount".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"features": [{"attributes": {"OBJECTID": 3}}]}"#)
+        .create_async()
+        .await;
+
+    let features = service
+        .query(LayerId::new(0))
+        .pbf()
+        .limit(500)
+        .execute_all()
+        .await?;
+
+    first.assert_async().await;
+    second.assert_async().await;
+    assert_eq!(features.features().len(), 3);
+
+    tracing::info!("test_query_negotiates_format_and_page_size: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_layer_capabilities_take_precedence() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_layer_capabilities_take_precedence: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let service = loaded_service(
+        &mut server,
+        r#"{"currentVersion": 11.1, "maxRecordCount": 1000, "supportsTruncate": true,
+            "advancedQueryCapabilities": {"supportsPagination": false}}"#,
+    )
+    .await?;
+    let layer = server
+        .mock("GET", format!("{}/0", SERVICE_PATH).as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"id": 0, "maxRecordCount": 2, "supportsTruncate": false,
+                "advancedQueryCapabilities": {"supportsPagination": true}}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let query = server
+        .mock("GET", format!("{}/0/query", SERVICE_PATH).as_str())
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("resultOffset".into(), "0".into()),
+            Matcher::UrlEncoded("resultRecordCount".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"features": [{"attributes": {"OBJECTID": 1}}]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let truncate = server
+        .mock("POST", format!("{}/0/truncate", SERVICE_PATH).as_str())
+        .expect(0)
+        .create_async()
+        .await;
+
+    let features = service
+        .query(LayerId::new(0))
+        .limit(500)
+        .execute_all()
+        .await?;
+    assert_eq!(features.features().len(), 1);
+
+    let err = service
+        .truncate(LayerId::new(0))
+        .await
+        .expect_err("Layer does not support truncate");
+    assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+
+    // Values the layer does not report come from the root
+    let capabilities = service
+        .cached_layer_capabilities(LayerId::new(0))
+        .expect("Layer capabilities cached");
+    assert_eq!(*capabilities.current_version(), Some(11.1));
+    assert_eq!(*capabilities.max_record_count(), Some(2));
+
+    layer.assert_async().await;
+    query.assert_async().await;
+    truncate.assert_async().await;
+
+    tracing::info!("test_layer_capabilities_take_precedence: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pagination_unsupported_fails_on_truncated_result() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_pagination_unsupported_fails_on_truncated_result: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let service = loaded_service(
+        &mut server,
+        r#"{"currentVersion": 10.2, "advancedQueryCapabilities": {"supportsPagination": false}}"#,
+    )
+    .await?;
+    // A single query is sent without paging parameters
+    let truncated = server
+        .mock("GET", format!("{}/0/query", SERVICE_PATH).as_str())
+        .match_query(Matcher::UrlEncoded("where".into(), "1=1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(unpaged_response(
+            r#"{"features": [{"attributes": {"OBJECTID": 1}}], "exceededTransferLimit": true}"#,
+        ))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let err = service
+        .query(LayerId::new(0))
+        .where_clause("1=1")
+        .execute_all()
+        .await
+        .expect_err("Truncated result should be rejected");
+    assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+
+    let mut pages = std::pin::pin!(service.query(LayerId::new(0)).where_clause("1=1").pages());
+    let err = pages
+        .next()
+        .await
+        .expect("Stream yields the error")
+        .expect_err("Truncated page should be rejected");
+    assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+    assert!(pages.next().await.is_none());
+
+    truncated.assert_async().await;
+
+    tracing::info!("test_pagination_unsupported_fails_on_truncated_result: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pagination_unsupported_allows_complete_result() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_pagination_unsupported_allows_complete_result: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let service = loaded_service(
+        &mut server,
+        r#"{"currentVersion": 10.2, "advancedQueryCapabilities": {"supportsPagination": false}}"#,
+    )
+    .await?;
+    let complete = server
+        .mock("GET", format!("{}/0/query", SERVICE_PATH).as_str())
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(unpaged_response(
+            r#"{"features": [{"attributes": {"OBJECTID": 1}}], "exceededTransferLimit": false}"#,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let features = service.query(LayerId::new(0)).execute_all().await?;
+    assert_eq!(features.features().len(), 1);
+
+    complete.assert_async().await;
+
+    tracing::info!("test_pagination_unsupported_allows_complete_result: Completed");
+    Ok(())
+}
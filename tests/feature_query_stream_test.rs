@@ -0,0 +1,268 @@
+//! Tests for streaming feature queries.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{ArcGISClient, ErrorKind, FeatureServiceClient, LayerId, NoAuth, RetryPolicy};
+use futures::{StreamExt, TryStreamExt};
+use mockito::{Matcher, ServerGuard};
+
+const QUERY_PATH: &str = "/FeatureServer/0/query";
+
+/// Builds a query response with the given object IDs.
+fn page_body(ids: &[i64], exceeded: bool) -> String {
+    let features = ids
+        .iter()
+        .map(|id| format!(r#"{{"attributes": {{"OBJECTID": {id}}}}}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"features": [{features}], "exceededTransferLimit": {exceeded}}}"#)
+}
+
+/// Mocks the query page at `offset` with `count` records requested.
+async fn mock_page(
+    server: &mut ServerGuard,
+    offset: u32,
+    count: u32,
+    body: String,
+) -> mockito::Mock {
+    server
+        .mock("GET", QUERY_PATH)
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("resultOffset".into(), offset.to_string()),
+            Matcher::UrlEncoded("resultRecordCount".into(), count.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await
+}
+
+fn service(server: &ServerGuard) -> FeatureServiceClient {
+    let client = ArcGISClient::new(NoAuth).with_retry_policy(RetryPolicy::none());
+    FeatureServiceClient::new(format!("{}/FeatureServer", server.url()), &client)
+}
+
+/// Returns the object IDs of streamed features.
+fn object_ids(features: &[arcgis::Feature]) -> Vec<i64> {
+    features
+        .iter()
+        .filter_map(|feature| feature.attributes().get("OBJECTID")?.as_i64())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_stream_yields_all_pages_in_order() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_stream_yields_all_pages_in_order: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, 0, 2, page_body(&[1, 2], true)).await;
+    let second = mock_page(&mut server, 2, 2, page_body(&[3, 4], true)).await;
+    let third = mock_page(&mut server, 4, 2, page_body(&[5], false)).await;
+    // Prefetched while the third page is in flight, then dropped
+    let past_end = server
+        .mock("GET", QUERY_PATH)
+        .match_query(Matcher::UrlEncoded("resultOffset".into(), "6".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_body(&[], false))
+        .expect_at_most(1)
+        .create_async()
+        .await;
+
+    let features: Vec<_> = service(&server)
+        .query(LayerId::new(0))
+        .limit(2)
+        .prefetch(2)
+        .stream()
+        .try_collect()
+        .await?;
+
+    first.assert_async().await;
+    second.assert_async().await;
+    third.assert_async().await;
+    past_end.assert_async().await;
+    assert_eq!(object_ids(&features), vec![1, 2, 3, 4, 5]);
+
+    tracing::info!("test_stream_yields_all_pages_in_order: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_adapts_to_server_page_size() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_stream_adapts_to_server_page_size: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    // The server caps pages at 2 records although 5 were requested
+    let first = mock_page(&mut server, 0, 5, page_body(&[1, 2], true)).await;
+    let second = mock_page(&mut server, 2, 2, page_body(&[3, 4], true)).await;
+    let third = mock_page(&mut server, 4, 2, page_body(&[], false)).await;
+
+    let pages: Vec<_> = service(&server)
+        .query(LayerId::new(0))
+        .limit(5)
+        .prefetch(1)
+        .pages()
+        .try_collect()
+        .await?;
+
+    first.assert_async().await;
+    second.assert_async().await;
+    third.assert_async().await;
+    // The empty last page is not yielded
+    assert_eq!(pages.len(), 2);
+
+    tracing::info!("test_stream_adapts_to_server_page_size: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_resumes_after_short_later_page() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_stream_resumes_after_short_later_page: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, 0, 3, page_body(&[1, 2, 3], true)).await;
+    // The server caps the second page at 2 records
+    let short = mock_page(&mut server, 3, 3, page_body(&[4, 5], true)).await;
+    // Prefetched alongside the short page, then discarded
+    let stale = server
+        .mock("GET", QUERY_PATH)
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("resultOffset".into(), "6".into()),
+            Matcher::UrlEncoded("resultRecordCount".into(), "3".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_body(&[7, 8, 9], true))
+        .expect_at_most(1)
+        .create_async()
+        .await;
+    let resumed = mock_page(&mut server, 5, 2, page_body(&[6, 7], true)).await;
+    let last = mock_page(&mut server, 7, 2, page_body(&[8], false)).await;
+    let past_end = server
+        .mock("GET", QUERY_PATH)
+        .match_query(Matcher::UrlEncoded("resultOffset".into(), "9".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page_body(&[], false))
+        .expect_at_most(1)
+        .create_async()
+        .await;
+
+    let features: Vec<_> = service(&server)
+        .query(LayerId::new(0))
+        .limit(3)
+        .prefetch(2)
+        .stream()
+        .try_collect()
+        .await?;
+
+    first.assert_async().await;
+    short.assert_async().await;
+    stale.assert_async().await;
+    resumed.assert_async().await;
+    last.assert_async().await;
+    past_end.assert_async().await;
+    assert_eq!(object_ids(&features), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+    tracing::info!("test_stream_resumes_after_short_later_page: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_all_resumes_after_short_page() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_execute_all_resumes_after_short_page: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    // The server caps pages at 2 records although 3 were requested
+    let first = mock_page(&mut server, 0, 3, page_body(&[1, 2], true)).await;
+    let second = mock_page(&mut server, 2, 3, page_body(&[3], false)).await;
+
+    let features = service(&server)
+        .query(LayerId::new(0))
+        .limit(3)
+        .execute_all()
+        .await?;
+
+    first.assert_async().await;
+    second.assert_async().await;
+    assert_eq!(object_ids(features.features()), vec![1, 2, 3]);
+
+    tracing::info!("test_execute_all_resumes_after_short_page: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_fetches_only_what_is_consumed() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_stream_fetches_only_what_is_consumed: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, 0, 2, page_body(&[1, 2], true)).await;
+    let second = mock_page(&mut server, 2, 2, page_body(&[3, 4], true)).await;
+    let unused = server
+        .mock("GET", QUERY_PATH)
+        .match_query(Matcher::UrlEncoded("resultOffset".into(), "4".into()))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let features: Vec<_> = service(&server)
+        .query(LayerId::new(0))
+        .limit(2)
+        .prefetch(1)
+        .stream()
+        .take(3)
+        .try_collect()
+        .await?;
+
+    first.assert_async().await;
+    second.assert_async().await;
+    unused.assert_async().await;
+    assert_eq!(object_ids(&features), vec![1, 2, 3]);
+
+    tracing::info!("test_stream_fetches_only_what_is_consumed: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_ends_after_error() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_stream_ends_after_error: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_page(&mut server, 0, 2, page_body(&[1, 2], true)).await;
+    let failing = mock_page(
+        &mut server,
+        2,
+        2,
+        r#"{"error": {"code": 400, "message": "Unable to complete operation.", "details": ["'where' parameter is invalid"]}}"#.to_string(),
+    )
+    .await;
+
+    let results: Vec<_> = service(&server)
+        .query(LayerId::new(0))
+        .limit(2)
+        .prefetch(1)
+        .stream()
+        .collect()
+        .await;
+
+    first.assert_async().await;
+    failing.assert_async().await;
+    assert_eq!(results.len(), 3);
+    assert!(results[..2].iter().all(Result::is_ok));
+    let err = results[2]
+        .as_ref()
+        .expect_err("Third item should be the error");
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter { .. }));
+
+    tracing::info!("test_stream_ends_after_error: Completed");
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//! Tests for named connection profiles.
+//!
+//! These tests parse profiles locally and require no credentials.
+
+mod common;
+
+use arcgis::{EnvConfig, ErrorKind, ProfileAuth, Profiles};
+use secrecy::{ExposeSecret, SecretString};
+
+const PROFILES: &str = r#"
+[dev]
+auth = "api_key"
+api_key = "dev-key"
+
+[prod]
+portal_url = "https://gis.example.com/portal"
+auth = "client_credentials"
+client_id = "prod-app"
+
+[prod.keys]
+location = "location-key"
+
+[prod.services]
+feature = "https://gis.example.com/server/rest/services/Assets/FeatureServer"
+"#;
+
+/// Environment with nothing set.
+fn empty_env() -> EnvConfig {
+    EnvConfig {
+        arcgis_api_key: None,
+        arcgis_public_key: None,
+        arcgis_location_key: None,
+        arcgis_content_key: None,
+        arcgis_features_key: None,
+        arcgis_client_id: None,
+        arcgis_client_secret: None,
+        arcgis_enterprise_portal: None,
+        arcgis_enterprise_key: None,
+        arcgis_feature_url: None,
+        arcgis_username: None,
+        arcgis_password: None,
+        arcgis_profile_file: None,
+    }
+}
+
+#[test]
+fn test_profiles_load_from_file() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_profiles_load_from_file: Starting");
+
+    let path = std::env::temp_dir().join(format!("arcgis-profiles-{}.toml", uuid::Uuid::new_v4()));
+    std::fs::write(&path, PROFILES)?;
+    let profiles = Profiles::from_path(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(profiles.names().collect::<Vec<_>>(), ["dev", "prod"]);
+
+    let prod = profiles.get("prod")?;
+    assert_eq!(prod.name, "prod");
+    assert_eq!(prod.auth, ProfileAuth::ClientCredentials);
+    assert_eq!(
+        prod.portal_url.as_deref(),
+        Some("https://gis.example.com/portal")
+    );
+    assert_eq!(
+        prod.service_url("feature"),
+        Some("https://gis.example.com/server/rest/services/Assets/FeatureServer")
+    );
+    assert!(!format!("{:?}", prod).contains("location-key"));
+
+    let err = profiles.get("staging").expect_err("Unknown profile");
+    assert!(matches!(err.kind(), ErrorKind::Profile(_)));
+    assert!(err.to_string().contains("dev, prod"), "{}", err);
+
+    tracing::info!("test_profiles_load_from_file: Completed");
+    Ok(())
+}
+
+#[test]
+fn test_env_overrides_profile_values() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_env_overrides_profile_values: Starting");
+
+    let profiles: Profiles = PROFILES.parse()?;
+    let mut env = empty_env();
+    env.arcgis_client_secret = Some(SecretString::from("env-secret"));
+    env.arcgis_enterprise_portal = Some("https://staging.example.com/portal".to_string());
+    env.arcgis_feature_url = Some("https://staging.example.com/FeatureServer".to_string());
+
+    let prod = profiles.get("prod")?.with_env_overrides(&env);
+    assert_eq!(
+        prod.client_secret.as_ref().map(|s| s.expose_secret()),
+        Some("env-secret")
+    );
+    assert_eq!(
+        prod.portal_url.as_deref(),
+        Some("https://staging.example.com/portal")
+    );
+    assert_eq!(
+        prod.service_url("feature"),
+        Some("https://staging.example.com/FeatureServer")
+    );
+    // Values the environment does not set are kept
+    assert_eq!(prod.client_id.as_deref(), Some("prod-app"));
+
+    tracing::info!("test_env_overrides_profile_values: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_profile_auth_provider_routes_tier_keys() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_profile_auth_provider_routes_tier_keys: Starting");
+
+    let profiles: Profiles = PROFILES.parse()?;
+
+    // Client credentials need a secret
+    let Err(err) = profiles.get("prod")?.auth_provider() else {
+        panic!("Missing client_secret should fail");
+    };
+    assert!(err.to_string().contains("client_secret"), "{}", err);
+
+    let mut prod = profiles.get("prod")?;
+    prod.auth = ProfileAuth::None;
+    let auth = prod.auth_provider()?;
+    let geocode = auth
+        .token_for_url(
+            "https://geocode.arcgis.com/arcgis/rest/services/World/GeocodeServer/findAddressCandidates",
+        )
+        .await?;
+    assert_eq!(geocode.as_deref(), Some("location-key"));
+    let features = auth
+        .token_for_url("https://gis.example.com/server/rest/services/Assets/FeatureServer/0/query")
+        .await?;
+    assert_eq!(features, None);
+
+    let dev = profiles.get("dev")?.auth_provider()?;
+    assert_eq!(dev.get_token().await?, "dev-key");
+
+    tracing::info!("test_profile_auth_provider_routes_tier_keys: Completed");
+    Ok(())
+}
+
+#[test]
+fn test_invalid_profiles_do_not_echo_secrets() {
+    common::init_tracing();
+    tracing::info!("test_invalid_profiles_do_not_echo_secrets: Starting");
+
+    let err = "[dev]\napi_key = \"super-secret\"\nunknown_field = 1\n"
+        .parse::<Profiles>()
+        .expect_err("Unknown field");
+    assert!(matches!(err.kind(), ErrorKind::Profile(_)));
+    assert!(err.to_string().contains("line 3"), "{}", err);
+    assert!(!err.to_string().contains("super-secret"), "{}", err);
+
+    let err = "[dev".parse::<Profiles>().expect_err("Malformed TOML");
+    assert!(matches!(err.kind(), ErrorKind::Profile(_)));
+
+    tracing::info!("test_invalid_profiles_do_not_echo_secrets: Completed");
+}
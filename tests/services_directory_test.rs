@@ -0,0 +1,222 @@
+//! Tests for the services directory crawler.
+//!
+//! These tests run against a local mock server and require no credentials.
+
+mod common;
+
+use arcgis::{ArcGISClient, LayerId, NoAuth, ServiceClient, ServiceType, ServicesDirectoryClient};
+use mockito::{Matcher, ServerGuard};
+
+/// Mocks a JSON endpoint answering any query.
+async fn mock_json(server: &mut ServerGuard, path: &str, body: &str) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await
+}
+
+#[test]
+fn test_service_type_round_trip() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_service_type_round_trip: Starting");
+
+    let parsed: Vec<ServiceType> =
+        serde_json::from_str(r#"["FeatureServer", "NAServer", "StreamServer"]"#)?;
+    assert_eq!(
+        parsed,
+        vec![
+            ServiceType::FeatureServer,
+            ServiceType::NAServer,
+            ServiceType::Other("StreamServer".to_string()),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_string(&parsed)?,
+        r#"["FeatureServer","NAServer","StreamServer"]"#
+    );
+
+    tracing::info!("test_service_type_round_trip: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crawl_builds_tree() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_crawl_builds_tree: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let root = mock_json(
+        &mut server,
+        "/arcgis/rest/services",
+        r#"{
+            "currentVersion": 11.1,
+            "folders": ["Utilities"],
+            "services": [
+                {"name": "Parcels", "type": "FeatureServer"},
+                {"name": "Basemap", "type": "MapServer"},
+                {"name": "Secure", "type": "ImageServer"}
+            ]
+        }"#,
+    )
+    .await;
+    let folder = mock_json(
+        &mut server,
+        "/arcgis/rest/services/Utilities",
+        r#"{
+            "currentVersion": 11.1,
+            "folders": [],
+            "services": [
+                {"name": "Utilities/Geometry", "type": "GeometryServer"},
+                {"name": "Utilities/PrintingTools", "type": "GPServer"}
+            ]
+        }"#,
+    )
+    .await;
+    let parcels = mock_json(
+        &mut server,
+        "/arcgis/rest/services/Parcels/FeatureServer",
+        r#"{
+            "serviceDescription": "County parcels",
+            "layers": [{"id": 0, "name": "Parcels", "geometryType": "esriGeometryPolygon"}],
+            "tables": [{"id": 1, "name": "Owners"}]
+        }"#,
+    )
+    .await;
+    let basemap = mock_json(
+        &mut server,
+        "/arcgis/rest/services/Basemap/MapServer",
+        r#"{
+            "serviceDescription": "",
+            "description": "Streets basemap",
+            "layers": [
+                {"id": 0, "name": "Roads", "parentLayerId": -1, "subLayerIds": [1]},
+                {"id": 1, "name": "Highways", "parentLayerId": 0, "subLayerIds": null}
+            ]
+        }"#,
+    )
+    .await;
+    let secure = mock_json(
+        &mut server,
+        "/arcgis/rest/services/Secure/ImageServer",
+        r#"{"error": {"code": 403, "message": "You do not have permissions to access this resource or perform this operation.", "details": []}}"#,
+    )
+    .await;
+    let geometry = mock_json(
+        &mut server,
+        "/arcgis/rest/services/Utilities/Geometry/GeometryServer",
+        r#"{"serviceDescription": "Geometry operations"}"#,
+    )
+    .await;
+    let printing = mock_json(
+        &mut server,
+        "/arcgis/rest/services/Utilities/PrintingTools/GPServer",
+        r#"{"tasks": ["Export Web Map Task", "Get Layout Templates Info Task"]}"#,
+    )
+    .await;
+
+    let client = ArcGISClient::new(NoAuth);
+    let directory =
+        ServicesDirectoryClient::new(format!("{}/arcgis/rest/services/", server.url()), &client);
+    let tree = directory.crawl().await?;
+
+    for mock in [
+        &root, &folder, &parcels, &basemap, &secure, &geometry, &printing,
+    ] {
+        mock.assert_async().await;
+    }
+
+    assert_eq!(tree.name(), "");
+    assert_eq!(*tree.current_version(), Some(11.1));
+    assert_eq!(tree.services().len(), 3);
+    assert_eq!(tree.folders().len(), 1);
+    assert_eq!(tree.folders()[0].name(), "Utilities");
+    assert_eq!(tree.all_services().len(), 5);
+
+    let parcels = tree
+        .find_service("Parcels", &ServiceType::FeatureServer)
+        .expect("Parcels service");
+    assert_eq!(
+        parcels.url(),
+        &format!(
+            "{}/arcgis/rest/services/Parcels/FeatureServer",
+            server.url()
+        )
+    );
+    assert_eq!(parcels.description().as_deref(), Some("County parcels"));
+    assert_eq!(*parcels.layers()[0].id(), LayerId::new(0));
+    assert_eq!(
+        parcels.layers()[0].geometry_type().as_deref(),
+        Some("esriGeometryPolygon")
+    );
+    assert_eq!(parcels.tables()[0].name(), "Owners");
+    assert!(parcels.feature_client(&client).is_some());
+    assert!(parcels.map_client(&client).is_none());
+
+    let basemap = tree
+        .find_service("Basemap", &ServiceType::MapServer)
+        .expect("Basemap service");
+    assert_eq!(basemap.description().as_deref(), Some("Streets basemap"));
+    assert_eq!(*basemap.layers()[1].parent_layer_id(), Some(0));
+    assert!(matches!(
+        basemap.client(&client),
+        Some(ServiceClient::Map(_))
+    ));
+
+    let secure = tree
+        .find_service("Secure", &ServiceType::ImageServer)
+        .expect("Secure service");
+    assert!(secure.error().is_some());
+    assert!(secure.layers().is_empty());
+
+    let geometry = tree
+        .find_service("Utilities/Geometry", &ServiceType::GeometryServer)
+        .expect("Geometry service");
+    assert_eq!(geometry.short_name(), "Geometry");
+    assert!(matches!(
+        geometry.client(&client),
+        Some(ServiceClient::Geometry(_))
+    ));
+
+    let printing = tree
+        .find_service("Utilities/PrintingTools", &ServiceType::GPServer)
+        .expect("Printing service");
+    assert_eq!(printing.tasks().len(), 2);
+    assert!(printing.client(&client).is_none());
+    assert!(
+        printing
+            .geoprocessing_client("Export Web Map Task", &client)
+            .is_some()
+    );
+
+    tracing::info!("test_crawl_builds_tree: Completed");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crawl_fails_when_root_unavailable() -> anyhow::Result<()> {
+    common::init_tracing();
+    tracing::info!("test_crawl_fails_when_root_unavailable: Starting");
+
+    let mut server = mockito::Server::new_async().await;
+    let root = server
+        .mock("GET", "/arcgis/rest/services")
+        .match_query(Matcher::Any)
+        .with_status(404)
+        .with_body("Not Found")
+        .create_async()
+        .await;
+
+    let client = ArcGISClient::new(NoAuth);
+    let directory =
+        ServicesDirectoryClient::new(format!("{}/arcgis/rest/services", server.url()), &client);
+    assert!(directory.crawl().await.is_err());
+
+    root.assert_async().await;
+
+    tracing::info!("test_crawl_fails_when_root_unavailable: Completed");
+    Ok(())
+}